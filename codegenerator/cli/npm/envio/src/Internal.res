@@ -55,6 +55,64 @@ type handlerContext
 type handlerArgs = genericHandlerArgs<event, handlerContext, loaderReturn>
 type handler = genericHandler<handlerArgs>
 
+@genType
+type blockHandlerBlock = {
+  number: int,
+  timestamp: int,
+  hash: string,
+}
+
+@genType
+type genericBlockHandlerArgs<'context> = {
+  block: blockHandlerBlock,
+  chainId: int,
+  context: 'context,
+}
+@genType
+type genericBlockHandler<'args> = 'args => promise<unit>
+
+type blockHandlerArgs = genericBlockHandlerArgs<handlerContext>
+type blockHandler = genericBlockHandler<blockHandlerArgs>
+
+@genType
+type genericTransactionHandlerArgs<'transaction, 'context> = {
+  transaction: 'transaction,
+  block: blockHandlerBlock,
+  chainId: int,
+  context: 'context,
+}
+@genType
+type genericTransactionHandler<'args> = 'args => promise<unit>
+
+type transactionHandlerArgs = genericTransactionHandlerArgs<eventTransaction, handlerContext>
+type transactionHandler = genericTransactionHandler<transactionHandlerArgs>
+
+@genType
+type genericCall<'inputs, 'outputs> = {
+  inputs: 'inputs,
+  outputs: 'outputs,
+  chainId: int,
+  srcAddress: Address.t,
+  from: Address.t,
+  transactionHash: string,
+  traceAddress: array<int>,
+  block: blockHandlerBlock,
+}
+
+@genType
+type genericCallHandlerArgs<'call, 'context> = {
+  call: 'call,
+  context: 'context,
+}
+@genType
+type genericCallHandler<'args> = 'args => promise<unit>
+
+type callInputs
+type callOutputs
+type call = genericCall<callInputs, callOutputs>
+type callHandlerArgs = genericCallHandlerArgs<call, handlerContext>
+type callHandler = genericCallHandler<callHandlerArgs>
+
 @genType
 type genericHandlerWithLoader<'loader, 'handler, 'eventFilters> = {
   loader: 'loader,
@@ -18,42 +18,92 @@ let makeTopicSelection = (~topic0, ~topic1=[], ~topic2=[], ~topic3=[]) =>
     }->Ok
   }
 
+/**
+Anonymous events don't have the sighash in topic0,
+so every topic is an optional filter on the indexed params
+*/
+let makeAnonymousTopicSelection = (~topic0=[], ~topic1=[], ~topic2=[], ~topic3=[]) => {
+  topic0,
+  topic1,
+  topic2,
+  topic3,
+}
+
+let isMatchAll = ({topic0, topic1, topic2, topic3}: topicSelection) =>
+  [topic0, topic1, topic2, topic3]->Js.Array2.every(Utils.Array.isEmpty)
+
 let hasFilters = ({topic1, topic2, topic3}: topicSelection) => {
   [topic1, topic2, topic3]->Js.Array2.find(topic => !Utils.Array.isEmpty(topic))->Belt.Option.isSome
 }
 
 /**
 For a group of topic selections, if multiple only use topic0, then they can be compressed into one
-selection combining the topic0s
+selection combining the topic0s. A selection without any topics (eg. an anonymous event without filters)
+matches every log, so it replaces all the others.
 */
 let compressTopicSelections = (topicSelections: array<topicSelection>) => {
-  let topic0sOfSelectionsWithoutFilters = []
+  switch topicSelections->Js.Array2.find(isMatchAll) {
+  | Some(matchAllSelection) => [matchAllSelection]
+  | None =>
+    let topic0sOfSelectionsWithoutFilters = []
 
-  let selectionsWithFilters = []
+    let selectionsWithFilters = []
 
-  topicSelections->Belt.Array.forEach(selection => {
-    if selection->hasFilters {
-      selectionsWithFilters->Js.Array2.push(selection)->ignore
-    } else {
-      selection.topic0->Belt.Array.forEach(topic0 => {
-        topic0sOfSelectionsWithoutFilters->Js.Array2.push(topic0)->ignore
-      })
-    }
-  })
+    topicSelections->Belt.Array.forEach(selection => {
+      if selection->hasFilters {
+        selectionsWithFilters->Js.Array2.push(selection)->ignore
+      } else {
+        selection.topic0->Belt.Array.forEach(topic0 => {
+          topic0sOfSelectionsWithoutFilters->Js.Array2.push(topic0)->ignore
+        })
+      }
+    })
 
-  switch topic0sOfSelectionsWithoutFilters {
-  | [] => selectionsWithFilters
-  | topic0 =>
-    let selectionWithoutFilters = {
-      topic0,
-      topic1: [],
-      topic2: [],
-      topic3: [],
+    switch topic0sOfSelectionsWithoutFilters {
+    | [] => selectionsWithFilters
+    | topic0 =>
+      let selectionWithoutFilters = {
+        topic0,
+        topic1: [],
+        topic2: [],
+        topic3: [],
+      }
+      Belt.Array.concat([selectionWithoutFilters], selectionsWithFilters)
     }
-    Belt.Array.concat([selectionWithoutFilters], selectionsWithFilters)
   }
 }
 
+/**
+Combines two groups of topic selections, so the result matches only the logs matched by both.
+Selections without common values for a topic are dropped, since they can't match any log.
+*/
+let intersectTopicSelections = (a: array<topicSelection>, b: array<topicSelection>) => {
+  let intersectTopic = (a, b) =>
+    switch (a, b) {
+    | ([], topic) | (topic, []) => Some(topic)
+    | _ =>
+      switch a->Js.Array2.filter(topic => b->Js.Array2.includes(topic)) {
+      | [] => None
+      | topic => Some(topic)
+      }
+    }
+
+  a->Belt.Array.flatMap(a =>
+    b->Belt.Array.keepMap(b =>
+      switch (
+        intersectTopic(a.topic0, b.topic0),
+        intersectTopic(a.topic1, b.topic1),
+        intersectTopic(a.topic2, b.topic2),
+        intersectTopic(a.topic3, b.topic3),
+      ) {
+      | (Some(topic0), Some(topic1), Some(topic2), Some(topic3)) =>
+        Some({topic0, topic1, topic2, topic3})
+      | _ => None
+      }
+    )
+  )
+}
+
 type t = {
   addresses: array<Address.t>,
   topicSelections: array<topicSelection>,
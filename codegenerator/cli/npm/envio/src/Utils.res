@@ -300,6 +300,36 @@ module Schema = {
     ->(magic: S.t<Js.Json.t> => S.t<Js.Date.t>)
     ->S.preprocess(_ => {serializer: date => date->magic->Js.Date.toISOString})
 
+  @send external bufferToHex: (unknown, @as("hex") _) => string = "toString"
+
+  // Bytes stored as BYTEA are exposed as 0x prefixed hex strings.
+  // Postgres accepts the \x hex format as input and returns a Buffer on reads.
+  // Hasura isn't converted, so it returns the \x hex format.
+  let dbBytes =
+    S.json(~validate=false)
+    ->(magic: S.t<Js.Json.t> => S.t<string>)
+    ->S.preprocess(s => {
+      parser: unknown =>
+        switch unknown->Js.typeof {
+        | "string" =>
+          let bytes: string = unknown->magic
+          if bytes->Js.String2.startsWith("\\x") {
+            ("0x" ++ bytes->Js.String2.sliceToEnd(~from=2))->magic
+          } else {
+            s.fail(`Bytes from the db need to be a \\x prefixed hex string. Got "${bytes}"`)
+          }
+        | _ => ("0x" ++ unknown->bufferToHex)->magic
+        },
+      serializer: bytes => {
+        let bytes: string = bytes->magic
+        if bytes->Js.String2.startsWith("0x") {
+          ("\\x" ++ bytes->Js.String2.sliceToEnd(~from=2))->magic
+        } else {
+          s.fail(`Bytes need to be a 0x prefixed hex string. Got "${bytes}"`)
+        }
+      },
+    })
+
   // When trying to serialize data to Json pg type, it will fail with
   // PostgresError: column "params" is of type json but expression is of type boolean
   // If there's bool or null on the root level. It works fine as object field values.
@@ -188,6 +188,9 @@ module JsonRpcProvider = {
   @send
   external getBlockNumber: t => promise<int> = "getBlockNumber"
 
+  @send
+  external send: (t, ~method: string, ~params: array<Js.Json.t>) => promise<Js.Json.t> = "send"
+
   type block = {
     _difficulty: bigint,
     difficulty: int,
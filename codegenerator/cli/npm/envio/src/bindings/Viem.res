@@ -50,3 +50,63 @@ let parseLogOrThrow = (
     }
   }
 }
+
+type rec abiParameter = {
+  name: string,
+  @as("type") type_: string,
+  indexed?: bool,
+  components?: array<abiParameter>,
+}
+
+@module("viem")
+external decodeAbiParameters: (array<abiParameter>, hex) => array<unknown> =
+  "decodeAbiParameters"
+
+// Indexed params of dynamic types are stored in topics as a keccak256 hash
+let isHashedWhenIndexed = ({type_}: abiParameter) =>
+  type_ === "string" ||
+  type_ === "bytes" ||
+  type_->Js.String2.startsWith("tuple") ||
+  type_->Js.String2.endsWith("]")
+
+/**
+Viem's decodeEventLog relies on topic0 to find the event,
+so anonymous events are decoded by their inputs instead
+*/
+let parseAnonymousLogOrThrow = (
+  ~eventName,
+  ~inputs: array<abiParameter>,
+  ~topics: array<hex>,
+  ~data: string,
+): decodedEvent<'a> => {
+  try {
+    let bodyInputs = inputs->Js.Array2.filter(input => input.indexed !== Some(true))
+    let bodyValues = decodeAbiParameters(bodyInputs, data->EvmTypes.Hex.fromStringUnsafe)
+    let args = Js.Dict.empty()
+    let topicIndex = ref(0)
+    let bodyIndex = ref(0)
+    inputs->Js.Array2.forEach(input => {
+      let value = switch input.indexed {
+      | Some(true) =>
+        let topic: hex = topics->Js.Array2.unsafe_get(topicIndex.contents)
+        topicIndex := topicIndex.contents + 1
+        if input->isHashedWhenIndexed {
+          topic->(Utils.magic: hex => unknown)
+        } else {
+          decodeAbiParameters([input], topic)->Js.Array2.unsafe_get(0)
+        }
+      | _ =>
+        let value = bodyValues->Js.Array2.unsafe_get(bodyIndex.contents)
+        bodyIndex := bodyIndex.contents + 1
+        value
+      }
+      args->Js.Dict.set(input.name, value)
+    })
+    {
+      eventName,
+      args: args->(Utils.magic: dict<unknown> => 'a),
+    }
+  } catch {
+  | exn => raise(ParseError(exn))
+  }
+}
@@ -16,8 +16,9 @@ type historyFieldsGeneral<'a> = {
 
 type historyFields = historyFieldsGeneral<int>
 
+//The id is the key of the entity in the in memory store. For entities with a composite
+//primary key, it's made of the values of all the key fields.
 type entityIdOnly = {id: string}
-let entityIdOnlySchema = S.schema(s => {id: s.matches(S.string)})
 type entityData<'entity> = Delete(entityIdOnly) | Set('entity)
 
 type historyRow<'entity> = {
@@ -43,17 +44,19 @@ let currentHistoryFieldsSchema = S.object(s => {
   log_index: s.field("entity_history_log_index", S.int),
 })
 
-let makeHistoryRowSchema: S.t<'entity> => S.t<historyRow<'entity>> = entitySchema => {
-  //Maps a schema object for the given entity with all fields nullable except for the id field
+let makeHistoryRowSchema = (~primaryKeyFieldNames=["id"], entitySchema: S.t<'entity>): S.t<
+  historyRow<'entity>,
+> => {
+  //Maps a schema object for the given entity with all fields nullable except for the primary key fields
   //Keeps any original nullable fields
   let nullableEntitySchema: S.t<Js.Dict.t<unknown>> = S.schema(s =>
     switch entitySchema->S.classify {
     | Object({items}) =>
       let nulldict = Js.Dict.empty()
       items->Belt.Array.forEach(({location, schema}) => {
-        let nullableFieldSchema = switch (location, schema->S.classify) {
-        | ("id", _)
-        | (_, Null(_)) => schema //TODO double check this works for array types
+        let nullableFieldSchema = switch schema->S.classify {
+        | _ if primaryKeyFieldNames->Js.Array2.includes(location) => schema
+        | Null(_) => schema //TODO double check this works for array types
         | _ => S.null(schema)->S.toUnknown
         }
 
@@ -103,15 +106,30 @@ let makeHistoryRowSchema: S.t<'entity> => S.t<historyRow<'entity>> = entitySchem
       entityData: switch v["action"] {
       | SET => v["entityData"]->(Utils.magic: Js.Dict.t<unknown> => 'entity)->Set
       | DELETE =>
-        let {id} = v["entityData"]->(Utils.magic: Js.Dict.t<unknown> => entityIdOnly)
-        Delete({id: id})
+        let entityData = v["entityData"]
+        let primaryKeyValues =
+          primaryKeyFieldNames->Js.Array2.map(fieldName => entityData->Js.Dict.unsafeGet(fieldName))
+        Delete({
+          id: switch primaryKeyValues {
+          | [id] => id->Js.String2.make
+          | _ => primaryKeyValues->Table.makeCompositeKey
+          },
+        })
       },
     },
     serializer: v => {
       let (entityData, action) = switch v.entityData {
       | Set(entityData) => (entityData->(Utils.magic: 'entity => Js.Dict.t<unknown>), RowAction.SET)
-      | Delete(entityIdOnly) => (
-          entityIdOnly->(Utils.magic: entityIdOnly => Js.Dict.t<unknown>),
+      | Delete({id}) =>
+        let primaryKeyValues = switch primaryKeyFieldNames {
+        | [_] => [id]
+        | _ => id->Table.parseCompositeKey
+        }
+        (
+          Belt.Array.zip(
+            primaryKeyFieldNames,
+            primaryKeyValues->(Utils.magic: array<string> => array<unknown>),
+          )->Js.Dict.fromArray,
           DELETE,
         )
       }
@@ -197,14 +215,14 @@ let fromTable = (table: table, ~schema: S.t<'entity>): t<'entity> => {
       mkField(fieldName, Integer, ~isNullable=true)
     )
 
-  let id = "id"
+  let primaryKeyFieldNames = table->Table.getPrimaryKeyFieldNames
 
   let dataFields = table.fields->Belt.Array.keepMap(field =>
     switch field {
     | Field(field) =>
       switch field.fieldName {
-      //id is not nullable and should be part of the pk
-      | "id" => {...field, fieldName: id, isPrimaryKey: true}->Field->Some
+      //The primary key fields are not nullable and should be part of the pk
+      | _ if field.isPrimaryKey => field->Field->Some
       //db_write_timestamp can be removed for this. TODO: remove this when we depracate
       //automatic db_write_timestamp creation
       | "db_write_timestamp" => None
@@ -250,6 +268,11 @@ let fromTable = (table: table, ~schema: S.t<'entity>): t<'entity> => {
   let historyTablePath = `"${originSchemaName}"."${historyTableName}"`
   let originTablePath = `"${originSchemaName}"."${originTableName}"`
 
+  let isSamePrimaryKeyStr =
+    primaryKeyFieldNames
+    ->Belt.Array.map(fieldName => `"${fieldName}" = ${historyRowArg}."${fieldName}"`)
+    ->Js.Array2.joinWith(" AND ")
+
   let previousHistoryFieldsAreNullStr =
     previousChangeFieldNames
     ->Belt.Array.map(fieldName => `${historyRowArg}.${fieldName} IS NULL`)
@@ -280,7 +303,7 @@ let fromTable = (table: table, ~schema: S.t<'entity>): t<'entity> => {
           -- Find the most recent record for the same id
           SELECT ${currentChangeFieldNamesCommaSeparated} INTO v_previous_record
           FROM ${historyTablePath}
-          WHERE ${id} = ${historyRowArg}.${id}
+          WHERE ${isSamePrimaryKeyStr}
           ORDER BY ${currentChangeFieldNames
       ->Belt.Array.map(fieldName => fieldName ++ " DESC")
       ->Js.Array2.joinWith(", ")}
@@ -295,7 +318,7 @@ let fromTable = (table: table, ~schema: S.t<'entity>): t<'entity> => {
       ->Js.Array2.joinWith(" ")}
             ElSIF should_copy_current_entity THEN
             -- Check if a value for the id exists in the origin table and if so, insert a history row for it.
-            SELECT ${dataFieldNamesCommaSeparated} FROM ${originTablePath} WHERE id = ${historyRowArg}.${id} INTO v_origin_record;
+            SELECT ${dataFieldNamesCommaSeparated} FROM ${originTablePath} WHERE ${isSamePrimaryKeyStr} INTO v_origin_record;
             IF FOUND THEN
               INSERT INTO ${historyTablePath} (${currentChangeFieldNamesCommaSeparated}, ${dataFieldNamesCommaSeparated}, "${actionFieldName}")
               -- SET the current change data fields to 0 since we don't know what they were
@@ -333,7 +356,7 @@ let fromTable = (table: table, ~schema: S.t<'entity>): t<'entity> => {
   let insertFn: (Postgres.sql, Js.Json.t, ~shouldCopyCurrentEntity: bool) => promise<unit> =
     insertFnString->Table.PostgresInterop.eval
 
-  let schema = makeHistoryRowSchema(schema)
+  let schema = makeHistoryRowSchema(schema, ~primaryKeyFieldNames)
 
   {table, createInsertFnQuery, schema, schemaRows: S.array(schema), insertFn}
 }
@@ -5,6 +5,7 @@ type derived
 @unboxed
 type fieldType =
   | @as("INTEGER") Integer
+  | @as("BIGINT") Int8
   | @as("BOOLEAN") Boolean
   | @as("NUMERIC") Numeric
   | @as("DOUBLE PRECISION") DoublePrecision
@@ -14,6 +15,7 @@ type fieldType =
   | @as("TIMESTAMP WITH TIME ZONE") Timestamp
   | @as("TIMESTAMP") TimestampWithoutTimezone
   | @as("TIMESTAMP WITH TIME ZONE NULL") TimestampWithNullTimezone
+  | @as("BYTEA") Bytea
   | Custom(string)
 
 type field = {
@@ -25,6 +27,9 @@ type field = {
   isIndex: bool,
   linkedEntity: option<string>,
   defaultValue: option<string>,
+  description: option<string>,
+  // Previous name of the field, the column is renamed instead of recreated
+  renamedFrom: option<string>,
 }
 
 type derivedFromField = {
@@ -42,6 +47,8 @@ let mkField = (
   ~isPrimaryKey=false,
   ~isIndex=false,
   ~linkedEntity=?,
+  ~description=?,
+  ~renamedFrom=?,
   fieldName,
   fieldType,
 ) =>
@@ -54,6 +61,8 @@ let mkField = (
     isIndex,
     linkedEntity,
     defaultValue: default,
+    description,
+    renamedFrom,
   }->Field
 
 let mkDerivedFromField = (fieldName, ~derivedFromEntity, ~derivedFromField) =>
@@ -74,6 +83,11 @@ let isLinkedEntityField = field => field.linkedEntity->Option.isSome
 let getDbFieldName = field =>
   field->isLinkedEntityField ? field.fieldName ++ "_id" : field.fieldName
 
+let getRenamedFromDbFieldName = field =>
+  field.renamedFrom->Option.map(renamedFrom =>
+    field->isLinkedEntityField ? renamedFrom ++ "_id" : renamedFrom
+  )
+
 let getFieldName = fieldOrDerived =>
   switch fieldOrDerived {
   | Field(field) => field->getDbFieldName
@@ -84,18 +98,112 @@ let getFieldType = (field: field) => {
   (field.fieldType :> string) ++ (field.isArray ? "[]" : "")
 }
 
+type indexMethod =
+  | @as("btree") Btree
+  | @as("hash") Hash
+  | @as("gin") Gin
+  | @as("brin") Brin
+
+type indexOrder =
+  | @as("ASC") Asc
+  | @as("DESC") Desc
+
+type compositeIndex = {
+  fieldNames: array<string>,
+  method: option<indexMethod>,
+  order: option<indexOrder>,
+  where: option<string>,
+}
+
+let mkCompositeIndex = (~method=?, ~order=?, ~where=?, fieldNames) => {
+  fieldNames,
+  method,
+  order,
+  where,
+}
+
+let hasIndexOptions = ({method, order, where}: compositeIndex) =>
+  method->Option.isSome || order->Option.isSome || where->Option.isSome
+
+type aggregationInterval =
+  | @as("hour") Hour
+  | @as("day") Day
+
+type aggregateFn =
+  | @as("sum") Sum
+  | @as("count") Count
+  | @as("min") Min
+  | @as("max") Max
+  | @as("first") First
+  | @as("last") Last
+
+type aggregate = {
+  fieldName: string,
+  fn: aggregateFn,
+  arg: option<string>,
+}
+
+let mkAggregate = (~arg=?, fieldName, fn) => {
+  fieldName,
+  fn,
+  arg,
+}
+
+type aggregation = {
+  source: string,
+  intervals: array<aggregationInterval>,
+  dimensions: array<string>,
+  aggregates: array<aggregate>,
+}
+
+// Stored as a generated tsvector column with a GIN index
+type fullTextSearch = {
+  name: string,
+  fields: array<string>,
+  language: string,
+}
+
+let mkFullTextSearch = (name, ~fields, ~language) => {
+  name,
+  fields,
+  language,
+}
+
 type table = {
   tableName: string,
   schemaName: string,
   fields: array<fieldOrDerived>,
-  compositeIndices: array<array<string>>,
+  compositeIndices: array<compositeIndex>,
+  // Immutable tables are insert only, existing rows are never updated
+  isImmutable: bool,
+  // Aggregation tables are rolled up from their source timeseries table after each batch
+  aggregation: option<aggregation>,
+  fullTextSearches: array<fullTextSearch>,
+  description: option<string>,
+  // Previous name of the table, the table is renamed instead of recreated
+  renamedFrom: option<string>,
 }
 
-let mkTable = (tableName, ~schemaName, ~compositeIndices=[], ~fields) => {
+let mkTable = (
+  tableName,
+  ~schemaName,
+  ~compositeIndices=[],
+  ~isImmutable=false,
+  ~aggregation=?,
+  ~fullTextSearches=[],
+  ~description=?,
+  ~renamedFrom=?,
+  ~fields,
+) => {
   tableName,
   schemaName,
   fields,
   compositeIndices,
+  isImmutable,
+  aggregation,
+  fullTextSearches,
+  description,
+  renamedFrom,
 }
 
 let getPrimaryKeyFieldNames = table =>
@@ -106,6 +214,19 @@ let getPrimaryKeyFieldNames = table =>
     }
   )
 
+//Entities with a composite primary key are keyed in the in memory store
+//by a JSON array of the stringified key values, in the order of the key fields.
+//The DB queries parse it back to the values of the primary key columns.
+let makeCompositeKey = (values: 'tuple): string =>
+  values
+  ->(Utils.magic: 'tuple => array<unknown>)
+  ->Js.Array2.map(value => value->Js.String2.make)
+  ->Js.Json.stringArray
+  ->Js.Json.stringify
+
+let parseCompositeKey = (key: string): array<string> =>
+  key->Js.Json.parseExn->(Utils.magic: Js.Json.t => array<string>)
+
 let getFields = table =>
   table.fields->Array.keepMap(field =>
     switch field {
@@ -165,15 +286,16 @@ exception NonExistingTableField(string)
 Gets all composite indicies (whether they are single indices or not)
 And maps the fields defined to their actual db name (some have _id suffix)
 */
-let getUnfilteredCompositeIndicesUnsafe = (table): array<array<string>> => {
-  table.compositeIndices->Array.map(compositeIndex =>
-    compositeIndex->Array.map(userDefinedFieldName =>
+let getUnfilteredCompositeIndicesUnsafe = (table): array<compositeIndex> => {
+  table.compositeIndices->Array.map(compositeIndex => {
+    ...compositeIndex,
+    fieldNames: compositeIndex.fieldNames->Array.map(userDefinedFieldName =>
       switch table->getFieldByName(userDefinedFieldName) {
       | Some(field) => field->getFieldName
       | None => raise(NonExistingTableField(userDefinedFieldName)) //Unexpected should be validated in schema parser
       }
-    )
-  )
+    ),
+  })
 }
 
 type sqlParams<'entity> = {
@@ -275,8 +397,10 @@ let getSingleIndices = (table): array<string> => {
   table
   ->getUnfilteredCompositeIndicesUnsafe
   //get all composite indices with only 1 field defined
-  //this is still a single index
-  ->Array.keep(cidx => cidx->Array.length == 1)
+  //and no options, this is still a single index
+  ->Array.keepMap(cidx =>
+    cidx.fieldNames->Array.length == 1 && !(cidx->hasIndexOptions) ? Some(cidx.fieldNames) : None
+  )
   ->Array.concat([indexFields])
   ->Array.concatMany
   ->Set.String.fromArray
@@ -285,13 +409,87 @@ let getSingleIndices = (table): array<string> => {
 }
 
 /*
-Gets all composite indicies
+Gets all composite indicies and single indices with options
 And maps the fields defined to their actual db name (some have _id suffix)
 */
-let getCompositeIndices = (table): array<array<string>> => {
+let getCompositeIndices = (table): array<compositeIndex> => {
   table
   ->getUnfilteredCompositeIndicesUnsafe
-  ->Array.keep(ind => ind->Array.length > 1)
+  ->Array.keep(ind => ind.fieldNames->Array.length > 1 || ind->hasIndexOptions)
+}
+
+/*
+Makes the query getting the earliest and the latest timestamp of the source timeseries rows
+with the ids passed as the $1 param. Used to find the buckets affected by
+rows which are about to be deleted by a rollback.
+*/
+let makeAggregationSourceTimestampRangeQuery = (table, ~aggregation: aggregation) =>
+  `SELECT MIN("timestamp") AS "fromTimestamp", MAX("timestamp") AS "toTimestamp" FROM "${table.schemaName}"."${aggregation.source}" WHERE "id"::text = ANY($1::text[]);`
+
+/*
+Makes the queries recomputing the rows of an aggregation table for every interval,
+from the bucket of the timestamp passed as the $1 param to the bucket of the $2 param.
+Rows are deleted and recomputed from the source timeseries table,
+so running the rollup again or after a rollback doesn't double count.
+Buckets are truncated in UTC, converting the timestamps to local UTC time and back,
+since date_trunc only takes a time zone from Postgres 14.
+The queries need to run in the returned order.
+*/
+let makeAggregationRollupQueries = (table, ~aggregation: aggregation): array<string> => {
+  let tableName = `"${table.schemaName}"."${table.tableName}"`
+  let sourceTableName = `"${table.schemaName}"."${aggregation.source}"`
+  let quotedDimensions = aggregation.dimensions->Array.map(dimension => `"${dimension}"`)
+  let quotedAggregateFieldNames =
+    aggregation.aggregates->Array.map(({fieldName}) => `"${fieldName}"`)
+
+  let aggregateExprs = aggregation.aggregates->Array.map(({fieldName, fn, arg}) => {
+    let expr = switch (fn, arg) {
+    | (Count, _) => "COUNT(*)"
+    | (Sum, Some(arg)) => `SUM("${arg}")`
+    | (Min, Some(arg)) => `MIN("${arg}")`
+    | (Max, Some(arg)) => `MAX("${arg}")`
+    | (First, Some(arg)) => `(array_agg("${arg}" ORDER BY "timestamp" ASC, "id" ASC))[1]`
+    | (Last, Some(arg)) => `(array_agg("${arg}" ORDER BY "timestamp" DESC, "id" DESC))[1]`
+    //Unexpected should be validated in schema parser
+    | (Sum | Min | Max | First | Last, None) => raise(NonExistingTableField(fieldName))
+    }
+    let fieldType = switch table->getFieldByDbName(fieldName) {
+    | Some(Field(field)) => field->getFieldType
+    | _ => raise(NonExistingTableField(fieldName)) //Unexpected should be validated in schema parser
+    }
+    `(${expr})::${fieldType}`
+  })
+
+  aggregation.intervals
+  ->Array.map(interval => {
+    let interval = (interval :> string)
+    let truncUtc = timestamp => `date_trunc('${interval}', ${timestamp} AT TIME ZONE 'UTC')`
+    let bucket = `(${truncUtc(`"timestamp"`)} AT TIME ZONE 'UTC')`
+    let fromBucket = `(${truncUtc("$1::timestamptz")} AT TIME ZONE 'UTC')`
+    //The end of the bucket of $2 is added before converting back, to be unaffected by DST
+    let toBucketEnd = `((${truncUtc("$2::timestamptz")} + interval '1 ${interval}') AT TIME ZONE 'UTC')`
+    let bucketsFilter = `"timestamp" >= ${fromBucket} AND "timestamp" < ${toBucketEnd}`
+    let id =
+      [`'${interval}'`, `EXTRACT(EPOCH FROM ${bucket})::bigint`]
+      ->Array.concat(quotedDimensions)
+      ->Js.Array2.joinWith(", ")
+
+    [
+      `DELETE FROM ${tableName} WHERE "interval" = '${interval}' AND ${bucketsFilter};`,
+      `INSERT INTO ${tableName} (${["\"id\"", "\"interval\"", "\"timestamp\""]
+        ->Array.concat(quotedDimensions)
+        ->Array.concat(quotedAggregateFieldNames)
+        ->Js.Array2.joinWith(", ")})
+SELECT ${[`concat_ws('-', ${id})`, `'${interval}'`, bucket]
+        ->Array.concat(quotedDimensions)
+        ->Array.concat(aggregateExprs)
+        ->Js.Array2.joinWith(", ")}
+FROM ${sourceTableName}
+WHERE ${bucketsFilter}
+GROUP BY ${[bucket]->Array.concat(quotedDimensions)->Js.Array2.joinWith(", ")};`,
+    ]
+  })
+  ->Array.concatMany
 }
 
 module PostgresInterop = {
@@ -299,18 +497,21 @@ module PostgresInterop = {
   type batchSetFn<'a> = (Postgres.sql, array<'a>) => promise<unit>
   external eval: string => 'a = "eval"
 
+  // Immutable tables have no conflict clause, so inserting an existing row fails
   let makeBatchSetFnString = (table: table) => {
     let fieldNamesInQuotes =
       table->getNonDefaultFieldNames->Array.map(fieldName => `"${fieldName}"`)
     `(sql, rows) => {
       return sql\`
         INSERT INTO "${table.schemaName}"."${table.tableName}"
-        \${sql(rows, ${fieldNamesInQuotes->Js.Array2.joinWith(", ")})}
+        \${sql(rows, ${fieldNamesInQuotes->Js.Array2.joinWith(", ")})}${table.isImmutable
+      ? ";"
+      : `
         ON CONFLICT(${table->getPrimaryKeyFieldNames->Js.Array2.joinWith(", ")}) DO UPDATE
         SET
         ${fieldNamesInQuotes
-      ->Array.map(fieldNameInQuotes => `${fieldNameInQuotes} = EXCLUDED.${fieldNameInQuotes}`)
-      ->Js.Array2.joinWith(", ")};\`
+          ->Array.map(fieldNameInQuotes => `${fieldNameInQuotes} = EXCLUDED.${fieldNameInQuotes}`)
+          ->Js.Array2.joinWith(", ")};`}\`
     }`
   }
 
@@ -142,16 +142,49 @@ module QueryTypes = {
 
   let logFieldSelectionSchema = S.array(logFieldOptionsSchema)
 
+  type traceFieldOptions =
+    | @as("from") From
+    | @as("to") To
+    | @as("call_type") CallType
+    | @as("input") Input
+    | @as("output") Output
+    | @as("block_number") BlockNumber
+    | @as("trace_address") TraceAddress
+    | @as("transaction_hash") TransactionHash
+    | @as("transaction_position") TransactionPosition
+    | @as("type") Type
+    | @as("error") Error
+
+  let traceFieldOptionsSchema = S.enum([
+    From,
+    To,
+    CallType,
+    Input,
+    Output,
+    BlockNumber,
+    TraceAddress,
+    TransactionHash,
+    TransactionPosition,
+    Type,
+    Error,
+  ])
+
+  type traceFieldSelection = array<traceFieldOptions>
+
+  let traceFieldSelectionSchema = S.array(traceFieldOptionsSchema)
+
   type fieldSelection = {
     block?: blockFieldSelection,
     transaction?: transactionFieldSelection,
     log?: logFieldSelection,
+    trace?: traceFieldSelection,
   }
 
   let fieldSelectionSchema = S.object(s => {
     block: ?s.field("block", S.option(blockFieldSelectionSchema)),
     transaction: ?s.field("transaction", S.option(transactionFieldSelectionSchema)),
     log: ?s.field("log", S.option(logFieldSelectionSchema)),
+    trace: ?s.field("trace", S.option(traceFieldSelectionSchema)),
   })
 
   type logParams = {
@@ -176,11 +209,24 @@ module QueryTypes = {
     sighash: ?s.field("sighash", S.option(S.array(S.string))),
   })
 
+  type traceParams = {
+    to?: array<Address.t>,
+    callType?: array<string>,
+    sighash?: array<string>,
+  }
+
+  let traceParamsSchema = S.object(s => {
+    to: ?s.field("to", S.option(S.array(Address.schema))),
+    callType: ?s.field("call_type", S.option(S.array(S.string))),
+    sighash: ?s.field("sighash", S.option(S.array(S.string))),
+  })
+
   type postQueryBody = {
     fromBlock: int,
     toBlockExclusive?: int,
     logs?: array<logParams>,
     transactions?: array<transactionParams>,
+    traces?: array<traceParams>,
     fieldSelection: fieldSelection,
     maxNumLogs?: int,
     includeAllBlocks?: bool,
@@ -191,6 +237,7 @@ module QueryTypes = {
     toBlockExclusive: ?s.field("to_block", S.option(S.int)),
     logs: ?s.field("logs", S.option(S.array(logParamsSchema))),
     transactions: ?s.field("transactions", S.option(S.array(transactionParamsSchema))),
+    traces: ?s.field("traces", S.option(S.array(traceParamsSchema))),
     fieldSelection: s.field("field_selection", fieldSelectionSchema),
     maxNumLogs: ?s.field("max_num_logs", S.option(S.int)),
     includeAllBlocks: ?s.field("include_all_blocks", S.option(S.bool)),
@@ -330,16 +377,46 @@ module ResponseTypes = {
     topic3: ?s.field("topic3", S.option(S.null(EvmTypes.Hex.schema))),
   })
 
+  type traceData = {
+    from?: option<unchecksummedEthAddress>,
+    to?: option<unchecksummedEthAddress>,
+    callType?: option<string>,
+    input?: option<string>,
+    output?: option<string>,
+    blockNumber?: int,
+    traceAddress?: option<array<int>>,
+    transactionHash?: option<string>,
+    transactionPosition?: option<int>,
+    type_?: option<string>,
+    error?: option<string>,
+  }
+
+  let traceDataSchema = S.object(s => {
+    from: ?s.field("from", S.option(S.null(S.string))),
+    to: ?s.field("to", S.option(S.null(S.string))),
+    callType: ?s.field("call_type", S.option(S.null(S.string))),
+    input: ?s.field("input", S.option(S.null(S.string))),
+    output: ?s.field("output", S.option(S.null(S.string))),
+    blockNumber: ?s.field("block_number", S.option(S.int)),
+    traceAddress: ?s.field("trace_address", S.option(S.null(S.array(S.int)))),
+    transactionHash: ?s.field("transaction_hash", S.option(S.null(S.string))),
+    transactionPosition: ?s.field("transaction_position", S.option(S.null(S.int))),
+    type_: ?s.field("type", S.option(S.null(S.string))),
+    error: ?s.field("error", S.option(S.null(S.string))),
+  })
+
   type data = {
     blocks?: array<blockData>,
     transactions?: array<transactionData>,
     logs?: array<logData>,
+    traces?: array<traceData>,
   }
 
   let dataSchema = S.object(s => {
     blocks: ?s.field("blocks", S.array(blockDataSchema)->S.option),
     transactions: ?s.field("transactions", S.array(transactionDataSchema)->S.option),
     logs: ?s.field("logs", S.array(logDataSchema)->S.option),
+    traces: ?s.field("traces", S.array(traceDataSchema)->S.option),
   })
 
   type queryResponse = {
@@ -179,3 +179,63 @@ module GetBlockByNumber = {
 module GetBlockHeight = {
   let route = makeRpcRoute("eth_blockNumber", S.tuple(_ => ()), hexIntSchema)
 }
+
+module TraceFilter = {
+  type param = {
+    fromBlock: int,
+    toBlock: int,
+    toAddress: array<Address.t>,
+  }
+
+  let paramsSchema = S.object((s): param => {
+    fromBlock: s.field("fromBlock", hexIntSchema),
+    toBlock: s.field("toBlock", hexIntSchema),
+    toAddress: s.field("toAddress", S.array(Address.schema)),
+  })
+
+  // Only call actions have all the fields,
+  // create, suicide and reward actions have a different shape
+  type action = {
+    callType: option<string>,
+    from: option<Address.t>,
+    to: option<Address.t>,
+    input: option<hex>,
+  }
+
+  let actionSchema = S.object((s): action => {
+    callType: s.field("callType", S.option(S.string)),
+    from: s.field("from", S.option(Address.schema)),
+    to: s.field("to", S.option(Address.schema)),
+    input: s.field("input", S.option(S.string)),
+  })
+
+  type result = {output: option<hex>}
+
+  let resultSchema = S.object((s): result => {
+    output: s.field("output", S.option(S.string)),
+  })
+
+  type trace = {
+    action: action,
+    // Failed traces have an error instead of a result,
+    // which is either null or missing depending on the client
+    result: option<option<result>>,
+    error: option<string>,
+    blockNumber: int,
+    traceAddress: array<int>,
+    transactionHash: option<hex>,
+    transactionPosition: option<int>,
+    @as("type") type_: string,
+  }
+
+  let traceSchema = S.object((s): trace => {
+    action: s.field("action", actionSchema),
+    result: s.field("result", S.option(S.null(resultSchema))),
+    error: s.field("error", S.option(S.string)),
+    blockNumber: s.field("blockNumber", S.int),
+    traceAddress: s.field("traceAddress", S.array(S.int)),
+    transactionHash: s.field("transactionHash", S.null(S.string)),
+    transactionPosition: s.field("transactionPosition", S.null(S.int)),
+    type_: s.field("type", S.string),
+  })
+}
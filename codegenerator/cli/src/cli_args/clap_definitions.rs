@@ -1,6 +1,6 @@
 use crate::constants::project_paths::{DEFAULT_CONFIG_PATH, DEFAULT_GENERATED_PATH};
 
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use clap_markdown::MarkdownOptions;
 use strum::{Display, EnumIter, EnumString};
 use subenum::subenum;
@@ -66,6 +66,13 @@ pub enum CommandType {
     ///Start the indexer without any automatic codegen
     Start(StartArgs),
 
+    ///Commands for inspecting the schema
+    #[command(subcommand)]
+    Schema(SchemaSubcommands),
+
+    ///Generate a Markdown reference of the indexer's networks, contracts, events and entities
+    Docs(DocsArgs),
+
     #[clap(hide = true)]
     #[command(subcommand)]
     Script(Script),
@@ -98,6 +105,36 @@ pub struct StartArgs {
     pub bench: bool,
 }
 
+#[derive(Debug, Args)]
+pub struct DocsArgs {
+    ///The directory within the project that the docs should be written to
+    #[arg(long, default_value_t = String::from("docs"))]
+    pub docs_directory: String,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SchemaSubcommands {
+    ///Print a diagram of the schema entities and their relationships
+    Graph(SchemaGraphArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct SchemaGraphArgs {
+    ///The format of the printed diagram
+    #[arg(short, long, default_value_t = GraphFormat::Mermaid)]
+    #[clap(value_enum)]
+    pub format: GraphFormat,
+    ///Include the entities written to by each registered event handler
+    #[arg(short, long, action)]
+    pub events: bool,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum GraphFormat {
+    Mermaid,
+    Dot,
+}
+
 #[derive(Debug, Subcommand)]
 pub enum LocalCommandTypes {
     /// Local Envio and ganache environment commands
@@ -58,6 +58,10 @@ pub mod evm {
                         event: EvmAbi::event_signature_from_abi_event(&event),
                         name: None,
                         field_selection: None,
+                        filters: None,
+                        wildcard: None,
+                        start_block: None,
+                        end_block: None,
                     })
                     .collect();
 
@@ -72,6 +76,8 @@ pub mod evm {
                             abi_file_path: None,
                             handler,
                             events,
+                            calls: None,
+                            wildcard: None,
                         },
                     };
 
@@ -92,6 +98,8 @@ pub mod evm {
                         abi_file_path: None,
                         handler,
                         events,
+                        calls: None,
+                        wildcard: None,
                     })
                 };
 
@@ -134,12 +142,17 @@ pub mod evm {
                                 end_block,
                                 confirmed_block_threshold: None,
                                 contracts: Vec::new(),
+                                block_handlers: None,
+                                transactions: None,
                             }
                         });
 
                     let contract = NetworkContract {
                         name: selected_contract.name.clone(),
                         address,
+                        factory: None,
+                        start_block: None,
+                        end_block: None,
                         config: config.clone(),
                     };
 
@@ -282,6 +295,9 @@ pub mod fuel {
                                     .map(|a| a.to_string())
                                     .collect::<Vec<String>>()
                                     .into(),
+                                factory: None,
+                                start_block: None,
+                                end_block: None,
                                 config: Some(ContractConfig {
                                     abi_file_path: selected_contract.get_vendored_abi_file_path(),
                                     handler: init_config.language.get_event_handler_directory(),
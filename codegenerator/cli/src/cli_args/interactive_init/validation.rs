@@ -127,43 +127,56 @@ mod tests {
     }
 }
 
-fn are_events_equivalent(event1: &ethers::abi::Event, event2: &ethers::abi::Event) -> bool {
+fn have_same_param_types(event1: &ethers::abi::Event, event2: &ethers::abi::Event) -> bool {
     event1.name == event2.name
         && event1.inputs.len() == event2.inputs.len()
         && event1
             .inputs
             .iter()
             .zip(&event2.inputs)
-            .all(|(input1, input2)| input1.kind == input2.kind && input1.indexed == input2.indexed)
+            .all(|(input1, input2)| input1.kind == input2.kind)
+}
+
+fn are_events_equivalent(event1: &ethers::abi::Event, event2: &ethers::abi::Event) -> bool {
+    have_same_param_types(event1, event2)
+        && event1
+            .inputs
+            .iter()
+            .zip(&event2.inputs)
+            .all(|(input1, input2)| input1.indexed == input2.indexed)
 }
 
+///Removes duplicate events from the abi. Overloaded events with different param types
+///are kept, since each overload can be selected by its signature
 pub fn filter_duplicate_events(
     events: BTreeMap<String, Vec<ethers::abi::Event>>,
 ) -> BTreeMap<String, Vec<ethers::abi::Event>> {
     let mut filtered_events: BTreeMap<String, Vec<ethers::abi::Event>> = BTreeMap::new();
 
     for (event_name, event_list) in events {
-        if event_list.len() > 1 {
-            let first_event = event_list[0].clone();
-            for event in event_list.iter().skip(1) {
-                if !are_events_equivalent(&first_event, &event) {
-                    let warning_message = "Note: this is unimplemented! The code might behave \
-                                           unexpectedly.\n"
-                        .red()
-                        .bold();
-                    println!("{}", warning_message);
-                    println!(
-                        "Found duplicate event: {} in contract abi. This event will be ignored. However, this second ignored event has the same name as the first event, but different inputs. Handling this is currently unimplemented. Please ask the team on discord, or comment on our github issue if this is affecting you.\n\nhttps://github.com/enviodev/envio-hyperindexer-issues/issues/1\n",
-                        event_name
-                    );
+        let mut unique_events: Vec<ethers::abi::Event> = vec![];
+        for event in event_list {
+            match unique_events
+                .iter()
+                .find(|unique_event| have_same_param_types(unique_event, &event))
+            {
+                Some(unique_event) => {
+                    if !are_events_equivalent(unique_event, &event) {
+                        let warning_message = "Note: this is unimplemented! The code might \
+                                               behave unexpectedly.\n"
+                            .red()
+                            .bold();
+                        println!("{}", warning_message);
+                        println!(
+                            "Found duplicate event: {} in contract abi. This event will be ignored. However, this second ignored event has the same param types as the first event, but different indexed params. Both events have the same topic0, so they can't be told apart. Please ask the team on discord, or comment on our github issue if this is affecting you.\n\nhttps://github.com/enviodev/envio-hyperindexer-issues/issues/1\n",
+                            event_name
+                        );
+                    }
                 }
+                None => unique_events.push(event),
             }
-
-            filtered_events.insert(event_name, vec![first_event]);
-        } else {
-            filtered_events.insert(event_name, event_list);
-            continue;
         }
+        filtered_events.insert(event_name, unique_events);
     }
 
     filtered_events
@@ -236,7 +236,13 @@ pub mod db_migrate {
     use std::process::ExitStatus;
 
     use super::execute_command;
-    use crate::{persisted_state::PersistedState, project_paths::ParsedProjectPaths};
+    use crate::{
+        config_parsing::system_config::SystemConfig,
+        persisted_state::{
+            read_db_enum_values, PersistedState, PersistedStateExists, SchemaMigrations,
+        },
+        project_paths::ParsedProjectPaths,
+    };
 
     pub async fn run_up_migrations(
         project_paths: &ParsedProjectPaths,
@@ -257,6 +263,99 @@ pub mod db_migrate {
         Ok(())
     }
 
+    ///Applies the @renamedFrom hints of the schema to the existing tables,
+    ///keeping the indexed data
+    async fn run_rename_migrations(project_paths: &ParsedProjectPaths) -> anyhow::Result<()> {
+        let args = vec!["db-rename"];
+        let current_dir = &project_paths.generated;
+        let exit = execute_command("pnpm", args, current_dir).await?;
+
+        if !exit.success() {
+            return Err(anyhow!("Failed to run db rename migrations"));
+        }
+        Ok(())
+    }
+
+    ///Adds the values appended to the schema enums to their existing db types,
+    ///keeping the indexed data
+    async fn run_enum_migrations(project_paths: &ParsedProjectPaths) -> anyhow::Result<()> {
+        let args = vec!["db-add-enum-values"];
+        let current_dir = &project_paths.generated;
+        let exit = execute_command("pnpm", args, current_dir).await?;
+
+        if !exit.success() {
+            return Err(anyhow!("Failed to run db enum migrations"));
+        }
+        Ok(())
+    }
+
+    ///Migrates the db schema from the persisted state on the db to the current one,
+    ///keeping the indexed data, when the schema only changed by @renamedFrom hints
+    ///and values appended to enums. Returns false without touching the db when
+    ///there are other changes, which need the db to be set up again.
+    pub async fn run_schema_migrations(
+        project_paths: &ParsedProjectPaths,
+        config: &SystemConfig,
+        current_state: &PersistedState,
+        persisted_state_db: &PersistedStateExists,
+    ) -> anyhow::Result<bool> {
+        let persisted_state = match persisted_state_db {
+            PersistedStateExists::Exists(persisted_state) => persisted_state,
+            PersistedStateExists::NotExists | PersistedStateExists::Corrupted => return Ok(false),
+        };
+
+        let db_enum_values = read_db_enum_values()
+            .await
+            .context("Failed reading enum types from db")?;
+
+        for enm in config.schema.enums.values() {
+            let values_change = match db_enum_values.get(&enm.name.to_lowercase()) {
+                Some(db_values) => enm.get_values_change(db_values),
+                None => continue,
+            };
+            if values_change.is_destructive() {
+                println!(
+                    "Values of enum {} were removed or reordered ({:?}). This is a destructive \
+                     change that requires recreating the db",
+                    enm.name, values_change
+                );
+            }
+        }
+
+        let previous_schema_hashes =
+            PersistedState::get_previous_schema_hashes(config, &db_enum_values)
+                .context("Failed getting schema hashes before the migrations")?;
+
+        let SchemaMigrations {
+            renames,
+            enum_additions,
+        } = match current_state.get_schema_migrations(persisted_state, &previous_schema_hashes) {
+            Some(schema_migrations) => schema_migrations,
+            None => return Ok(false),
+        };
+
+        //Renames run first, so the db only differs from the schema by the enum values after
+        if renames {
+            println!("Running db rename migrations");
+            run_rename_migrations(project_paths)
+                .await
+                .context("Failed running db rename migrations command")?;
+        }
+        if enum_additions {
+            println!("Running db enum migrations");
+            run_enum_migrations(project_paths)
+                .await
+                .context("Failed running db enum migrations command")?;
+        }
+
+        //Only persisted once all the migrations ran, so a failed one is retried
+        current_state
+            .upsert_to_db()
+            .await
+            .context("Failed to upsert persisted state table")?;
+        Ok(true)
+    }
+
     pub async fn run_drop_schema(project_paths: &ParsedProjectPaths) -> anyhow::Result<ExitStatus> {
         let args = vec!["db-down"];
         let current_dir = &project_paths.generated;
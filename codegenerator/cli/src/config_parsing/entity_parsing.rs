@@ -1,22 +1,23 @@
 use super::{
     postgres_types::{Field as PGField, Primitive as PGPrimitive},
-    validation::{
-        check_enums_for_internal_reserved_words, check_names_from_schema_for_reserved_words,
-        is_valid_postgres_db_name,
-    },
+    validation::{check_enums_for_internal_reserved_words, is_valid_postgres_db_name},
 };
 use crate::{
     constants::project_paths::DEFAULT_SCHEMA_PATH,
     hbs_templating::codegen_templates::DerivedFieldTemplate,
     project_paths::{path_utils, ParsedProjectPaths},
-    rescript_types::RescriptTypeIdent,
-    utils::{text::Capitalize, unique_hashmap},
+    rescript_types::{RescriptRecordField, RescriptTypeIdent},
+    utils::{
+        normalized_list::SingleOrList,
+        text::{find_closest_match, Capitalize},
+        unique_hashmap,
+    },
 };
 use anyhow::{anyhow, Context};
 use ethers::abi::ethabi::ParamType as EthAbiParamType;
 use graphql_parser::schema::{
-    Definition, Directive, Document, EnumType, Field as ObjField, ObjectType, Type as ObjType,
-    TypeDefinition, Value,
+    Definition, Directive, Document, EnumType, Field as ObjField, ObjectType, ScalarType,
+    Type as ObjType, TypeDefinition, Value,
 };
 use itertools::Itertools;
 use serde::{Serialize, Serializer};
@@ -31,11 +32,22 @@ use subenum::subenum;
 pub struct Schema {
     pub entities: HashMap<String, Entity>,
     pub enums: HashMap<String, GraphQLEnum>,
+    pub scalars: HashMap<String, CustomScalar>,
+}
+
+///Schema printed with [`Schema::print_for_hash`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintedSchema {
+    pub current: String,
+    ///The schema as it was before applying the @renamedFrom hints.
+    ///None if there are no hints in the schema.
+    pub before_renames: Option<String>,
 }
 
 enum TypeDef<'a> {
     Entity(&'a Entity),
     Enum,
+    Scalar(&'a CustomScalar),
 }
 
 impl Schema {
@@ -43,10 +55,15 @@ impl Schema {
         Schema {
             entities: HashMap::new(),
             enums: HashMap::new(),
+            scalars: HashMap::new(),
         }
     }
 
-    pub fn new(entities: Vec<Entity>, enums: Vec<GraphQLEnum>) -> anyhow::Result<Self> {
+    pub fn new(
+        entities: Vec<Entity>,
+        enums: Vec<GraphQLEnum>,
+        scalars: Vec<CustomScalar>,
+    ) -> anyhow::Result<Self> {
         let entities = unique_hashmap::from_vec_no_duplicates(
             entities.into_iter().map(|e| (e.name.clone(), e)).collect(),
         )
@@ -55,11 +72,20 @@ impl Schema {
             enums.into_iter().map(|e| (e.name.clone(), e)).collect(),
         )
         .context("Found enums with duplicate names")?;
+        let scalars = unique_hashmap::from_vec_no_duplicates(
+            scalars.into_iter().map(|s| (s.name.clone(), s)).collect(),
+        )
+        .context("Found scalars with duplicate names")?;
 
-        Self { entities, enums }.validate()
+        Self {
+            entities,
+            enums,
+            scalars,
+        }
+        .validate()
     }
 
-    fn from_document(document: Document<String>) -> anyhow::Result<Self> {
+    pub fn from_document(document: Document<String>) -> anyhow::Result<Self> {
         let entities = document
             .definitions
             .iter()
@@ -90,60 +116,176 @@ impl Schema {
             .collect::<anyhow::Result<Vec<GraphQLEnum>>>()
             .context("Failed constructing enums in schema from document")?;
 
-        Self::new(entities, enums)
+        let scalars = document
+            .definitions
+            .iter()
+            .filter_map(|d| match d {
+                Definition::TypeDefinition(TypeDefinition::Scalar(scalar)) => Some(scalar),
+                _ => None,
+            })
+            .filter_map(|scalar| CustomScalar::from_scalar(scalar).transpose())
+            .collect::<anyhow::Result<Vec<CustomScalar>>>()
+            .context("Failed constructing scalars in schema from document")?;
+
+        Self::new(entities, enums, scalars)
+    }
+
+    ///Resolves the schema paths from the config relative to the config directory.
+    ///Each path can also be a glob pattern matching multiple schema files.
+    pub fn resolve_schema_paths(
+        project_paths: &ParsedProjectPaths,
+        maybe_custom_paths: &Option<SingleOrList<String>>,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let relative_schema_paths_from_config: Vec<String> = match maybe_custom_paths {
+            Some(custom_paths) => custom_paths.clone().into(),
+            None => vec![DEFAULT_SCHEMA_PATH.to_string()],
+        };
+
+        let mut schema_paths = vec![];
+        for relative_schema_path in relative_schema_paths_from_config {
+            let schema_path = path_utils::get_config_path_relative_to_root(
+                project_paths,
+                PathBuf::from(&relative_schema_path),
+            )
+            .context("Failed creating a relative path to schema")?;
+
+            let is_glob_pattern = relative_schema_path.contains(['*', '?', '[']);
+            if !is_glob_pattern {
+                schema_paths.push(schema_path);
+                continue;
+            }
+
+            let pattern = schema_path.to_str().ok_or_else(|| {
+                anyhow!("EE200: Schema path {relative_schema_path} is not valid unicode")
+            })?;
+            let mut matched_paths = glob::glob(pattern)
+                .context(format!(
+                    "EE200: Invalid glob pattern for schema files {relative_schema_path}"
+                ))?
+                .collect::<Result<Vec<_>, _>>()
+                .context(format!(
+                    "EE200: Failed to read schema files matching {relative_schema_path}"
+                ))?;
+
+            if matched_paths.is_empty() {
+                return Err(anyhow!(
+                    "EE200: No schema files found matching the pattern {relative_schema_path}. \
+                     Please ensure that the schema files are placed correctly in the directory."
+                ));
+            }
+
+            //Keep the order of the merged schema deterministic
+            matched_paths.sort();
+            schema_paths.extend(matched_paths);
+        }
+
+        Ok(schema_paths.into_iter().unique().collect())
     }
 
     pub fn parse_from_file(
         project_paths: &ParsedProjectPaths,
-        maybe_custom_path: &Option<String>,
+        maybe_custom_paths: &Option<SingleOrList<String>>,
     ) -> anyhow::Result<Self> {
-        let relative_schema_path_from_config = match maybe_custom_path {
-            Some(custom_path) => custom_path.clone(),
-            None => DEFAULT_SCHEMA_PATH.to_string(),
-        };
+        let schema_paths = Self::resolve_schema_paths(project_paths, maybe_custom_paths)?;
 
-        let schema_path = path_utils::get_config_path_relative_to_root(
-            project_paths,
-            PathBuf::from(relative_schema_path_from_config),
-        )
-        .context("Failed creating a relative path to schema")?;
+        Self::from_document(Self::read_document(&schema_paths)?)
+            .context("Failed converting schema doc to schema struct")
+    }
 
-        let schema_string = std::fs::read_to_string(&schema_path).context(format!(
-            "EE200: Failed to read schema file at {}. Please ensure that the schema file is \
-             placed correctly in the directory.",
-            &schema_path.to_str().unwrap_or_else(|| "bad file path"),
-        ))?;
+    ///Reads and parses all the schema files, merging them into a single document
+    pub fn read_document(schema_paths: &[PathBuf]) -> anyhow::Result<Document<'static, String>> {
+        let schema_strings = schema_paths
+            .iter()
+            .map(|schema_path| {
+                std::fs::read_to_string(schema_path).context(format!(
+                    "EE200: Failed to read schema file at {}. Please ensure that the schema file \
+                     is placed correctly in the directory.",
+                    &schema_path.to_str().unwrap_or_else(|| "bad file path"),
+                ))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut definitions = vec![];
+        //Type name to the file it's defined in. Used to point at both files
+        //when the same type is defined in multiple schema files
+        let mut type_def_paths: HashMap<String, &PathBuf> = HashMap::new();
+
+        for (schema_path, schema_string) in schema_paths.iter().zip(schema_strings.iter()) {
+            let schema_doc =
+                graphql_parser::parse_schema::<String>(schema_string).context(format!(
+                    "EE201: Failed to parse schema as document at {}",
+                    schema_path.display()
+                ))?;
+
+            for definition in schema_doc.into_static().definitions {
+                if let Definition::TypeDefinition(type_def) = &definition {
+                    let type_name = match type_def {
+                        TypeDefinition::Scalar(t) => &t.name,
+                        TypeDefinition::Object(t) => &t.name,
+                        TypeDefinition::Interface(t) => &t.name,
+                        TypeDefinition::Union(t) => &t.name,
+                        TypeDefinition::Enum(t) => &t.name,
+                        TypeDefinition::InputObject(t) => &t.name,
+                    };
+                    match type_def_paths.get(type_name) {
+                        Some(&other_path) if other_path != schema_path => {
+                            return Err(anyhow!(
+                                "EE220: The type '{}' is defined in multiple schema files: {} and \
+                                 {}",
+                                type_name,
+                                other_path.display(),
+                                schema_path.display()
+                            ));
+                        }
+                        Some(_) => (),
+                        None => {
+                            type_def_paths.insert(type_name.clone(), schema_path);
+                        }
+                    }
+                }
+                definitions.push(definition);
+            }
+        }
+
+        Ok(Document { definitions })
+    }
 
-        let schema_doc = graphql_parser::parse_schema::<String>(&schema_string)
-            .context("EE201: Failed to parse schema as document")?;
+    ///Prints the schema files for hashing. The @renamedFrom hints are left out,
+    ///so adding or removing them doesn't change the hash.
+    pub fn print_for_hash(schema_paths: &[PathBuf]) -> anyhow::Result<PrintedSchema> {
+        Self::print_for_hash_with_enum_values(schema_paths, &HashMap::new())
+    }
 
-        Self::from_document(schema_doc).context("Failed converting schema doc to schema struct")
+    ///Prints the schema for hashing with the values of the given enums replaced.
+    ///Used to get the hash the schema had before values were appended to them.
+    pub fn print_for_hash_with_enum_values(
+        schema_paths: &[PathBuf],
+        enum_values: &HashMap<String, Vec<String>>,
+    ) -> anyhow::Result<PrintedSchema> {
+        let document = retain_enum_values(Self::read_document(schema_paths)?, enum_values);
+        let before_renames = revert_renames(&document)
+            .map(|document| strip_renamed_from_directives(document).to_string());
+        Ok(PrintedSchema {
+            current: strip_renamed_from_directives(document).to_string(),
+            before_renames,
+        })
     }
 
     fn validate(self) -> anyhow::Result<Self> {
         self.check_enum_type_defs()?
-            .check_schema_for_reserved_words()?
             .check_duplicate_naming_between_enums_and_entities()?
+            .check_custom_scalar_names()?
             .check_related_type_defs_exist()?
+            .check_aggregation_sources()?
+            .check_full_text_search_names()?
+            .check_renamed_entities()?
+            .check_escaped_field_names()?
             .validate_entity_field_types()
     }
 
     fn get_all_enum_type_names(&self) -> Vec<String> {
         self.enums.keys().cloned().collect()
     }
-    fn get_all_enum_values(&self) -> Vec<String> {
-        self.enums.values().flat_map(|v| v.values.clone()).collect()
-    }
-    fn get_all_entity_type_names(&self) -> Vec<String> {
-        self.entities.keys().cloned().collect()
-    }
-    fn get_all_entity_field_names(&self) -> Vec<String> {
-        self.entities
-            .values()
-            .flat_map(|v| v.fields.values())
-            .map(|v| v.name.clone())
-            .collect()
-    }
 
     fn check_enum_type_defs(self) -> anyhow::Result<Self> {
         match check_enums_for_internal_reserved_words(self.get_all_enum_type_names()) {
@@ -155,24 +297,6 @@ impl Schema {
         }
     }
 
-    fn check_schema_for_reserved_words(self) -> anyhow::Result<Self> {
-        let all_names = vec![
-            self.get_all_enum_type_names(),
-            self.get_all_enum_values(),
-            self.get_all_entity_type_names(),
-            self.get_all_entity_field_names(),
-        ]
-        .concat();
-
-        match check_names_from_schema_for_reserved_words(all_names) {
-            reserved_enum_types_used if reserved_enum_types_used.is_empty() => Ok(self),
-            reserved_enum_types_used => Err(anyhow!(
-                "EE210: Schema contains the following reserved keywords: {}",
-                reserved_enum_types_used.join(", ")
-            )),
-        }
-    }
-
     fn check_duplicate_naming_between_enums_and_entities(self) -> anyhow::Result<Self> {
         let duplicate_names = self
             .get_all_enum_type_names()
@@ -190,9 +314,68 @@ impl Schema {
         }
     }
 
+    fn check_custom_scalar_names(self) -> anyhow::Result<Self> {
+        let duplicate_names = self
+            .scalars
+            .keys()
+            .filter(|k| self.entities.contains_key(*k) || self.enums.contains_key(*k))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !duplicate_names.is_empty() {
+            Err(anyhow!(
+                "EE228: Schema contains the following scalars with the same name as an entity or \
+                 enum, all type definitions must be unique in the schema: {}",
+                duplicate_names.join(", ")
+            ))
+        } else {
+            Ok(self)
+        }
+    }
+
+    ///Reserved words are escaped in the generated code, eg. a field called `type`
+    ///becomes `type_`. Validates the escaped names don't collide with other fields.
+    fn check_escaped_field_names(self) -> anyhow::Result<Self> {
+        for entity in self.entities.values() {
+            let mut fields_by_res_name: HashMap<String, Vec<&String>> = HashMap::new();
+            for field in entity.get_fields() {
+                if field.field_type.is_derived_from() {
+                    continue;
+                }
+                let key = if field.field_type.is_entity_field(&self)? {
+                    format!("{}_id", field.name)
+                } else {
+                    field.name.clone()
+                };
+                fields_by_res_name
+                    .entry(RescriptRecordField::to_valid_res_name(&key))
+                    .or_default()
+                    .push(&field.name);
+            }
+
+            if let Some((res_name, field_names)) = fields_by_res_name
+                .iter()
+                .find(|(_, field_names)| field_names.len() > 1)
+            {
+                return Err(anyhow!(
+                    "EE230: Fields {} of entity '{}' would all be named '{res_name}' in the \
+                     generated code. Please rename one of them",
+                    field_names
+                        .iter()
+                        .map(|name| format!("'{name}'"))
+                        .join(", "),
+                    entity.name,
+                ));
+            }
+        }
+        Ok(self)
+    }
+
     fn try_get_type_def(&self, name: &String) -> anyhow::Result<TypeDef> {
         match (self.entities.get(name), self.enums.get(name)) {
-            (None, None) => Err(anyhow!("No type definition '{}' exists in schema", name)),
+            (None, None) => match self.scalars.get(name) {
+                Some(scalar) => Ok(TypeDef::Scalar(scalar)),
+                None => Err(anyhow!("No type definition '{}' exists in schema", name)),
+            },
             (Some(_), Some(_)) => Err(anyhow!(
                 "Both an enum and an entity type definition '{}' exist in schema",
                 name
@@ -207,12 +390,30 @@ impl Schema {
             for rel in entity.get_relationships() {
                 match &rel {
                     Relationship::TypeDef { name } => {
-                        let _ = self.try_get_type_def(name)?;
+                        //Relationship columns store the id of the referenced entity
+                        if let TypeDef::Entity(Entity {
+                            composite_key: Some(_),
+                            ..
+                        }) = self.try_get_type_def(name)?
+                        {
+                            return Err(anyhow!(
+                                "EE231: Entity '{}' can't reference entity '{name}', since it \
+                                 has a composite key. Please store the key fields instead",
+                                entity.name
+                            ));
+                        }
                     }
                     Relationship::DerivedFrom {
                         name,
                         derived_from_field,
                     } => {
+                        if entity.composite_key.is_some() {
+                            return Err(anyhow!(
+                                "EE231: The @derivedFrom field '{derived_from_field}' can't be \
+                                 used on entity '{}', since it has a composite key",
+                                entity.name
+                            ));
+                        }
                         let type_def = self.try_get_type_def(name)?;
 
                         match type_def {
@@ -220,23 +421,16 @@ impl Schema {
                                 "Cannot derive field {derived_from_field} from enum {name}. \
                                  derivedFrom is intended to be used with Entity type definitions"
                             ))?,
+                            TypeDef::Scalar(_) => Err(anyhow!(
+                                "Cannot derive field {derived_from_field} from scalar {name}. \
+                                 derivedFrom is intended to be used with Entity type definitions"
+                            ))?,
                             TypeDef::Entity(derived_entity) => {
-                                match derived_entity.fields.get(derived_from_field) {
-                                    None => Err(anyhow!(
-                                        "Derived field {derived_from_field} does not exist on \
-                                         entity {name}."
-                                    ))?,
-                                    Some(field) => match field.field_type.get_underlying_scalar() {
-                                        GqlScalar::Custom(name) if name == entity.name => (),
-                                        GqlScalar::ID | GqlScalar::String => (),
-                                        _ => Err(anyhow!(
-                                            "Derived field '{derived_from_field}' on entity \
-                                             '{name}' must either be an ID, String, or an Object \
-                                             relationship with Entity '{}'",
-                                            entity.name
-                                        ))?,
-                                    },
-                                }
+                                Self::check_derived_from_field_references_back(
+                                    entity,
+                                    derived_entity,
+                                    derived_from_field,
+                                )?
                             }
                         }
                     }
@@ -247,6 +441,259 @@ impl Schema {
         Ok(self)
     }
 
+    ///Validates that the field a @derivedFrom relationship is derived from exists
+    ///on the derived entity and references the parent entity back. Otherwise the
+    ///derived field would only resolve to an empty array at runtime.
+    fn check_derived_from_field_references_back(
+        entity: &Entity,
+        derived_entity: &Entity,
+        derived_from_field: &String,
+    ) -> anyhow::Result<()> {
+        let parent_name = &entity.name;
+        let name = &derived_entity.name;
+
+        let fields_referencing_parent = derived_entity
+            .fields
+            .values()
+            .filter(|field| {
+                !field.field_type.is_derived_from()
+                    && matches!(
+                        field.field_type.get_underlying_scalar(),
+                        GqlScalar::Custom(type_name) if &type_name == parent_name
+                    )
+            })
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>();
+
+        //Prefer suggesting the fields which already reference the parent entity,
+        //then the closest of the other candidates
+        let get_suggestion = |other_candidates: Vec<&str>| {
+            find_closest_match(derived_from_field, fields_referencing_parent.clone())
+                .or_else(|| find_closest_match(derived_from_field, other_candidates))
+                .or(match fields_referencing_parent.as_slice() {
+                    [field_name] => Some(*field_name),
+                    _ => None,
+                })
+                .map(|field_name| format!(" Did you mean '{field_name}'?"))
+                .unwrap_or_default()
+        };
+
+        let Some(field) = derived_entity.fields.get(derived_from_field) else {
+            let suggestion = get_suggestion(
+                derived_entity
+                    .fields
+                    .values()
+                    .map(|field| field.name.as_str())
+                    .collect(),
+            );
+            return Err(anyhow!(
+                "EE229: The @derivedFrom field '{derived_from_field}' of entity '{parent_name}' \
+                 does not exist on entity '{name}'.{suggestion}"
+            ));
+        };
+
+        //Lists of plain ids are still allowed, only entity references need to be single
+        let is_entity_list = field.field_type.is_array()
+            && matches!(
+                field.field_type.get_underlying_scalar(),
+                GqlScalar::Custom(_)
+            );
+        if field.field_type.is_derived_from() || is_entity_list {
+            return Err(anyhow!(
+                "EE229: The @derivedFrom field '{derived_from_field}' of entity '{parent_name}' \
+                 can't be a list or another derived field on entity '{name}'. It needs to be a \
+                 single reference to entity '{parent_name}'.{}",
+                get_suggestion(vec![])
+            ));
+        }
+
+        match field.field_type.get_underlying_scalar() {
+            GqlScalar::Custom(type_name) if &type_name == parent_name => Ok(()),
+            GqlScalar::ID | GqlScalar::String => Ok(()),
+            //Plain fields holding the id of an entity with a non string id
+            scalar if scalar == entity.get_id_scalar() => Ok(()),
+            GqlScalar::Custom(type_name) => Err(anyhow!(
+                "EE229: The @derivedFrom field '{derived_from_field}' of entity '{parent_name}' \
+                 references entity '{type_name}' instead of '{parent_name}' on entity \
+                 '{name}'.{}",
+                get_suggestion(vec![])
+            )),
+            _ => Err(anyhow!(
+                "EE229: The @derivedFrom field '{derived_from_field}' on entity '{name}' must \
+                 either be an ID, String, the type of the id, or an Object relationship with \
+                 Entity '{parent_name}'.{}",
+                get_suggestion(vec![])
+            )),
+        }
+    }
+
+    ///Validates that the source of every aggregation is a timeseries entity
+    ///containing its dimension fields and the args of its aggregates
+    fn check_aggregation_sources(self) -> anyhow::Result<Self> {
+        for entity in self.entities.values() {
+            let Some(aggregation) = &entity.aggregation else {
+                continue;
+            };
+            let name = &entity.name;
+            let source_name = &aggregation.source;
+
+            let source = match self.entities.get(source_name) {
+                Some(source) if source.is_timeseries => source,
+                Some(_) => Err(anyhow!(
+                    "EE222: The source '{source_name}' of aggregation {name} needs to be a \
+                     timeseries entity. Please add @entity(timeseries: true) to it"
+                ))?,
+                None => Err(anyhow!(
+                    "EE222: The source '{source_name}' of aggregation {name} does not exist in \
+                     the schema"
+                ))?,
+            };
+
+            let get_source_regular_field = |field_name: &String| {
+                source
+                    .fields
+                    .get(field_name)
+                    .filter(|field| matches!(field.field_type, FieldType::RegularField { .. }))
+            };
+
+            for dimension in aggregation.get_dimension_fields(entity) {
+                let is_matching_source_field = matches!(
+                    (&dimension.field_type, get_source_regular_field(&dimension.name)),
+                    (FieldType::RegularField { field_type, .. }, Some(source_field))
+                        if field_type.to_string() == source_field.field_type.to_string()
+                );
+                if !is_matching_source_field {
+                    Err(anyhow!(
+                        "EE222: The field '{}' of aggregation {name} is neither an aggregate nor a \
+                         field of the same type on the source entity {source_name}. Fields \
+                         without @aggregate are used to group the aggregation and need to exist \
+                         on the source entity",
+                        dimension.name
+                    ))?
+                }
+            }
+
+            for aggregate in &aggregation.aggregates {
+                let field_name = &aggregate.field_name;
+                let aggregate_scalar = entity
+                    .fields
+                    .get(field_name)
+                    .ok_or_else(|| anyhow!("Unexpected, aggregate field {field_name} not found"))?
+                    .field_type
+                    .get_underlying_scalar();
+                let is_numeric = |scalar: &GqlScalar| {
+                    matches!(
+                        scalar,
+                        GqlScalar::Int
+                            | GqlScalar::Int8
+                            | GqlScalar::Float
+                            | GqlScalar::BigInt(_)
+                            | GqlScalar::BigDecimal(_)
+                    )
+                };
+
+                let arg_scalar = match &aggregate.arg {
+                    None => None,
+                    Some(arg) => Some(
+                        get_source_regular_field(arg)
+                            .ok_or_else(|| {
+                                anyhow!(
+                                    "EE223: The arg '{arg}' of the aggregate field {field_name} \
+                                     on aggregation {name} is not a field of the source entity \
+                                     {source_name}"
+                                )
+                            })?
+                            .field_type
+                            .get_underlying_scalar(),
+                    ),
+                };
+
+                let is_valid_type = match (aggregate.function, arg_scalar) {
+                    (AggregateFn::Count, _) => {
+                        matches!(
+                            aggregate_scalar,
+                            GqlScalar::Int | GqlScalar::Int8 | GqlScalar::BigInt(_)
+                        )
+                    }
+                    //The sum is cast to the type of the aggregate field,
+                    //so it needs to be able to hold any value of the arg
+                    (AggregateFn::Sum, Some(arg_scalar)) => {
+                        is_numeric(&arg_scalar)
+                            && match aggregate_scalar {
+                                GqlScalar::Int => matches!(arg_scalar, GqlScalar::Int),
+                                GqlScalar::Int8 => {
+                                    matches!(arg_scalar, GqlScalar::Int | GqlScalar::Int8)
+                                }
+                                GqlScalar::BigInt(_) => matches!(
+                                    arg_scalar,
+                                    GqlScalar::Int | GqlScalar::Int8 | GqlScalar::BigInt(_)
+                                ),
+                                GqlScalar::Float => !matches!(
+                                    arg_scalar,
+                                    GqlScalar::BigInt(_) | GqlScalar::BigDecimal(_)
+                                ),
+                                GqlScalar::BigDecimal(_) => true,
+                                _ => false,
+                            }
+                    }
+                    (_, Some(arg_scalar)) => {
+                        std::mem::discriminant(&arg_scalar)
+                            == std::mem::discriminant(&aggregate_scalar)
+                    }
+                    (_, None) => false,
+                };
+                if !is_valid_type {
+                    Err(anyhow!(
+                        "EE223: The type of the aggregate field {field_name} on aggregation \
+                         {name} doesn't match its aggregate function. \"count\" needs an Int, \
+                         Int8 or BigInt field, \"sum\" needs a numeric field which can hold \
+                         any value of its numeric arg and other functions need the same type as \
+                         their arg"
+                    ))?
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    ///Full text searches get a search function exposed on the GraphQL API,
+    ///so their names need to be unique across the schema
+    fn check_full_text_search_names(self) -> anyhow::Result<Self> {
+        let mut used_names: HashSet<&String> = self.entities.keys().collect();
+        for entity in self.entities.values().sorted_by_key(|entity| &entity.name) {
+            for full_text_search in &entity.full_text_searches {
+                if !used_names.insert(&full_text_search.name) {
+                    return Err(anyhow!(
+                        "EE224: The @fulltext name '{}' on entity {} is already used by another \
+                         @fulltext directive or entity in the schema",
+                        full_text_search.name,
+                        entity.name
+                    ));
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    fn check_renamed_entities(self) -> anyhow::Result<Self> {
+        let mut used_names: HashSet<&String> =
+            self.entities.keys().chain(self.enums.keys()).collect();
+        for entity in self.entities.values().sorted_by_key(|entity| &entity.name) {
+            if let Some(renamed_from) = &entity.renamed_from {
+                if !used_names.insert(renamed_from) {
+                    return Err(anyhow!(
+                        "EE226: The entity {} can't be renamed from '{}', the name is already \
+                         used by another type or @renamedFrom directive in the schema",
+                        entity.name,
+                        renamed_from
+                    ));
+                }
+            }
+        }
+        Ok(self)
+    }
+
     /// For all entities validate the defined field types.
     ///
     /// This function will return an error if there is a defined related type where the type does
@@ -323,6 +770,210 @@ impl GraphQLEnum {
             .collect::<Vec<String>>();
         Self::new(name, values)
     }
+
+    ///Compares the values of the enum with the ones of its type in the db.
+    ///Postgres can only add values to an existing enum type in place.
+    pub fn get_values_change(&self, db_values: &[String]) -> EnumValuesChange {
+        let removed_values = db_values
+            .iter()
+            .filter(|value| !self.values.contains(value))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !removed_values.is_empty() {
+            EnumValuesChange::Removed(removed_values)
+        } else if !self.values.starts_with(db_values) {
+            EnumValuesChange::Reordered
+        } else if self.values.len() == db_values.len() {
+            EnumValuesChange::Unchanged
+        } else {
+            EnumValuesChange::Appended(self.values[db_values.len()..].to_vec())
+        }
+    }
+}
+
+///Difference between the values of an enum in the schema and in the db
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnumValuesChange {
+    Unchanged,
+    ///New values after the existing ones, added with ALTER TYPE ADD VALUE
+    Appended(Vec<String>),
+    ///Values no longer in the schema. The type needs to be recreated
+    Removed(Vec<String>),
+    ///Existing values in a different order. The type needs to be recreated
+    Reordered,
+}
+
+impl EnumValuesChange {
+    pub fn is_destructive(&self) -> bool {
+        matches!(self, Self::Removed(_) | Self::Reordered)
+    }
+}
+
+const SCALAR_DIRECTIVE_NAME: &str = "scalar";
+
+///User defined scalar, declared with `scalar Name @scalar(type: "...")`.
+///It's stored like its base scalar and gets its own type in the generated code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomScalar {
+    pub name: String,
+    pub base: GqlScalar,
+    ///Regex the values need to match. Only allowed on string based scalars.
+    pub pattern: Option<String>,
+}
+
+impl CustomScalar {
+    ///Parses a scalar definition of the schema. Definitions of built in scalars
+    ///without the @scalar directive are skipped, since they are commonly kept in
+    ///subgraph schemas.
+    fn from_scalar(scalar: &ScalarType<String>) -> anyhow::Result<Option<Self>> {
+        let name = scalar.name.clone();
+        let is_built_in = !matches!(
+            GqlScalar::from_str(&name, &PgTypeModifications::default()),
+            GqlScalar::Custom(_)
+        );
+
+        let scalar_directive = match scalar
+            .directives
+            .iter()
+            .filter(|directive| directive.name == SCALAR_DIRECTIVE_NAME)
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            [] if is_built_in => return Ok(None),
+            [] => Err(anyhow!(
+                "EE228: Scalar '{name}' needs a @scalar directive with the type it's stored \
+                 as. Eg. scalar {name} @scalar(type: \"String\")"
+            ))?,
+            _ if is_built_in => Err(anyhow!(
+                "EE228: '{name}' is a built in scalar and can't be redefined with @scalar"
+            ))?,
+            [scalar_directive] => *scalar_directive,
+            _ => Err(anyhow!(
+                "EE228: Scalar '{name}' can only have a single @scalar directive"
+            ))?,
+        };
+
+        let mut base = None;
+        let mut pattern = None;
+        for (arg_name, arg_value) in &scalar_directive.arguments {
+            match (arg_name.as_str(), arg_value) {
+                ("type", Value::String(type_name)) => {
+                    base = Some(GqlScalar::from_str(
+                        type_name,
+                        &PgTypeModifications::default(),
+                    ))
+                }
+                ("pattern", Value::String(regex)) => pattern = Some(regex.clone()),
+                _ => Err(anyhow!(
+                    "EE228: The @scalar directive on scalar '{name}' only takes the string \
+                     arguments 'type' and 'pattern'. Got '{arg_name}'"
+                ))?,
+            }
+        }
+
+        let base = match base {
+            Some(GqlScalar::Custom(type_name)) => Err(anyhow!(
+                "EE228: The type of scalar '{name}' needs to be a built in scalar. Got \
+                 '{type_name}'"
+            ))?,
+            Some(base) => base,
+            None => Err(anyhow!(
+                "EE228: The @scalar directive on scalar '{name}' is missing the 'type' argument"
+            ))?,
+        };
+
+        if let Some(regex) = &pattern {
+            if !matches!(
+                base,
+                GqlScalar::ID | GqlScalar::String | GqlScalar::Bytes(BytesStorage::Text)
+            ) {
+                Err(anyhow!(
+                    "EE228: The pattern of scalar '{name}' can only be used with the ID, String \
+                     or Bytes types"
+                ))?
+            }
+            regex::Regex::new(regex)
+                .map_err(|e| anyhow!("EE228: Invalid pattern on scalar '{name}'. {e}"))?;
+            check_portable_pattern(regex).map_err(|e| {
+                anyhow!(
+                    "EE228: Unsupported pattern on scalar '{name}': {e}. Patterns are matched \
+                     with a JavaScript RegExp at runtime, so only the syntax it shares with \
+                     Rust regexes is allowed"
+                )
+            })?;
+        }
+
+        Ok(Some(Self {
+            name,
+            base,
+            pattern,
+        }))
+    }
+
+    ///Rescript type the scalar aliases in the generated code
+    pub fn get_base_rescript_type(&self, schema: &Schema) -> anyhow::Result<RescriptTypeIdent> {
+        match &self.base {
+            GqlScalar::ID => Ok(RescriptTypeIdent::String),
+            base => base.to_rescript_type(schema),
+        }
+    }
+}
+
+///Checks that a scalar pattern only uses the syntax that the Rust regex crate
+///and JavaScript RegExp (without flags) read the same way
+fn check_portable_pattern(pattern: &str) -> Result<(), String> {
+    let mut chars = pattern.chars().peekable();
+    let mut in_class = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(
+                    '\\' | '^' | '$' | '.' | '|' | '?' | '*' | '+' | '(' | ')' | '[' | ']' | '{'
+                    | '}' | '/' | '-',
+                ) => (),
+                Some('d' | 'D' | 'w' | 'W' | 's' | 'S' | 'n' | 'r' | 't' | 'f' | 'v') => (),
+                Some('b' | 'B') if !in_class && chars.peek() != Some(&'{') => (),
+                Some('x') => {
+                    let is_hex_digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_hexdigit());
+                    if !(is_hex_digit(chars.next()) && is_hex_digit(chars.next())) {
+                        return Err("hex escapes need exactly two digits, eg. \\x41".to_string());
+                    }
+                }
+                Some(escaped) => return Err(format!("the escape \\{escaped} isn't supported")),
+                None => return Err("the pattern ends with a backslash".to_string()),
+            },
+            '[' if in_class => return Err("nested character classes aren't supported".to_string()),
+            '[' => {
+                in_class = true;
+                if chars.peek() == Some(&'^') {
+                    chars.next();
+                }
+                if chars.peek() == Some(&']') {
+                    return Err("a ']' at the start of a class needs to be escaped".to_string());
+                }
+            }
+            ']' if in_class => in_class = false,
+            '&' | '-' | '~' if in_class && chars.peek() == Some(&c) => {
+                return Err(format!("the class operator {c}{c} isn't supported"))
+            }
+            '(' if !in_class && chars.peek() == Some(&'?') => {
+                chars.next();
+                if chars.next() != Some(':') {
+                    return Err(
+                        "only non capturing (?:...) groups are supported, not named groups or \
+                         inline flags"
+                            .to_string(),
+                    );
+                }
+            }
+            '{' if !in_class && chars.peek() == Some(&',') => {
+                return Err("repetitions need a minimum, eg. {0,3}".to_string())
+            }
+            _ => (),
+        }
+    }
+    Ok(())
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -330,13 +981,48 @@ pub struct Entity {
     pub name: String,
     pub fields: HashMap<String, Field>,
     pub multi_field_indexes: Vec<MultiFieldIndex>,
+    ///Set with @entity(immutable: true). Immutable entities are only ever
+    ///inserted, never updated or deleted.
+    pub is_immutable: bool,
+    ///Set with @entity(timeseries: true). Timeseries entities are immutable
+    ///and can be used as the source of aggregations.
+    pub is_timeseries: bool,
+    ///Set for entities defined with the @aggregation directive
+    pub aggregation: Option<Aggregation>,
+    ///Defined with the entity level @fulltext directive
+    pub full_text_searches: Vec<FullTextSearch>,
+    ///Description of the entity in the schema
+    pub description: Option<String>,
+    ///Previous name of the entity, set with the @renamedFrom directive
+    pub renamed_from: Option<String>,
+    ///Set with @entity(compositeKey: [...]). The fields forming the primary
+    ///key of an entity without an id field.
+    pub composite_key: Option<Vec<String>>,
+}
+
+///Arguments of the optional @entity directive
+#[derive(Debug, Default)]
+struct EntityDirectiveArgs {
+    immutable: Option<bool>,
+    timeseries: bool,
+    composite_key: Option<Vec<String>>,
 }
 
+///Timestamp field required on timeseries and aggregation entities
+pub const TIMESERIES_TIMESTAMP_FIELD_NAME: &str = "timestamp";
+
+const RENAMED_FROM_DIRECTIVE_NAME: &str = "renamedFrom";
+
 impl Entity {
     fn new(
         name: &str,
         fields: Vec<Field>,
         multi_field_indexes: Vec<MultiFieldIndex>,
+        is_immutable: bool,
+        is_timeseries: bool,
+        aggregation: Option<Aggregation>,
+        full_text_searches: Vec<FullTextSearch>,
+        composite_key: Option<Vec<String>>,
     ) -> anyhow::Result<Self> {
         let fields = unique_hashmap::from_vec_no_duplicates(
             fields.into_iter().map(|f| (f.name.clone(), f)).collect(),
@@ -355,7 +1041,8 @@ impl Entity {
                         &vec!["db_write_timestamp".to_string()],
                     )?
                     .validate_no_index_on_derived_field(&fields)?
-                    .validate_no_index_on_id_field()
+                    .validate_no_index_on_id_field()?
+                    .validate_index_options(&fields)
             })
             .collect::<anyhow::Result<Vec<_>>>()
             .context(format!("Invalid multi field indexes on Entity {name}"))?;
@@ -373,52 +1060,296 @@ impl Entity {
             }
         }
 
+        if let Some(id_field) = fields.get("id") {
+            Self::validate_id_field(id_field, aggregation.is_some())
+                .context(format!("Invalid id field on Entity {name}"))?;
+        }
+
+        if let Some(composite_key) = &composite_key {
+            Self::validate_composite_key(name, composite_key, &fields, is_timeseries)?;
+        }
+
+        if is_timeseries || aggregation.is_some() {
+            let has_timestamp_field = matches!(
+                fields.get(TIMESERIES_TIMESTAMP_FIELD_NAME),
+                Some(Field {
+                    field_type: FieldType::RegularField {
+                        field_type: UserDefinedFieldType::NonNullType(field_type),
+                        ..
+                    },
+                    ..
+                }) if **field_type == UserDefinedFieldType::Single(GqlScalar::Timestamp)
+            );
+            if !has_timestamp_field {
+                return Err(anyhow!(
+                    "EE221: Timeseries and aggregation entities need a '{}: Timestamp!' field. \
+                     Entity {name}",
+                    TIMESERIES_TIMESTAMP_FIELD_NAME
+                ));
+            }
+        }
+
+        for full_text_search in &full_text_searches {
+            full_text_search
+                .validate_fields(&fields)
+                .context(format!("Invalid @fulltext directive on Entity {name}"))?;
+        }
+
         Ok(Self {
             name: name.to_string(),
             fields,
             multi_field_indexes,
+            is_immutable,
+            is_timeseries,
+            aggregation,
+            full_text_searches,
+            description: None,
+            renamed_from: None,
+            composite_key,
         })
     }
 
-    fn from_object(obj: &ObjectType<String>) -> anyhow::Result<Self> {
-        let name = &obj.name;
-
-        let has_id = obj.fields.iter().any(|field| field.name == "id");
-        if !has_id {
+    ///The key fields replace the id, so they need to be usable as keys of the
+    ///in memory store the same way.
+    fn validate_composite_key(
+        name: &str,
+        composite_key: &[String],
+        fields: &HashMap<String, Field>,
+        is_timeseries: bool,
+    ) -> anyhow::Result<()> {
+        if is_timeseries {
             return Err(anyhow!(
-                "No 'id' field found on entity {}. Please add an 'id' field to your entity.",
-                name
+                "EE231: Timeseries entities can't have a composite key. Entity {name}"
+            ));
+        }
+        if fields.contains_key("id") {
+            return Err(anyhow!(
+                "EE231: Entity {name} has a composite key and can't have an 'id' field. Please \
+                 remove one of them"
+            ));
+        }
+        if composite_key.len() < 2 {
+            return Err(anyhow!(
+                "EE231: The composite key of entity {name} needs at least 2 fields. Please use \
+                 an 'id' field for a single field key"
+            ));
+        }
+        if let Some(duplicate) = composite_key.iter().duplicates().next() {
+            return Err(anyhow!(
+                "EE231: The field '{duplicate}' is used more than once in the composite key of \
+                 entity {name}"
             ));
         }
 
-        let multi_field_indexes = obj
-            .directives
-            .iter()
-            .filter(|directive| directive.name == "index")
-            .map(
-                |directive| match directive.arguments.iter().find(|(key, _)| key == "fields") {
-                    Some((_, Value::List(fields))) => {
-                        let index_fields = fields
-                            .iter()
-                            .map(|v| {
-                                if let Value::String(field_name) = v {
-                                    Ok(field_name.clone())
-                                } else {
-                                    Err(anyhow!("Listed index field should be a string"))
-                                }
-                            })
-                            .collect::<anyhow::Result<Vec<String>>>()
-                            .context("Failed to get fields in index")?;
-
-                        Ok(MultiFieldIndex::new(index_fields))
-                    }
-                    _ => Err(anyhow!(
-                        "Invalid @index directive. Please ensure index has a key of fields with a \
-                         list of strings matching field names in your entity. Eg. @index(fields: \
-                         [\"fieldA\", \"fieldB\"])"
-                    )),
-                },
-            )
+        for field_name in composite_key {
+            let Some(field) = fields.get(field_name) else {
+                let suggestion = find_closest_match(field_name, fields.keys().map(String::as_str))
+                    .map(|closest_name| format!(" Did you mean '{closest_name}'?"))
+                    .unwrap_or_default();
+                return Err(anyhow!(
+                    "EE231: The composite key field '{field_name}' does not exist on entity \
+                     {name}.{suggestion}"
+                ));
+            };
+            let key_scalar = match &field.field_type {
+                FieldType::RegularField {
+                    field_type: UserDefinedFieldType::NonNullType(field_type),
+                    ..
+                } => match field_type.as_ref() {
+                    UserDefinedFieldType::Single(key_scalar) => Some(key_scalar),
+                    _ => None,
+                },
+                _ => None,
+            };
+            match key_scalar {
+                Some(
+                    GqlScalar::ID
+                    | GqlScalar::String
+                    | GqlScalar::Bytes(BytesStorage::Text)
+                    | GqlScalar::Int
+                    | GqlScalar::BigInt(_)
+                    | GqlScalar::Int8,
+                ) => (),
+                _ => {
+                    return Err(anyhow!(
+                        "EE231: The composite key field '{field_name}' of entity {name} needs \
+                         to be one of ID!, String!, Bytes!, Int!, BigInt! or Int8!. Got {}",
+                        field.field_type
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///Only scalars that can be used as keys of the in memory store are allowed.
+    ///Aggregation ids are built from their bucket, so they need to be strings.
+    fn validate_id_field(id_field: &Field, is_aggregation: bool) -> anyhow::Result<()> {
+        let id_scalar = match &id_field.field_type {
+            FieldType::RegularField {
+                field_type: UserDefinedFieldType::NonNullType(field_type),
+                ..
+            } => match field_type.as_ref() {
+                UserDefinedFieldType::Single(id_scalar) => Some(id_scalar),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match id_scalar {
+            Some(GqlScalar::ID | GqlScalar::String | GqlScalar::Bytes(BytesStorage::Text)) => {
+                Ok(())
+            }
+            Some(GqlScalar::Int8) if !is_aggregation => Ok(()),
+            Some(GqlScalar::Int8) => Err(anyhow!(
+                "EE227: The id of an aggregation entity needs to be either ID!, String! or Bytes!"
+            )),
+            Some(GqlScalar::Bytes(BytesStorage::Bytea)) => Err(anyhow!(
+                "EE227: Bytes ids can't be stored as bytea. Please remove the storage option \
+                 from the id field"
+            )),
+            _ => Err(anyhow!(
+                "EE227: The id field needs to be one of ID!, String!, Int8! or Bytes!. Got {}",
+                id_field.field_type
+            )),
+        }
+    }
+
+    ///Underlying scalar of the id field. Relationship columns referencing the
+    ///entity use the same type.
+    pub fn get_id_scalar(&self) -> GqlScalar {
+        self.fields
+            .get("id")
+            .map(|id_field| id_field.field_type.get_underlying_scalar())
+            .unwrap_or(GqlScalar::ID)
+    }
+
+    ///Fields of the primary key, either the id or the composite key fields
+    pub fn get_primary_key_fields(&self) -> Vec<&Field> {
+        match &self.composite_key {
+            Some(composite_key) => composite_key
+                .iter()
+                .filter_map(|field_name| self.fields.get(field_name))
+                .collect(),
+            None => self.fields.get("id").into_iter().collect(),
+        }
+    }
+
+    ///Rescript type of the entity id, aliased as the id type of the entity module.
+    ///Entities with a composite key use a tuple of the key field types.
+    pub fn get_id_rescript_type(&self, schema: &Schema) -> anyhow::Result<RescriptTypeIdent> {
+        let to_id_rescript_type = |id_scalar: GqlScalar| match id_scalar {
+            GqlScalar::ID => Ok(RescriptTypeIdent::String),
+            id_scalar => id_scalar.to_rescript_type(schema),
+        };
+        match &self.composite_key {
+            Some(_) => Ok(RescriptTypeIdent::Tuple(
+                self.get_primary_key_fields()
+                    .into_iter()
+                    .map(|field| to_id_rescript_type(field.field_type.get_underlying_scalar()))
+                    .collect::<anyhow::Result<_>>()?,
+            )),
+            None => to_id_rescript_type(self.get_id_scalar()),
+        }
+    }
+
+    ///Parses the optional entity level @entity directive. It's not required,
+    ///but it's supported for compatibility with subgraph schemas.
+    fn get_entity_directive_args(obj: &ObjectType<String>) -> anyhow::Result<EntityDirectiveArgs> {
+        let entity_directives = obj
+            .directives
+            .iter()
+            .filter(|directive| directive.name == "entity")
+            .collect::<Vec<_>>();
+
+        let entity_directive = match entity_directives.as_slice() {
+            [] => return Ok(EntityDirectiveArgs::default()),
+            [entity_directive] => entity_directive,
+            _ => {
+                return Err(anyhow!(
+                    "EE218: Cannot use more than one @entity directive on entity {}",
+                    obj.name
+                ))
+            }
+        };
+
+        let mut args = EntityDirectiveArgs::default();
+        for (arg_name, arg_value) in &entity_directive.arguments {
+            match (arg_name.as_str(), arg_value) {
+                ("immutable", Value::Boolean(val)) => args.immutable = Some(*val),
+                ("timeseries", Value::Boolean(val)) => args.timeseries = *val,
+                ("compositeKey", Value::List(values)) => {
+                    let field_names = values
+                        .iter()
+                        .map(|value| match value {
+                            Value::String(field_name) => Some(field_name.clone()),
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "EE218: The 'compositeKey' argument of the @entity directive on \
+                                 entity {} needs to be a list of field names",
+                                obj.name
+                            )
+                        })?;
+                    args.composite_key = Some(field_names);
+                }
+                ("compositeKey", _) => {
+                    return Err(anyhow!(
+                        "EE218: The 'compositeKey' argument of the @entity directive on entity \
+                         {} needs to be a list of field names",
+                        obj.name
+                    ))
+                }
+                ("immutable" | "timeseries", _) => {
+                    return Err(anyhow!(
+                        "EE218: The '{}' argument of the @entity directive on entity {} needs to \
+                         be a boolean",
+                        arg_name,
+                        obj.name
+                    ))
+                }
+                (unknown_arg, _) => {
+                    return Err(anyhow!(
+                        "EE218: Unknown argument '{}' in the @entity directive on entity {}. \
+                         Only 'immutable', 'timeseries' and 'compositeKey' are supported",
+                        unknown_arg,
+                        obj.name
+                    ))
+                }
+            }
+        }
+
+        if args.timeseries && args.immutable == Some(false) {
+            return Err(anyhow!(
+                "EE221: Timeseries entities are always immutable. Please remove 'immutable: \
+                 false' from the @entity directive on entity {}",
+                obj.name
+            ));
+        }
+
+        Ok(args)
+    }
+
+    fn from_object(obj: &ObjectType<String>) -> anyhow::Result<Self> {
+        let name = &obj.name;
+
+        let entity_directive_args = Self::get_entity_directive_args(obj)?;
+
+        let has_id = obj.fields.iter().any(|field| field.name == "id");
+        if !has_id && entity_directive_args.composite_key.is_none() {
+            return Err(anyhow!(
+                "No 'id' field found on entity {}. Please add an 'id' field to your entity.",
+                name
+            ));
+        }
+
+        let multi_field_indexes = obj
+            .directives
+            .iter()
+            .filter(|directive| directive.name == "index")
+            .map(MultiFieldIndex::from_directive)
             .collect::<anyhow::Result<Vec<_>>>()
             .context(format!(
                 "Failed parsing multi field indexes on entity {name}"
@@ -434,11 +1365,106 @@ impl Entity {
             .collect::<anyhow::Result<Vec<Field>>>()
             .context(format!("Failed parsing fields on entity {name}"))?;
 
-        let entity = Self::new(name, fields, multi_field_indexes)
-            .context(format!("Failed constructing entity {name}",))?;
+        let mut renamed_field_names = HashSet::new();
+        for field in &fields {
+            if let Some(renamed_from) = &field.renamed_from {
+                if fields.iter().any(|other| &other.name == renamed_from)
+                    || !renamed_field_names.insert(renamed_from)
+                {
+                    return Err(anyhow!(
+                        "EE226: The field {} on entity {name} can't be renamed from '{}', the \
+                         name is already used by another field or @renamedFrom directive",
+                        field.name,
+                        renamed_from
+                    ));
+                }
+            }
+        }
+
+        let aggregation = Aggregation::from_object(obj)?;
+
+        if aggregation.is_some() && obj.directives.iter().any(|d| d.name == "entity") {
+            return Err(anyhow!(
+                "EE222: The @aggregation directive can't be combined with the @entity directive \
+                 on entity {name}"
+            ));
+        }
+
+        let (fields, multi_field_indexes) = match &aggregation {
+            None => (fields, multi_field_indexes),
+            Some(_) => {
+                if fields
+                    .iter()
+                    .any(|field| field.name == Aggregation::INTERVAL_FIELD_NAME)
+                {
+                    return Err(anyhow!(
+                        "EE222: The field name '{}' is reserved on aggregation entity {name}",
+                        Aggregation::INTERVAL_FIELD_NAME
+                    ));
+                }
+                let interval_field = Field {
+                    name: Aggregation::INTERVAL_FIELD_NAME.to_string(),
+                    field_type: FieldType::RegularField {
+                        field_type: UserDefinedFieldType::NonNullType(Box::new(
+                            UserDefinedFieldType::Single(GqlScalar::String),
+                        )),
+                        has_indexed_directive: false,
+                    },
+                    description: Some("Interval of the aggregated time bucket".to_string()),
+                    deprecation_reason: None,
+                    renamed_from: None,
+                };
+                //Rollups delete and recompute rows by interval and bucket timestamp
+                let interval_index = MultiFieldIndex::new(
+                    vec![
+                        Aggregation::INTERVAL_FIELD_NAME.to_string(),
+                        TIMESERIES_TIMESTAMP_FIELD_NAME.to_string(),
+                    ],
+                    IndexOptions::default(),
+                );
+                (
+                    vec![fields, vec![interval_field]].concat(),
+                    vec![multi_field_indexes, vec![interval_index]].concat(),
+                )
+            }
+        };
+
+        let full_text_searches = obj
+            .directives
+            .iter()
+            .filter(|directive| directive.name == "fulltext")
+            .map(FullTextSearch::from_directive)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .context(format!(
+                "Failed parsing @fulltext directives on entity {name}"
+            ))?;
+
+        let entity = Self::new(
+            name,
+            fields,
+            multi_field_indexes,
+            entity_directive_args.immutable.unwrap_or(false) || entity_directive_args.timeseries,
+            entity_directive_args.timeseries,
+            aggregation,
+            full_text_searches,
+            entity_directive_args.composite_key,
+        )
+        .context(format!("Failed constructing entity {name}",))?;
+
+        let renamed_from = get_renamed_from(&obj.directives, &format!("entity {name}"))?;
+        if renamed_from.as_ref() == Some(name) {
+            return Err(anyhow!(
+                "EE226: The @renamedFrom directive on entity {name} needs a name different from \
+                 the current one"
+            ));
+        }
 
         // Here, store indexed information somewhere within your entity structure or handle them accordingly
-        Ok(entity)
+        Ok(Self {
+            description: obj.description.clone(),
+            renamed_from,
+            ..entity
+        })
     }
 
     /// Returns the fields of this [`Entity`] sorted by field name.
@@ -484,7 +1510,7 @@ impl Entity {
                         |e| Some(Err(e)),
                         |type_def| match type_def {
                             TypeDef::Entity(entity) => Some(Ok((field, entity))),
-                            TypeDef::Enum => None,
+                            TypeDef::Enum | TypeDef::Scalar(_) => None,
                         },
                     )
                 } else {
@@ -508,22 +1534,220 @@ impl Entity {
     }
 
     ///Returns defined multi field indices where definitions
-    ///have > 1 fields.
-    pub fn get_composite_indices(&self) -> Vec<Vec<String>> {
+    ///have > 1 fields or any index options.
+    pub fn get_composite_indices(&self) -> Vec<&MultiFieldIndex> {
         self.multi_field_indexes
             .iter()
-            .cloned()
-            .filter_map(|multi_field_index| {
-                if multi_field_index.0.len() > 1 {
-                    Some(multi_field_index.0)
-                } else {
-                    None
-                }
+            .filter(|multi_field_index| {
+                multi_field_index.0.len() > 1 || multi_field_index.has_options()
             })
             .collect()
     }
 }
 
+///Parses the optional @renamedFrom directive of an entity or a field
+fn get_renamed_from(
+    directives: &[Directive<String>],
+    location: &str,
+) -> anyhow::Result<Option<String>> {
+    let renamed_from_directives = directives
+        .iter()
+        .filter(|directive| directive.name == RENAMED_FROM_DIRECTIVE_NAME)
+        .collect::<Vec<_>>();
+
+    let renamed_from_directive = match renamed_from_directives.as_slice() {
+        [] => return Ok(None),
+        [renamed_from_directive] => renamed_from_directive,
+        _ => {
+            return Err(anyhow!(
+                "EE226: Cannot use more than one @renamedFrom directive on {location}"
+            ))
+        }
+    };
+
+    match renamed_from_directive.arguments.as_slice() {
+        [(arg_name, Value::String(name))] if arg_name == "name" => Ok(Some(name.clone())),
+        _ => Err(anyhow!(
+            "EE226: The @renamedFrom directive on {location} needs a single string argument \
+             called 'name'"
+        )),
+    }
+}
+
+fn strip_renamed_from_directives(mut document: Document<String>) -> Document<String> {
+    for definition in document.definitions.iter_mut() {
+        if let Definition::TypeDefinition(TypeDefinition::Object(obj)) = definition {
+            obj.directives
+                .retain(|directive| directive.name != RENAMED_FROM_DIRECTIVE_NAME);
+            for field in obj.fields.iter_mut() {
+                field
+                    .directives
+                    .retain(|directive| directive.name != RENAMED_FROM_DIRECTIVE_NAME);
+            }
+        }
+    }
+    document
+}
+
+///Keeps only the given values on the enums of the document. Enums that aren't
+///in the map are left as they are.
+fn retain_enum_values<'a>(
+    mut document: Document<'a, String>,
+    enum_values: &HashMap<String, Vec<String>>,
+) -> Document<'a, String> {
+    for definition in document.definitions.iter_mut() {
+        if let Definition::TypeDefinition(TypeDefinition::Enum(enm)) = definition {
+            if let Some(values) = enum_values.get(&enm.name) {
+                enm.values.retain(|value| values.contains(&value.name));
+            }
+        }
+    }
+    document
+}
+
+///Returns the document with all the entities and fields named as before their
+///@renamedFrom hints, including the references to them in other directives.
+///None if there are no hints to revert.
+fn revert_renames<'a>(document: &Document<'a, String>) -> Option<Document<'a, String>> {
+    let get_renamed_from = |directives: &[Directive<String>]| {
+        directives
+            .iter()
+            .find(|directive| directive.name == RENAMED_FROM_DIRECTIVE_NAME)
+            .and_then(|directive| match directive.arguments.as_slice() {
+                [(_, Value::String(name))] => Some(name.clone()),
+                _ => None,
+            })
+    };
+
+    let objects = || {
+        document
+            .definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                Definition::TypeDefinition(TypeDefinition::Object(obj)) => Some(obj),
+                _ => None,
+            })
+    };
+
+    //Current entity name to its previous name
+    let entity_renames: HashMap<String, String> = objects()
+        .filter_map(|obj| Some((obj.name.clone(), get_renamed_from(&obj.directives)?)))
+        .collect();
+    //Current entity and field name to the previous field name
+    let field_renames: HashMap<(String, String), String> = objects()
+        .flat_map(|obj| {
+            obj.fields.iter().filter_map(|field| {
+                Some((
+                    (obj.name.clone(), field.name.clone()),
+                    get_renamed_from(&field.directives)?,
+                ))
+            })
+        })
+        .collect();
+
+    if entity_renames.is_empty() && field_renames.is_empty() {
+        return None;
+    }
+
+    let revert_entity_name = |name: &mut String| {
+        if let Some(renamed_from) = entity_renames.get(name) {
+            *name = renamed_from.clone();
+        }
+    };
+    let revert_field_name = |entity_name: &str, name: &mut String| {
+        if let Some(renamed_from) = field_renames.get(&(entity_name.to_string(), name.clone())) {
+            *name = renamed_from.clone();
+        }
+    };
+    fn revert_type_name(field_type: &mut ObjType<String>, revert: &dyn Fn(&mut String)) {
+        match field_type {
+            ObjType::NamedType(name) => revert(name),
+            ObjType::ListType(field_type) | ObjType::NonNullType(field_type) => {
+                revert_type_name(field_type, revert)
+            }
+        }
+    }
+    fn get_type_name<'b>(field_type: &'b ObjType<String>) -> &'b String {
+        match field_type {
+            ObjType::NamedType(name) => name,
+            ObjType::ListType(field_type) | ObjType::NonNullType(field_type) => {
+                get_type_name(field_type)
+            }
+        }
+    }
+    fn directive_args<'d, 'b>(
+        directives: &'b mut [Directive<'d, String>],
+        directive_name: &'b str,
+        arg_name: &'b str,
+    ) -> impl Iterator<Item = &'b mut Value<'d, String>> + 'b {
+        directives
+            .iter_mut()
+            .filter(move |directive| directive.name == directive_name)
+            .flat_map(move |directive| {
+                directive
+                    .arguments
+                    .iter_mut()
+                    .filter(move |(name, _)| name == arg_name)
+                    .map(|(_, value)| value)
+            })
+    }
+
+    let mut document = document.clone();
+    for definition in document.definitions.iter_mut() {
+        let Definition::TypeDefinition(TypeDefinition::Object(obj)) = definition else {
+            continue;
+        };
+        let entity_name = obj.name.clone();
+        let aggregation_source = obj
+            .directives
+            .iter()
+            .filter(|directive| directive.name == "aggregation")
+            .flat_map(|directive| directive.arguments.iter())
+            .find_map(|(name, value)| match (name.as_str(), value) {
+                ("source", Value::String(source)) => Some(source.clone()),
+                _ => None,
+            });
+
+        for directive_name in ["index", "fulltext"] {
+            for value in directive_args(&mut obj.directives, directive_name, "fields") {
+                if let Value::List(values) = value {
+                    for value in values.iter_mut() {
+                        if let Value::String(field_name) = value {
+                            revert_field_name(&entity_name, field_name);
+                        }
+                    }
+                }
+            }
+        }
+        for value in directive_args(&mut obj.directives, "aggregation", "source") {
+            if let Value::String(source) = value {
+                revert_entity_name(source);
+            }
+        }
+
+        for field in obj.fields.iter_mut() {
+            let type_name = get_type_name(&field.field_type).clone();
+            for value in directive_args(&mut field.directives, "derivedFrom", "field") {
+                if let Value::String(derived_from_field) = value {
+                    revert_field_name(&type_name, derived_from_field);
+                }
+            }
+            if let Some(aggregation_source) = &aggregation_source {
+                for value in directive_args(&mut field.directives, "aggregate", "arg") {
+                    if let Value::String(arg) = value {
+                        revert_field_name(aggregation_source, arg);
+                    }
+                }
+            }
+            revert_type_name(&mut field.field_type, &revert_entity_name);
+            revert_field_name(&entity_name, &mut field.name);
+        }
+        revert_entity_name(&mut obj.name);
+    }
+
+    Some(document)
+}
+
 ///  used to get the positive integers in the directives from the GraphQL schema.
 fn get_positive_integer(arg_value: &Value<String>) -> anyhow::Result<u32> {
     match arg_value {
@@ -544,6 +1768,12 @@ fn get_positive_integer(arg_value: &Value<String>) -> anyhow::Result<u32> {
 pub struct Field {
     pub name: String,
     pub field_type: FieldType,
+    ///Description of the field in the schema
+    pub description: Option<String>,
+    ///Set with the @deprecated directive
+    pub deprecation_reason: Option<String>,
+    ///Previous name of the field, set with the @renamedFrom directive
+    pub renamed_from: Option<String>,
 }
 
 impl Field {
@@ -705,10 +1935,43 @@ impl Field {
                     pg_type_modifications.big_decimal_precision_scale =
                         Some((precision.unwrap(), scale.unwrap()));
                 }
+                GqlScalar::Bytes(_) => {
+                    // Process storage for Bytes
+                    if config_directive.arguments.len() != 1 {
+                        return Err(anyhow!(
+                            "EE232: The config directive on Bytes should only take a single \
+                             string argument called 'storage'. Field '{}'",
+                            field.name
+                        ));
+                    }
+                    let (arg_name, arg_value) = config_directive.arguments.first().unwrap();
+                    if arg_name != "storage" {
+                        return Err(anyhow!(
+                            "EE232: The config directive on Bytes should only have a 'storage' \
+                             parameter. Unknown parameter '{}'. Field '{}'",
+                            arg_name,
+                            field.name
+                        ));
+                    }
+                    let storage = match arg_value {
+                        Value::String(val) => BytesStorage::from_str(val),
+                        _ => None,
+                    }
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "EE232: The 'storage' parameter of the config directive on Bytes \
+                             must be either \"text\" or \"bytea\". \"bytea\" stores the raw \
+                             bytes at half the size, but Hasura returns them as \\x instead of \
+                             0x prefixed hex. Field '{}'",
+                            field.name
+                        )
+                    })?;
+                    pg_type_modifications.bytes_storage = Some(storage);
+                }
                 _ => {
                     return Err(anyhow!(
-                        "EE215: The config directive is only applicable to BigInt and BigDecimal \
-                         scalar types. Field '{}'",
+                        "EE215: The config directive is only applicable to BigInt, BigDecimal \
+                         and Bytes scalar types. Field '{}'",
                         field.name
                     ));
                 }
@@ -724,12 +1987,82 @@ impl Field {
         let field_type = FieldType::from_obj_field_type(&field.field_type, params)
             .context(format!("Failed parsing field {}", field.name))?;
 
+        let deprecation_reason = Self::get_deprecation_reason(field)?;
+
+        let renamed_from = get_renamed_from(&field.directives, &format!("field {}", field.name))?;
+        match &renamed_from {
+            Some(renamed_from) if renamed_from == &field.name => {
+                return Err(anyhow!(
+                    "EE226: The @renamedFrom directive on field {} needs a name different from \
+                     the current one",
+                    field.name
+                ))
+            }
+            Some(_) if field_type.is_derived_from() => {
+                return Err(anyhow!(
+                    "EE226: The @renamedFrom directive can't be used on the @derivedFrom field \
+                     {}, since it isn't stored in the database",
+                    field.name
+                ))
+            }
+            _ => (),
+        }
+
         Ok(Field {
             name: field.name.clone(),
             field_type,
+            description: field.description.clone(),
+            deprecation_reason,
+            renamed_from,
         })
     }
 
+    ///Parses the optional @deprecated directive, the reason defaults
+    ///to the one from the GraphQL spec
+    fn get_deprecation_reason(field: &ObjField<String>) -> anyhow::Result<Option<String>> {
+        const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
+
+        let deprecated_directives = field
+            .directives
+            .iter()
+            .filter(|directive| directive.name == "deprecated")
+            .collect::<Vec<_>>();
+
+        let deprecated_directive = match deprecated_directives.as_slice() {
+            [] => return Ok(None),
+            [deprecated_directive] => deprecated_directive,
+            _ => {
+                return Err(anyhow!(
+                    "EE225: Cannot use more than one @deprecated directive on field {}",
+                    field.name
+                ))
+            }
+        };
+
+        match deprecated_directive.arguments.as_slice() {
+            [] => Ok(Some(DEFAULT_DEPRECATION_REASON.to_string())),
+            [(arg_name, Value::String(reason))] if arg_name == "reason" => Ok(Some(reason.clone())),
+            _ => Err(anyhow!(
+                "EE225: The @deprecated directive on field {} only takes an optional string \
+                 argument called 'reason'",
+                field.name
+            )),
+        }
+    }
+
+    ///Description of the field together with its deprecation reason. Used for
+    ///the column comment in the database.
+    pub fn get_documentation(&self) -> Option<String> {
+        match (&self.description, &self.deprecation_reason) {
+            (None, None) => None,
+            (Some(description), None) => Some(description.clone()),
+            (None, Some(reason)) => Some(format!("Deprecated: {reason}")),
+            (Some(description), Some(reason)) => {
+                Some(format!("{description}\n\nDeprecated: {reason}"))
+            }
+        }
+    }
+
     fn get_relationship(&self) -> Option<Relationship> {
         match self.field_type.get_underlying_scalar() {
             GqlScalar::Custom(name) => Some(Relationship::TypeDef { name: name.clone() }),
@@ -771,7 +2104,9 @@ impl Field {
                     //In the case where its just an an ID or a string,
                     //just keep the the field as is from what was
                     //defined in @derivedFrom
-                    GqlScalar::ID | GqlScalar::String => Ok(derived_from_field.clone()),
+                    GqlScalar::ID | GqlScalar::String | GqlScalar::Int8 | GqlScalar::Bytes(_) => {
+                        Ok(derived_from_field.clone())
+                    }
                     _ => Err(anyhow!(
                         "Unexpected, derived from field is neither an ID, String or bidirectional \
                          relationship"
@@ -794,6 +2129,20 @@ impl Field {
         has_indexed_directive || has_single_field_index_directive
     }
 
+    ///Whether the field gets a default btree index on its column. Single field
+    ///indexes with options are created together with the composite indices.
+    fn has_default_db_index(&self, entity: &Entity) -> bool {
+        let has_indexed_directive = self.field_type.has_indexed_directive();
+        let has_default_single_field_index_directive = entity
+            .multi_field_indexes
+            .iter()
+            .filter(|multi_field_index| !multi_field_index.has_options())
+            .filter_map(MultiFieldIndex::get_single_field_index)
+            .any(|single_field_index| single_field_index == self.name);
+
+        has_indexed_directive || has_default_single_field_index_directive
+    }
+
     pub fn is_derived_lookup_field(&self, entity: &Entity, schema: &Schema) -> bool {
         schema.entities.values().fold(false, |accum, entity_inner| {
             accum
@@ -813,8 +2162,11 @@ impl Field {
         })
     }
 
-    pub fn is_primary_key(&self) -> bool {
-        self.name.as_str().to_lowercase() == "id"
+    pub fn is_primary_key(&self, entity: &Entity) -> bool {
+        match &entity.composite_key {
+            Some(composite_key) => composite_key.contains(&self.name),
+            None => self.name.as_str().to_lowercase() == "id",
+        }
     }
 
     ///Returns None if it is a derived field
@@ -832,10 +2184,12 @@ impl Field {
                 field_name: self.name.clone(),
                 field_type: gql_field_type.to_underlying_postgres_primitive(schema)?,
                 is_array: gql_field_type.is_array(),
-                is_index: self.is_indexed_field(entity),
+                is_index: self.has_default_db_index(entity),
                 linked_entity: gql_field_type.get_linked_entity(schema)?,
-                is_primary_key: self.is_primary_key(),
+                is_primary_key: self.is_primary_key(entity),
                 is_nullable: gql_field_type.is_optional(),
+                description: self.get_documentation(),
+                renamed_from: self.renamed_from.clone(),
             })),
         }
     }
@@ -855,20 +2209,141 @@ impl Field {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct MultiFieldIndex(Vec<String>);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndexMethod {
+    Btree,
+    Hash,
+    Gin,
+    Brin,
+}
 
-impl MultiFieldIndex {
-    fn new(field_names: Vec<String>) -> Self {
-        Self(field_names.into_iter().collect())
+impl IndexMethod {
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "btree" => Some(Self::Btree),
+            "hash" => Some(Self::Hash),
+            "gin" => Some(Self::Gin),
+            "brin" => Some(Self::Brin),
+            _ => None,
+        }
     }
+}
 
-    pub fn get_field_names(&self) -> &Vec<String> {
-        &self.0
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndexOrder {
+    Asc,
+    Desc,
+}
 
-    fn get_single_field_index(&self) -> Option<String> {
-        if self.0.len() == 1 {
+impl IndexOrder {
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "asc" => Some(Self::Asc),
+            "desc" => Some(Self::Desc),
+            _ => None,
+        }
+    }
+}
+
+///Optional arguments of the entity level @index directive
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IndexOptions {
+    pub method: Option<IndexMethod>,
+    pub order: Option<IndexOrder>,
+    ///Predicate of a partial index, used as is in the WHERE clause
+    pub where_predicate: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MultiFieldIndex(Vec<String>, IndexOptions);
+
+impl MultiFieldIndex {
+    fn new(field_names: Vec<String>, options: IndexOptions) -> Self {
+        Self(field_names.into_iter().collect(), options)
+    }
+
+    fn from_directive(directive: &Directive<'_, String>) -> anyhow::Result<Self> {
+        let get_string_arg = |arg_name: &str, arg_value: &Value<String>| match arg_value {
+            Value::String(val) => Ok(val.clone()),
+            _ => Err(anyhow!(
+                "EE219: The '{arg_name}' argument of the @index directive needs to be a string"
+            )),
+        };
+
+        let mut index_fields = None;
+        let mut options = IndexOptions::default();
+
+        for (arg_name, arg_value) in &directive.arguments {
+            match (arg_name.as_str(), arg_value) {
+                ("fields", Value::List(fields)) => {
+                    let fields = fields
+                        .iter()
+                        .map(|v| {
+                            if let Value::String(field_name) = v {
+                                Ok(field_name.clone())
+                            } else {
+                                Err(anyhow!("Listed index field should be a string"))
+                            }
+                        })
+                        .collect::<anyhow::Result<Vec<String>>>()
+                        .context("Failed to get fields in index")?;
+                    index_fields = Some(fields);
+                }
+                ("method", val) => {
+                    let method = get_string_arg(arg_name, val)?;
+                    options.method = Some(IndexMethod::from_str(&method).ok_or_else(|| {
+                        anyhow!(
+                            "EE219: Invalid index method '{method}'. Supported methods are \
+                             \"btree\", \"hash\", \"gin\" and \"brin\""
+                        )
+                    })?);
+                }
+                ("order", val) => {
+                    let order = get_string_arg(arg_name, val)?;
+                    options.order = Some(IndexOrder::from_str(&order).ok_or_else(|| {
+                        anyhow!(
+                            "EE219: Invalid index order '{order}'. Supported orders are \"asc\" \
+                             and \"desc\""
+                        )
+                    })?);
+                }
+                ("where", val) => {
+                    options.where_predicate = Some(get_string_arg(arg_name, val)?);
+                }
+                ("fields", _) => (),
+                (unknown_arg, _) => {
+                    return Err(anyhow!(
+                        "EE219: Unknown argument '{unknown_arg}' in @index directive. Supported \
+                         arguments are 'fields', 'method', 'order' and 'where'"
+                    ))
+                }
+            }
+        }
+
+        match index_fields {
+            Some(index_fields) => Ok(Self::new(index_fields, options)),
+            None => Err(anyhow!(
+                "Invalid @index directive. Please ensure index has a key of fields with a list \
+                 of strings matching field names in your entity. Eg. @index(fields: [\"fieldA\", \
+                 \"fieldB\"])"
+            )),
+        }
+    }
+
+    pub fn get_field_names(&self) -> &Vec<String> {
+        &self.0
+    }
+
+    pub fn get_options(&self) -> &IndexOptions {
+        &self.1
+    }
+
+    fn has_options(&self) -> bool {
+        self.1 != IndexOptions::default()
+    }
+
+    fn get_single_field_index(&self) -> Option<String> {
+        if self.0.len() == 1 {
             self.0.get(0).cloned()
         } else {
             None
@@ -913,7 +2388,11 @@ impl MultiFieldIndex {
         }
 
         //Check for @index directives on the defined field
-        if let Some(single_field_index) = self.get_single_field_index() {
+        //A single field index with options is a different index, so it's allowed
+        if let Some(single_field_index) = self
+            .get_single_field_index()
+            .filter(|_| !self.has_options())
+        {
             if let Some(field) = fields.get(&single_field_index) {
                 if field.field_type.has_indexed_directive() {
                     return Err(anyhow!(
@@ -947,6 +2426,56 @@ impl MultiFieldIndex {
         Ok(self)
     }
 
+    fn validate_index_options(self, fields: &HashMap<String, Field>) -> anyhow::Result<Self> {
+        let IndexOptions {
+            method,
+            order,
+            where_predicate,
+        } = self.get_options();
+
+        if method == &Some(IndexMethod::Hash) && self.0.len() > 1 {
+            return Err(anyhow!(
+                "EE219: Hash indexes only support a single field. Index on fields {:?}",
+                self.0
+            ));
+        }
+
+        if order.is_some() && matches!(method, Some(m) if m != &IndexMethod::Btree) {
+            return Err(anyhow!(
+                "EE219: The 'order' argument is only supported by btree indexes. Index on fields \
+                 {:?}",
+                self.0
+            ));
+        }
+
+        //Gin indexes need an operator class for the column type. Of the column types
+        //of entity fields, Postgres only has a default one for arrays
+        if method == &Some(IndexMethod::Gin) {
+            if let Some(field_name) = self.0.iter().find(|field_name| {
+                !fields
+                    .get(*field_name)
+                    .is_some_and(|field| field.field_type.is_array())
+            }) {
+                return Err(anyhow!(
+                    "EE219: Gin indexes are only supported on list fields. The field '{}' isn't \
+                     a list. Index on fields {:?}",
+                    field_name,
+                    self.0
+                ));
+            }
+        }
+
+        if matches!(where_predicate, Some(predicate) if predicate.trim().is_empty()) {
+            return Err(anyhow!(
+                "EE219: The 'where' argument of the @index directive can't be empty. Index on \
+                 fields {:?}",
+                self.0
+            ));
+        }
+
+        Ok(self)
+    }
+
     fn validate_no_index_on_id_field(self) -> anyhow::Result<Self> {
         if let Some(single_field_index) = self.get_single_field_index() {
             if single_field_index == "id" {
@@ -960,6 +2489,429 @@ impl MultiFieldIndex {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AggregationInterval {
+    Hour,
+    Day,
+}
+
+impl AggregationInterval {
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "hour" => Some(Self::Hour),
+            "day" => Some(Self::Day),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AggregateFn {
+    Sum,
+    Count,
+    Min,
+    Max,
+    First,
+    Last,
+}
+
+impl AggregateFn {
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "sum" => Some(Self::Sum),
+            "count" => Some(Self::Count),
+            "min" => Some(Self::Min),
+            "max" => Some(Self::Max),
+            "first" => Some(Self::First),
+            "last" => Some(Self::Last),
+            _ => None,
+        }
+    }
+}
+
+///A field of an aggregation entity defined with @aggregate(fn: "...", arg: "...")
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Aggregate {
+    pub field_name: String,
+    pub function: AggregateFn,
+    ///Name of the aggregated field on the source timeseries entity.
+    ///Not used by "count".
+    pub arg: Option<String>,
+}
+
+impl Aggregate {
+    fn from_directive(field_name: &str, directive: &Directive<'_, String>) -> anyhow::Result<Self> {
+        let mut function = None;
+        let mut arg = None;
+
+        for (arg_name, arg_value) in &directive.arguments {
+            match (arg_name.as_str(), arg_value) {
+                ("fn", Value::String(val)) => {
+                    function = Some(AggregateFn::from_str(val).ok_or_else(|| {
+                        anyhow!(
+                            "EE223: Invalid aggregate function '{val}' on field {field_name}. \
+                             Supported functions are \"sum\", \"count\", \"min\", \"max\", \
+                             \"first\" and \"last\""
+                        )
+                    })?);
+                }
+                ("arg", Value::String(val)) => arg = Some(val.clone()),
+                ("fn" | "arg", _) => {
+                    return Err(anyhow!(
+                        "EE223: The '{arg_name}' argument of the @aggregate directive on field \
+                         {field_name} needs to be a string"
+                    ))
+                }
+                (unknown_arg, _) => {
+                    return Err(anyhow!(
+                        "EE223: Unknown argument '{unknown_arg}' in the @aggregate directive on \
+                         field {field_name}. Supported arguments are 'fn' and 'arg'"
+                    ))
+                }
+            }
+        }
+
+        let function = function.ok_or_else(|| {
+            anyhow!(
+                "EE223: No 'fn' argument supplied to @aggregate directive on field {field_name}"
+            )
+        })?;
+
+        match (function, &arg) {
+            (AggregateFn::Count, Some(_)) => Err(anyhow!(
+                "EE223: The \"count\" aggregate function doesn't take an 'arg'. Field {field_name}"
+            )),
+            (AggregateFn::Count, None) | (_, Some(_)) => Ok(Self {
+                field_name: field_name.to_string(),
+                function,
+                arg,
+            }),
+            (_, None) => Err(anyhow!(
+                "EE223: No 'arg' argument supplied to @aggregate directive on field {field_name}"
+            )),
+        }
+    }
+}
+
+///Defined with @aggregation(intervals: [...], source: "...") on an entity.
+///Rows of an aggregation are rolled up from its source timeseries entity
+///for every interval and are never written by handlers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Aggregation {
+    pub source: String,
+    pub intervals: Vec<AggregationInterval>,
+    pub aggregates: Vec<Aggregate>,
+}
+
+impl Aggregation {
+    ///Implicit field storing which interval a rolled up row belongs to
+    pub const INTERVAL_FIELD_NAME: &'static str = "interval";
+
+    fn from_object(obj: &ObjectType<String>) -> anyhow::Result<Option<Self>> {
+        let aggregates = obj
+            .fields
+            .iter()
+            .flat_map(|field| {
+                field
+                    .directives
+                    .iter()
+                    .filter(|directive| directive.name == "aggregate")
+                    .map(|directive| Aggregate::from_directive(&field.name, directive))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        if let Some(aggregate) = aggregates.iter().find(|aggregate| {
+            aggregate.field_name == "id" || aggregate.field_name == TIMESERIES_TIMESTAMP_FIELD_NAME
+        }) {
+            return Err(anyhow!(
+                "EE223: The @aggregate directive can't be used on the '{}' field of entity {}",
+                aggregate.field_name,
+                obj.name
+            ));
+        }
+
+        let aggregation_directives = obj
+            .directives
+            .iter()
+            .filter(|directive| directive.name == "aggregation")
+            .collect::<Vec<_>>();
+
+        let aggregation_directive = match aggregation_directives.as_slice() {
+            [] if aggregates.is_empty() => return Ok(None),
+            [] => {
+                return Err(anyhow!(
+                    "EE223: The @aggregate directive can only be used on fields of entities with \
+                     the @aggregation directive. Entity {}",
+                    obj.name
+                ))
+            }
+            [aggregation_directive] => aggregation_directive,
+            _ => {
+                return Err(anyhow!(
+                    "EE222: Cannot use more than one @aggregation directive on entity {}",
+                    obj.name
+                ))
+            }
+        };
+
+        let mut intervals = None;
+        let mut source = None;
+
+        for (arg_name, arg_value) in &aggregation_directive.arguments {
+            match (arg_name.as_str(), arg_value) {
+                ("intervals", Value::List(values)) => {
+                    let values = values
+                        .iter()
+                        .map(|value| match value {
+                            Value::String(val) => AggregationInterval::from_str(val),
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "EE222: Invalid intervals in the @aggregation directive on entity \
+                                 {}. Supported intervals are \"hour\" and \"day\"",
+                                obj.name
+                            )
+                        })?;
+                    intervals = Some(values.into_iter().unique().collect::<Vec<_>>());
+                }
+                ("source", Value::String(val)) => source = Some(val.clone()),
+                ("intervals", _) => {
+                    return Err(anyhow!(
+                        "EE222: The 'intervals' argument of the @aggregation directive on entity \
+                         {} needs to be a list of strings",
+                        obj.name
+                    ))
+                }
+                ("source", _) => {
+                    return Err(anyhow!(
+                        "EE222: The 'source' argument of the @aggregation directive on entity {} \
+                         needs to be a string",
+                        obj.name
+                    ))
+                }
+                (unknown_arg, _) => {
+                    return Err(anyhow!(
+                        "EE222: Unknown argument '{}' in the @aggregation directive on entity {}. \
+                         Supported arguments are 'intervals' and 'source'",
+                        unknown_arg,
+                        obj.name
+                    ))
+                }
+            }
+        }
+
+        let intervals = match intervals {
+            Some(intervals) if !intervals.is_empty() => intervals,
+            _ => {
+                return Err(anyhow!(
+                    "EE222: The @aggregation directive on entity {} needs at least one interval. \
+                     Eg. @aggregation(intervals: [\"hour\", \"day\"], source: \"...\")",
+                    obj.name
+                ))
+            }
+        };
+
+        let source = source.ok_or_else(|| {
+            anyhow!(
+                "EE222: No 'source' argument supplied to the @aggregation directive on entity {}",
+                obj.name
+            )
+        })?;
+
+        if aggregates.is_empty() {
+            return Err(anyhow!(
+                "EE222: The aggregation entity {} needs at least one field with the @aggregate \
+                 directive",
+                obj.name
+            ));
+        }
+
+        Ok(Some(Self {
+            source,
+            intervals,
+            aggregates,
+        }))
+    }
+
+    pub fn get_aggregate(&self, field_name: &str) -> Option<&Aggregate> {
+        self.aggregates.iter().find(|a| a.field_name == field_name)
+    }
+
+    ///Fields the rolled up rows are grouped by, next to the time bucket
+    pub fn get_dimension_fields<'a>(&self, entity: &'a Entity) -> Vec<&'a Field> {
+        entity
+            .get_fields()
+            .into_iter()
+            .filter(|field| {
+                !field.is_primary_key(entity)
+                    && field.name != TIMESERIES_TIMESTAMP_FIELD_NAME
+                    && field.name != Self::INTERVAL_FIELD_NAME
+                    && self.get_aggregate(&field.name).is_none()
+            })
+            .collect()
+    }
+}
+
+///Text search configurations available in a default Postgres installation
+const FULL_TEXT_SEARCH_LANGUAGES: &[&str] = &[
+    "simple",
+    "arabic",
+    "armenian",
+    "basque",
+    "catalan",
+    "danish",
+    "dutch",
+    "english",
+    "finnish",
+    "french",
+    "german",
+    "greek",
+    "hindi",
+    "hungarian",
+    "indonesian",
+    "irish",
+    "italian",
+    "lithuanian",
+    "nepali",
+    "norwegian",
+    "portuguese",
+    "romanian",
+    "russian",
+    "serbian",
+    "spanish",
+    "swedish",
+    "tamil",
+    "turkish",
+    "yiddish",
+];
+
+///Defined with @fulltext(name: "...", fields: [...], language: "...") on an entity.
+///Stored as a generated tsvector column with the given name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FullTextSearch {
+    pub name: String,
+    pub fields: Vec<String>,
+    pub language: String,
+}
+
+impl FullTextSearch {
+    const DEFAULT_LANGUAGE: &'static str = "simple";
+
+    fn from_directive(directive: &Directive<'_, String>) -> anyhow::Result<Self> {
+        let mut name = None;
+        let mut fields = None;
+        let mut language = Self::DEFAULT_LANGUAGE.to_string();
+
+        for (arg_name, arg_value) in &directive.arguments {
+            match (arg_name.as_str(), arg_value) {
+                ("name", Value::String(val)) => name = Some(val.clone()),
+                ("language", Value::String(val)) => language = val.clone(),
+                ("fields", Value::List(values)) => {
+                    fields = Some(
+                        values
+                            .iter()
+                            .map(|value| match value {
+                                Value::String(field_name) => Ok(field_name.clone()),
+                                _ => {
+                                    Err(anyhow!("EE224: Listed @fulltext fields should be strings"))
+                                }
+                            })
+                            .collect::<anyhow::Result<Vec<_>>>()?,
+                    )
+                }
+                ("name" | "language", _) => {
+                    return Err(anyhow!(
+                        "EE224: The '{arg_name}' argument of the @fulltext directive needs to be a \
+                         string"
+                    ))
+                }
+                ("fields", _) => {
+                    return Err(anyhow!(
+                        "EE224: The 'fields' argument of the @fulltext directive needs to be a \
+                         list of strings"
+                    ))
+                }
+                (unknown_arg, _) => {
+                    return Err(anyhow!(
+                        "EE224: Unknown argument '{unknown_arg}' in the @fulltext directive. \
+                         Supported arguments are 'name', 'fields' and 'language'"
+                    ))
+                }
+            }
+        }
+
+        let name = name
+            .ok_or_else(|| anyhow!("EE224: No 'name' argument supplied to @fulltext directive"))?;
+
+        if !is_valid_postgres_db_name(&name) {
+            return Err(anyhow!(
+                "EE224: The @fulltext name '{name}' is invalid. It must start with a letter, can \
+                 only contain letters, numbers, and underscores and have a maximum length of 63 \
+                 characters"
+            ));
+        }
+
+        let fields = match fields {
+            Some(fields) if !fields.is_empty() => fields,
+            _ => {
+                return Err(anyhow!(
+                    "EE224: The @fulltext directive '{name}' needs at least one field. Eg. \
+                     @fulltext(name: \"{name}\", fields: [\"fieldA\", \"fieldB\"])"
+                ))
+            }
+        };
+
+        if !FULL_TEXT_SEARCH_LANGUAGES.contains(&language.as_str()) {
+            return Err(anyhow!(
+                "EE224: Unsupported language '{language}' in the @fulltext directive '{name}'. \
+                 Supported languages are: {}",
+                FULL_TEXT_SEARCH_LANGUAGES.join(", ")
+            ));
+        }
+
+        Ok(Self {
+            name,
+            fields,
+            language,
+        })
+    }
+
+    fn validate_fields(&self, fields: &HashMap<String, Field>) -> anyhow::Result<()> {
+        if fields.contains_key(&self.name) {
+            return Err(anyhow!(
+                "EE224: The @fulltext name '{}' is already used by a field on the entity",
+                self.name
+            ));
+        }
+
+        for field_name in &self.fields {
+            let is_text_field = matches!(
+                fields.get(field_name),
+                Some(Field {
+                    field_type: FieldType::RegularField { field_type, .. },
+                    ..
+                }) if !field_type.is_array()
+                    && matches!(
+                        field_type.get_underlying_scalar(),
+                        GqlScalar::String | GqlScalar::ID
+                    )
+            );
+            if !is_text_field {
+                return Err(anyhow!(
+                    "EE224: The field '{}' of the @fulltext directive '{}' needs to be a String \
+                     field on the entity",
+                    field_name,
+                    self.name
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum UserDefinedFieldType {
     Single(GqlScalar),
@@ -1183,6 +3135,28 @@ impl Serialize for UserDefinedFieldType {
 pub struct PgTypeModifications {
     pub big_int_precision: Option<u32>,
     pub big_decimal_precision_scale: Option<(u32, u32)>,
+    pub bytes_storage: Option<BytesStorage>,
+}
+
+///How a Bytes field is stored in postgres. Handlers always see a 0x prefixed hex string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BytesStorage {
+    ///Hex string stored as TEXT
+    #[default]
+    Text,
+    ///Raw bytes stored as BYTEA, converted from and to hex at the db boundary.
+    ///Hasura returns the postgres hex format, so queries get \x instead of 0x prefixed hex
+    Bytea,
+}
+
+impl BytesStorage {
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "text" => Some(Self::Text),
+            "bytea" => Some(Self::Bytea),
+            _ => None,
+        }
+    }
 }
 
 pub struct FieldTypeParams {
@@ -1346,11 +3320,13 @@ pub enum GqlScalar {
     #[subenum(AdditionalGqlScalar)]
     BigInt(Option<u32>), // Optional argument, max digits (base 10) this number can have.
     #[subenum(AdditionalGqlScalar)]
+    Int8,
+    #[subenum(AdditionalGqlScalar)]
     BigDecimal(Option<(u32, u32)>),
     #[subenum(AdditionalGqlScalar)]
     Timestamp,
     #[subenum(AdditionalGqlScalar)]
-    Bytes,
+    Bytes(BytesStorage),
     Custom(String),
 }
 
@@ -1389,11 +3365,12 @@ impl GqlScalar {
             "Float" => GqlScalar::Float,
             "Boolean" => GqlScalar::Boolean,
             "BigInt" => GqlScalar::BigInt(pg_type_modifications.big_int_precision),
+            "Int8" => GqlScalar::Int8,
             "BigDecimal" => {
                 GqlScalar::BigDecimal(pg_type_modifications.big_decimal_precision_scale)
             }
             "Timestamp" => GqlScalar::Timestamp,
-            "Bytes" => GqlScalar::Bytes,
+            "Bytes" => GqlScalar::Bytes(pg_type_modifications.bytes_storage.unwrap_or_default()),
             name => GqlScalar::Custom(name.to_string()),
         }
     }
@@ -1403,9 +3380,11 @@ impl GqlScalar {
             GqlScalar::ID => PGPrimitive::Text,
             GqlScalar::String => PGPrimitive::Text,
             GqlScalar::Int => PGPrimitive::Integer,
+            GqlScalar::Int8 => PGPrimitive::Int8,
             GqlScalar::Float => PGPrimitive::DoublePrecision, // Should we allow this type? Rounding issues will abound.
             GqlScalar::Boolean => PGPrimitive::Boolean,
-            GqlScalar::Bytes => PGPrimitive::Text,
+            GqlScalar::Bytes(BytesStorage::Text) => PGPrimitive::Text,
+            GqlScalar::Bytes(BytesStorage::Bytea) => PGPrimitive::Bytea,
             GqlScalar::BigInt(None) => PGPrimitive::Numeric(None),
             GqlScalar::BigInt(Some(precision)) => PGPrimitive::Numeric(Some((*precision, 0))), //  We leave the scale as zero since it is not relevant for integers.
             GqlScalar::BigDecimal(None) => PGPrimitive::Numeric(None),
@@ -1414,8 +3393,12 @@ impl GqlScalar {
             }
             GqlScalar::Timestamp => PGPrimitive::Timestamp,
             GqlScalar::Custom(name) => match schema.try_get_type_def(name)? {
-                TypeDef::Entity(_) => PGPrimitive::Text,
+                //Relationship columns take the type of the referenced id
+                TypeDef::Entity(entity) => entity
+                    .get_id_scalar()
+                    .to_underlying_postgres_primitive(schema)?,
                 TypeDef::Enum => PGPrimitive::Enum(name.clone()),
+                TypeDef::Scalar(scalar) => scalar.base.to_underlying_postgres_primitive(schema)?,
             },
         };
         Ok(converted)
@@ -1426,15 +3409,19 @@ impl GqlScalar {
             GqlScalar::ID => RescriptTypeIdent::ID,
             GqlScalar::String => RescriptTypeIdent::String,
             GqlScalar::Int => RescriptTypeIdent::Int,
-            GqlScalar::BigInt(_) => RescriptTypeIdent::BigInt,
+            GqlScalar::BigInt(_) | GqlScalar::Int8 => RescriptTypeIdent::BigInt,
             GqlScalar::BigDecimal(_) => RescriptTypeIdent::BigDecimal,
             GqlScalar::Float => RescriptTypeIdent::Float,
-            GqlScalar::Bytes => RescriptTypeIdent::String,
+            GqlScalar::Bytes(BytesStorage::Text) => RescriptTypeIdent::String,
+            GqlScalar::Bytes(BytesStorage::Bytea) => RescriptTypeIdent::DbBytes,
             GqlScalar::Boolean => RescriptTypeIdent::Bool,
             GqlScalar::Timestamp => RescriptTypeIdent::Timestamp,
             GqlScalar::Custom(name) => match schema.try_get_type_def(name)? {
-                TypeDef::Entity(_) => RescriptTypeIdent::ID,
+                TypeDef::Entity(entity) => entity.get_id_scalar().to_rescript_type(schema)?,
                 TypeDef::Enum => RescriptTypeIdent::SchemaEnum(name.to_capitalized_options()),
+                TypeDef::Scalar(_) => {
+                    RescriptTypeIdent::SchemaScalar(name.to_capitalized_options())
+                }
             },
         };
         Ok(res_type)
@@ -1444,7 +3431,7 @@ impl GqlScalar {
         let opt_entity_name = match self {
             Self::Custom(name) => match schema.try_get_type_def(name)? {
                 TypeDef::Entity(entity) => Some(entity.name.clone()),
-                TypeDef::Enum => None,
+                TypeDef::Enum | TypeDef::Scalar(_) => None,
             },
             _ => None,
         };
@@ -1456,9 +3443,15 @@ impl GqlScalar {
 #[cfg(test)]
 mod tests {
     use super::{
-        anyhow, Entity, Field, FieldType, GqlScalar, GraphQLEnum, Schema, UserDefinedFieldType,
+        anyhow, retain_enum_values, revert_renames, strip_renamed_from_directives, Aggregate,
+        AggregateFn, AggregationInterval, BytesStorage, Entity, EnumValuesChange, Field, FieldType,
+        FullTextSearch, GqlScalar, GraphQLEnum, IndexMethod, IndexOptions, IndexOrder, Schema,
+        UserDefinedFieldType,
+    };
+    use crate::{
+        config_parsing::postgres_types::Primitive as PGPrimitive,
+        project_paths::ParsedProjectPaths, utils::normalized_list::SingleOrList,
     };
-    use crate::config_parsing::postgres_types::Primitive as PGPrimitive;
     use graphql_parser::schema::{parse_schema, Definition, Document, ObjectType, TypeDefinition};
 
     fn setup_document(schema: &str) -> anyhow::Result<Document<String>> {
@@ -1720,8 +3713,18 @@ type TestEntity {
     #[test]
     fn gql_type_to_rescript_type_entity() {
         let test_entity_string = String::from("TestEntity");
-        let test_entity = Entity::new(&test_entity_string, vec![], vec![]).unwrap();
-        let schema = Schema::new(vec![test_entity], vec![]).unwrap();
+        let test_entity = Entity::new(
+            &test_entity_string,
+            vec![],
+            vec![],
+            false,
+            false,
+            None,
+            vec![],
+            None,
+        )
+        .unwrap();
+        let schema = Schema::new(vec![test_entity], vec![], vec![]).unwrap();
         let rescript_type = UserDefinedFieldType::Single(GqlScalar::Custom(test_entity_string))
             .to_rescript_type(&schema)
             .expect("expected rescript type string");
@@ -1733,7 +3736,7 @@ type TestEntity {
     fn gql_type_to_rescript_type_enum() {
         let name = String::from("TestEnum");
         let test_enum = GraphQLEnum::new(name.clone(), vec![]).unwrap();
-        let schema = Schema::new(vec![], vec![test_enum]).unwrap();
+        let schema = Schema::new(vec![], vec![test_enum], vec![]).unwrap();
         let rescript_type = UserDefinedFieldType::Single(GqlScalar::Custom(name))
             .to_rescript_type(&schema)
             .expect("expected rescript type string");
@@ -1813,7 +3816,7 @@ type TestEntity {
         let test_enum = GraphQLEnum::new(name.clone(), vec!["TEST_VALUE".to_string()]).unwrap();
         let field_type =
             get_field_type_helper_with_additional("TestEnum!", vec![test_enum.clone()]);
-        let schema = Schema::new(vec![], vec![test_enum]).unwrap();
+        let schema = Schema::new(vec![], vec![test_enum], vec![]).unwrap();
         let pg_primitive = field_type
             .to_user_defined_field_type()
             .to_underlying_postgres_primitive(&schema)
@@ -2328,25 +4331,775 @@ type TestEntity {
     }
 
     #[test]
-    fn multifield_index_should_retain_original_order() {
+    fn test_bytes_storage_config() {
         let schema_str = r#"
-        type Entity 
-        @index(fields: ["a", "b"])
-        @index(fields: ["b", "a"])
-        {
-            id: ID!
-            a: String!
-            b: String!
-        }
+type TestEntity {
+  id: ID!
+  hash: Bytes! @config(storage: "bytea")
+  calldata: [Bytes!] @config(storage: "bytea")
+  textHash: Bytes! @config(storage: "text")
+  defaultHash: Bytes!
+}
         "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+        let entity = schema.entities.get("TestEntity").unwrap();
 
-        let gql_doc = setup_document(schema_str).expect("Failed to parse schema string");
-        let schema = Schema::from_document(gql_doc).expect("Failed to parse schema from doc");
-        let entity = schema.entities.get("Entity").expect("Entity not found");
+        let get_pg_field = |name: &str| {
+            entity
+                .fields
+                .get(name)
+                .unwrap()
+                .get_postgres_field(&schema, entity)
+                .expect("Failed to get postgres field")
+                .unwrap()
+        };
 
-        assert_eq!(entity.multi_field_indexes.len(), 2);
-        assert_eq!(
-            entity.multi_field_indexes[0].0,
+        let hash = get_pg_field("hash");
+        assert_eq!(hash.field_type, PGPrimitive::Bytea);
+        assert!(!hash.is_array);
+
+        let calldata = get_pg_field("calldata");
+        assert_eq!(calldata.field_type, PGPrimitive::Bytea);
+        assert!(calldata.is_array);
+        assert!(calldata.is_nullable);
+
+        assert_eq!(get_pg_field("textHash").field_type, PGPrimitive::Text);
+        assert_eq!(get_pg_field("defaultHash").field_type, PGPrimitive::Text);
+    }
+
+    #[test]
+    fn test_error_case_bytes_storage_unknown_value() {
+        let schema_str = r#"
+        type Entity {
+            id: ID!
+            hash: Bytes! @config(storage: "blob")
+        }
+        "#;
+
+        let gql_doc = setup_document(schema_str).expect("Failed to parse schema");
+        let result = Schema::from_document(gql_doc);
+
+        assert!(result.is_err());
+        let err_message = format!("{:?}", result.unwrap_err());
+        assert!(err_message.contains("EE232"));
+        assert!(
+            err_message.contains("Hasura returns them as \\x instead of 0x prefixed hex"),
+            "The error should document how Hasura returns bytea. Got {err_message}"
+        );
+    }
+
+    #[test]
+    fn test_entity_immutable_directive() {
+        let schema_str = r#"
+type Transfer @entity(immutable: true) {
+  id: ID!
+  value: BigInt!
+}
+
+type Account @entity {
+  id: ID!
+}
+
+type Token @entity(immutable: false) {
+  id: ID!
+}
+
+type Pool {
+  id: ID!
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+
+        assert!(schema.entities.get("Transfer").unwrap().is_immutable);
+        assert!(!schema.entities.get("Account").unwrap().is_immutable);
+        assert!(!schema.entities.get("Token").unwrap().is_immutable);
+        assert!(!schema.entities.get("Pool").unwrap().is_immutable);
+    }
+
+    #[test]
+    fn test_error_case_entity_directive_unknown_argument() {
+        let schema_str = r#"
+        type Transfer @entity(sparse: true) {
+            id: ID!
+        }
+        "#;
+
+        let gql_doc = setup_document(schema_str).expect("Failed to parse schema");
+        let result = Schema::from_document(gql_doc);
+
+        assert!(result.is_err());
+        let err_message = format!("{:?}", result.unwrap_err());
+        assert!(err_message.contains("EE218"));
+    }
+
+    #[test]
+    fn timeseries_and_aggregation_are_parsed() {
+        let schema_str = r#"
+type Token @entity {
+  id: ID!
+}
+
+type Swap @entity(timeseries: true) {
+  id: ID!
+  timestamp: Timestamp!
+  token: Token!
+  price: BigDecimal!
+  amount: BigInt!
+}
+
+type TokenStats @aggregation(intervals: ["hour", "day"], source: "Swap") {
+  id: ID!
+  timestamp: Timestamp!
+  token: Token!
+  volume: BigInt! @aggregate(fn: "sum", arg: "amount")
+  swapCount: Int! @aggregate(fn: "count")
+  high: BigDecimal! @aggregate(fn: "max", arg: "price")
+  close: BigDecimal! @aggregate(fn: "last", arg: "price")
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+
+        let swap = schema.entities.get("Swap").unwrap();
+        assert!(swap.is_timeseries);
+        assert!(swap.is_immutable);
+        assert_eq!(swap.aggregation, None);
+
+        let token_stats = schema.entities.get("TokenStats").unwrap();
+        let aggregation = token_stats.aggregation.as_ref().unwrap();
+        assert_eq!(aggregation.source, "Swap");
+        assert_eq!(
+            aggregation.intervals,
+            vec![AggregationInterval::Hour, AggregationInterval::Day]
+        );
+        assert_eq!(
+            aggregation.get_aggregate("swapCount"),
+            Some(&Aggregate {
+                field_name: "swapCount".to_string(),
+                function: AggregateFn::Count,
+                arg: None,
+            })
+        );
+        assert_eq!(
+            aggregation
+                .get_dimension_fields(token_stats)
+                .into_iter()
+                .map(|field| field.name.clone())
+                .collect::<Vec<_>>(),
+            vec!["token".to_string()]
+        );
+        assert!(token_stats.fields.contains_key("interval"));
+        assert_eq!(
+            token_stats.get_composite_indices()[0].get_field_names(),
+            &vec!["interval".to_string(), "timestamp".to_string()]
+        );
+    }
+
+    #[test]
+    fn aggregation_errors() {
+        let expect_error = |schema_str: &str, error_code: &str| {
+            let gql_doc = setup_document(schema_str).unwrap();
+            let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+            assert!(
+                err_message.contains(error_code),
+                "Expected {error_code} but got {err_message}"
+            );
+        };
+
+        let source = r#"
+type Swap @entity(timeseries: true) {
+  id: ID!
+  timestamp: Timestamp!
+  pool: String!
+  amount: BigInt!
+}
+        "#;
+
+        //Timeseries entity without a timestamp
+        expect_error(
+            r#"type Swap @entity(timeseries: true) { id: ID! amount: BigInt! }"#,
+            "EE221",
+        );
+        //Timeseries entity explicitly mutable
+        expect_error(
+            r#"type Swap @entity(timeseries: true, immutable: false) {
+              id: ID! timestamp: Timestamp!
+            }"#,
+            "EE221",
+        );
+        //Unknown interval
+        expect_error(
+            &format!(
+                r#"{source} type Stats @aggregation(intervals: ["week"], source: "Swap") {{
+                  id: ID! timestamp: Timestamp! volume: BigInt! @aggregate(fn: "sum", arg: "amount")
+                }}"#
+            ),
+            "EE222",
+        );
+        //Source is not a timeseries
+        expect_error(
+            r#"type Swap @entity { id: ID! timestamp: Timestamp! amount: BigInt! }
+            type Stats @aggregation(intervals: ["hour"], source: "Swap") {
+              id: ID! timestamp: Timestamp! volume: BigInt! @aggregate(fn: "sum", arg: "amount")
+            }"#,
+            "EE222",
+        );
+        //Dimension doesn't exist on the source
+        expect_error(
+            &format!(
+                r#"{source} type Stats @aggregation(intervals: ["hour"], source: "Swap") {{
+                  id: ID! timestamp: Timestamp! token: String!
+                  volume: BigInt! @aggregate(fn: "sum", arg: "amount")
+                }}"#
+            ),
+            "EE222",
+        );
+        //Aggregate arg doesn't exist on the source
+        expect_error(
+            &format!(
+                r#"{source} type Stats @aggregation(intervals: ["hour"], source: "Swap") {{
+                  id: ID! timestamp: Timestamp! volume: BigInt! @aggregate(fn: "sum", arg: "price")
+                }}"#
+            ),
+            "EE223",
+        );
+        //Sum of a non numeric field
+        expect_error(
+            &format!(
+                r#"{source} type Stats @aggregation(intervals: ["hour"], source: "Swap") {{
+                  id: ID! timestamp: Timestamp! volume: String! @aggregate(fn: "sum", arg: "pool")
+                }}"#
+            ),
+            "EE223",
+        );
+        //Sum which could overflow the type of the aggregate field
+        expect_error(
+            &format!(
+                r#"{source} type Stats @aggregation(intervals: ["hour"], source: "Swap") {{
+                  id: ID! timestamp: Timestamp! volume: Int! @aggregate(fn: "sum", arg: "amount")
+                }}"#
+            ),
+            "EE223",
+        );
+        //Count with an arg
+        expect_error(
+            &format!(
+                r#"{source} type Stats @aggregation(intervals: ["hour"], source: "Swap") {{
+                  id: ID! timestamp: Timestamp! swaps: Int! @aggregate(fn: "count", arg: "amount")
+                }}"#
+            ),
+            "EE223",
+        );
+        //Aggregate outside of an aggregation
+        expect_error(
+            r#"type Stats { id: ID! volume: BigInt! @aggregate(fn: "sum", arg: "amount") }"#,
+            "EE223",
+        );
+    }
+
+    #[test]
+    fn full_text_search_is_parsed() {
+        let schema_str = r#"
+type Token
+  @fulltext(name: "tokenSearch", fields: ["name", "symbol"], language: "english")
+  @fulltext(name: "tokenNameSearch", fields: ["name"]) {
+  id: ID!
+  name: String!
+  symbol: String
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+
+        assert_eq!(
+            schema.entities.get("Token").unwrap().full_text_searches,
+            vec![
+                FullTextSearch {
+                    name: "tokenSearch".to_string(),
+                    fields: vec!["name".to_string(), "symbol".to_string()],
+                    language: "english".to_string(),
+                },
+                FullTextSearch {
+                    name: "tokenNameSearch".to_string(),
+                    fields: vec!["name".to_string()],
+                    language: "simple".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn full_text_search_errors() {
+        let expect_error = |schema_str: &str| {
+            let gql_doc = setup_document(schema_str).unwrap();
+            let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+            assert!(err_message.contains("EE224"), "Got {err_message}");
+        };
+
+        //Field doesn't exist
+        expect_error(r#"type Token @fulltext(name: "search", fields: ["name"]) { id: ID! }"#);
+        //Field isn't a string
+        expect_error(
+            r#"type Token @fulltext(name: "search", fields: ["decimals"]) { id: ID! decimals: Int! }"#,
+        );
+        //Unsupported language
+        expect_error(
+            r#"type Token @fulltext(name: "search", fields: ["name"], language: "klingon") {
+              id: ID! name: String!
+            }"#,
+        );
+        //Duplicate name across entities
+        expect_error(
+            r#"type Token @fulltext(name: "search", fields: ["name"]) { id: ID! name: String! }
+            type Pool @fulltext(name: "search", fields: ["name"]) { id: ID! name: String! }"#,
+        );
+    }
+
+    #[test]
+    fn descriptions_and_deprecations_are_parsed() {
+        let schema_str = r#"
+"A token with an on-chain balance"
+type Token {
+  id: ID!
+  "Symbol as returned by the contract"
+  symbol: String!
+  legacyName: String @deprecated
+  decimals: Int! @deprecated(reason: "Use the ERC20 metadata")
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+        let token = schema.entities.get("Token").unwrap();
+
+        assert_eq!(
+            token.description,
+            Some("A token with an on-chain balance".to_string())
+        );
+
+        let symbol = token.fields.get("symbol").unwrap();
+        assert_eq!(
+            symbol.description,
+            Some("Symbol as returned by the contract".to_string())
+        );
+        assert_eq!(symbol.deprecation_reason, None);
+        assert_eq!(
+            symbol.get_documentation(),
+            Some("Symbol as returned by the contract".to_string())
+        );
+
+        let legacy_name = token.fields.get("legacyName").unwrap();
+        assert_eq!(
+            legacy_name.get_documentation(),
+            Some("Deprecated: No longer supported".to_string())
+        );
+
+        let decimals = token.fields.get("decimals").unwrap();
+        assert_eq!(
+            decimals.deprecation_reason,
+            Some("Use the ERC20 metadata".to_string())
+        );
+        assert_eq!(token.fields.get("id").unwrap().get_documentation(), None);
+    }
+
+    #[test]
+    fn test_error_case_deprecated_with_unknown_argument() {
+        let schema_str = r#"
+type Token {
+  id: ID!
+  decimals: Int! @deprecated(since: "v2")
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+        assert!(err_message.contains("EE225"));
+    }
+
+    #[test]
+    fn renamed_from_is_parsed() {
+        let schema_str = r#"
+type Holder @renamedFrom(name: "Account") {
+  id: ID!
+  tokenBalance: BigInt! @renamedFrom(name: "balance")
+  token: Token! @renamedFrom(name: "asset")
+}
+type Token {
+  id: ID!
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+        let holder = schema.entities.get("Holder").unwrap();
+
+        assert_eq!(holder.renamed_from, Some("Account".to_string()));
+        assert_eq!(
+            holder.fields.get("tokenBalance").unwrap().renamed_from,
+            Some("balance".to_string())
+        );
+        assert_eq!(holder.fields.get("id").unwrap().renamed_from, None);
+        assert_eq!(schema.entities.get("Token").unwrap().renamed_from, None);
+
+        let token_pg_field = holder
+            .fields
+            .get("token")
+            .unwrap()
+            .get_postgres_field(&schema, holder)
+            .unwrap()
+            .unwrap();
+        assert_eq!(token_pg_field.renamed_from, Some("asset".to_string()));
+    }
+
+    #[test]
+    fn renamed_from_errors() {
+        let expect_error = |schema_str: &str| {
+            let gql_doc = setup_document(schema_str).unwrap();
+            let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+            assert!(err_message.contains("EE226"), "Got {err_message}");
+        };
+
+        //Missing name argument
+        expect_error(r#"type Token @renamedFrom { id: ID! }"#);
+        //Renamed from its current name
+        expect_error(r#"type Token { id: ID! name: String! @renamedFrom(name: "name") }"#);
+        //Renamed from an existing field
+        expect_error(
+            r#"type Token { id: ID! name: String! symbol: String! @renamedFrom(name: "name") }"#,
+        );
+        //Renamed from an existing entity
+        expect_error(
+            r#"type Token @renamedFrom(name: "Pool") { id: ID! }
+            type Pool { id: ID! }"#,
+        );
+        //Two entities renamed from the same name
+        expect_error(
+            r#"type Token @renamedFrom(name: "Asset") { id: ID! }
+            type Pool @renamedFrom(name: "Asset") { id: ID! }"#,
+        );
+        //Derived fields aren't stored in the db
+        expect_error(
+            r#"type Token { id: ID! holders: [Holder!]! @derivedFrom(field: "token") @renamedFrom(name: "owners") }
+            type Holder { id: ID! token: Token! }"#,
+        );
+    }
+
+    #[test]
+    fn reverting_renames_matches_the_previous_schema() {
+        let previous_schema_str = r#"
+type Account @index(fields: ["balance", "asset"]) {
+  id: ID!
+  balance: BigInt!
+  asset: Token!
+}
+type Token {
+  id: ID!
+  accounts: [Account!]! @derivedFrom(field: "asset")
+}
+        "#;
+        let schema_str = r#"
+type Holder @renamedFrom(name: "Account") @index(fields: ["tokenBalance", "token"]) {
+  id: ID!
+  tokenBalance: BigInt! @renamedFrom(name: "balance")
+  token: Token! @renamedFrom(name: "asset")
+}
+type Token {
+  id: ID!
+  accounts: [Holder!]! @derivedFrom(field: "token")
+}
+        "#;
+        let previous_doc = setup_document(previous_schema_str).unwrap();
+        let doc = setup_document(schema_str).unwrap();
+
+        let reverted_doc = revert_renames(&doc).unwrap();
+        assert_eq!(
+            strip_renamed_from_directives(reverted_doc).to_string(),
+            previous_doc.to_string()
+        );
+        assert_eq!(revert_renames(&previous_doc), None);
+    }
+
+    #[test]
+    fn enum_values_change() {
+        let enm = GraphQLEnum::new(
+            "Status".to_string(),
+            vec![
+                "ACTIVE".to_string(),
+                "PAUSED".to_string(),
+                "CLOSED".to_string(),
+            ],
+        )
+        .unwrap();
+        let db_values = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            enm.get_values_change(&db_values(&["ACTIVE", "PAUSED", "CLOSED"])),
+            EnumValuesChange::Unchanged
+        );
+        assert_eq!(
+            enm.get_values_change(&db_values(&["ACTIVE"])),
+            EnumValuesChange::Appended(vec!["PAUSED".to_string(), "CLOSED".to_string()])
+        );
+        assert_eq!(
+            enm.get_values_change(&db_values(&["ACTIVE", "DELETED"])),
+            EnumValuesChange::Removed(vec!["DELETED".to_string()])
+        );
+        assert_eq!(
+            enm.get_values_change(&db_values(&["PAUSED", "ACTIVE"])),
+            EnumValuesChange::Reordered
+        );
+        assert!(!EnumValuesChange::Appended(vec![]).is_destructive());
+        assert!(EnumValuesChange::Reordered.is_destructive());
+    }
+
+    #[test]
+    fn retaining_enum_values_matches_the_previous_schema() {
+        let previous_schema_str = r#"
+enum Status {
+  ACTIVE
+}
+type Pool {
+  id: ID!
+  status: Status!
+}
+        "#;
+        let schema_str = r#"
+enum Status {
+  ACTIVE
+  PAUSED
+}
+type Pool {
+  id: ID!
+  status: Status!
+}
+        "#;
+        let previous_doc = setup_document(previous_schema_str).unwrap();
+        let doc = setup_document(schema_str).unwrap();
+
+        let enum_values = [("Status".to_string(), vec!["ACTIVE".to_string()])]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            retain_enum_values(doc, &enum_values).to_string(),
+            previous_doc.to_string()
+        );
+    }
+
+    #[test]
+    fn custom_scalars_are_parsed() {
+        let schema_str = r#"
+scalar BigInt
+scalar Hash @scalar(type: "Bytes", pattern: "^0x[0-9a-fA-F]{64}$")
+scalar Percent @scalar(type: "BigDecimal")
+type Transfer {
+  id: ID!
+  txHash: Hash!
+  fee: Percent
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+
+        assert_eq!(schema.scalars.len(), 2);
+        let hash = schema.scalars.get("Hash").unwrap();
+        assert_eq!(hash.base, GqlScalar::Bytes(BytesStorage::Text));
+        assert_eq!(hash.pattern, Some("^0x[0-9a-fA-F]{64}$".to_string()));
+
+        let transfer = schema.entities.get("Transfer").unwrap();
+        let tx_hash_field = transfer.fields.get("txHash").unwrap();
+        let tx_hash_pg_field = tx_hash_field
+            .get_postgres_field(&schema, transfer)
+            .unwrap()
+            .unwrap();
+        assert_eq!(tx_hash_pg_field.field_type, PGPrimitive::Text);
+        assert_eq!(tx_hash_pg_field.linked_entity, None);
+        assert_eq!(
+            tx_hash_field
+                .field_type
+                .to_rescript_type(&schema)
+                .unwrap()
+                .to_string(),
+            "Scalars.Hash.t"
+        );
+
+        let fee_pg_field = transfer
+            .fields
+            .get("fee")
+            .unwrap()
+            .get_postgres_field(&schema, transfer)
+            .unwrap()
+            .unwrap();
+        assert_eq!(fee_pg_field.field_type, PGPrimitive::Numeric(None));
+    }
+
+    #[test]
+    fn custom_scalar_errors() {
+        let expect_error = |schema_str: &str| {
+            let gql_doc = setup_document(schema_str).unwrap();
+            let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+            assert!(err_message.contains("EE228"), "Got {err_message}");
+        };
+
+        //Missing @scalar directive
+        expect_error(r#"scalar Hash"#);
+        //Missing type argument
+        expect_error(r#"scalar Hash @scalar(pattern: "^0x")"#);
+        //Base type isn't a built in scalar
+        expect_error(r#"scalar Hash @scalar(type: "Address")"#);
+        //Redefining a built in scalar
+        expect_error(r#"scalar BigInt @scalar(type: "String")"#);
+        //Pattern on a non string scalar
+        expect_error(r#"scalar Percent @scalar(type: "BigDecimal", pattern: "^1")"#);
+        //Invalid pattern
+        expect_error(r#"scalar Hash @scalar(type: "String", pattern: "(")"#);
+        //Pattern syntax JavaScript reads differently
+        expect_error(r#"scalar Hash @scalar(type: "String", pattern: "(?i)^0x")"#);
+        //Same name as an entity
+        expect_error(
+            r#"scalar Token @scalar(type: "String")
+            type Token { id: ID! }"#,
+        );
+    }
+
+    #[test]
+    fn portable_scalar_patterns() {
+        for pattern in [
+            "^0x[0-9a-fA-F]{64}$",
+            r"^(?:[a-z]+\.)*[a-z]+$",
+            r"^\d{1,3}(?:,\d{3})*\b",
+            r"^[^\]\-]+\x41$",
+        ] {
+            assert_eq!(check_portable_pattern(pattern), Ok(()), "{pattern}");
+        }
+        for pattern in [
+            "(?i)^0x",
+            "(?P<hex>[0-9a-f]+)",
+            r"\A0x",
+            r"^\p{L}+$",
+            r"\x{41}",
+            r"\<word\>",
+            "[a-z&&[^x]]",
+            "[]a]",
+            "a{,3}",
+        ] {
+            assert!(check_portable_pattern(pattern).is_err(), "{pattern}");
+        }
+    }
+
+    #[test]
+    fn derived_from_field_errors() {
+        let get_error = |schema_str: &str| {
+            let gql_doc = setup_document(schema_str).unwrap();
+            let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+            assert!(err_message.contains("EE229"), "Got {err_message}");
+            err_message
+        };
+
+        //Misspelled field, suggesting the closest one
+        let err_message = get_error(
+            r#"type Token { id: ID! holders: [Holder!]! @derivedFrom(field: "tokn") }
+            type Holder { id: ID! token: Token! owner: String! }"#,
+        );
+        assert!(
+            err_message.contains("Did you mean 'token'?"),
+            "Got {err_message}"
+        );
+
+        //Unrelated field name, suggesting the only field referencing the parent
+        let err_message = get_error(
+            r#"type Token { id: ID! holders: [Holder!]! @derivedFrom(field: "owner") }
+            type Holder { id: ID! asset: Token! amount: BigInt! }"#,
+        );
+        assert!(
+            err_message.contains("Did you mean 'asset'?"),
+            "Got {err_message}"
+        );
+
+        //Field referencing a different entity
+        get_error(
+            r#"type Token { id: ID! holders: [Holder!]! @derivedFrom(field: "pool") }
+            type Holder { id: ID! pool: Pool! }
+            type Pool { id: ID! }"#,
+        );
+
+        //Field that isn't a reference
+        get_error(
+            r#"type Token { id: ID! holders: [Holder!]! @derivedFrom(field: "amount") }
+            type Holder { id: ID! amount: BigInt! }"#,
+        );
+
+        //Derived from another derived field
+        get_error(
+            r#"type Token { id: ID! holders: [Holder!]! @derivedFrom(field: "tokens") }
+            type Holder { id: ID! tokens: [Token!]! @derivedFrom(field: "holders") }"#,
+        );
+
+        //Lists of ids are still allowed
+        let gql_doc = setup_document(
+            r#"type Token { id: ID! holders: [Holder!]! @derivedFrom(field: "tokens") }
+            type Holder { id: ID! tokens: [String!]! }"#,
+        )
+        .unwrap();
+        assert!(Schema::from_document(gql_doc).is_ok());
+    }
+
+    #[test]
+    fn reserved_words_are_allowed_as_names() {
+        let schema_str = r#"
+enum Kind {
+  open
+  catch
+}
+type Transfer {
+  id: ID!
+  from: String!
+  to: String!
+  type: Kind!
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+        assert!(schema
+            .entities
+            .get("Transfer")
+            .unwrap()
+            .fields
+            .contains_key("type"));
+    }
+
+    #[test]
+    fn escaped_field_names_collision_errors() {
+        let schema_str = r#"
+type Transfer {
+  id: ID!
+  type: String!
+  type_: String!
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+        assert!(err_message.contains("EE230"), "Got {err_message}");
+    }
+
+    #[test]
+    fn multifield_index_should_retain_original_order() {
+        let schema_str = r#"
+        type Entity 
+        @index(fields: ["a", "b"])
+        @index(fields: ["b", "a"])
+        {
+            id: ID!
+            a: String!
+            b: String!
+        }
+        "#;
+
+        let gql_doc = setup_document(schema_str).expect("Failed to parse schema string");
+        let schema = Schema::from_document(gql_doc).expect("Failed to parse schema from doc");
+        let entity = schema.entities.get("Entity").expect("Entity not found");
+
+        assert_eq!(entity.multi_field_indexes.len(), 2);
+        assert_eq!(
+            entity.multi_field_indexes[0].0,
             vec!["a".to_string(), "b".to_string()]
         );
         assert_eq!(
@@ -2354,4 +5107,317 @@ type TestEntity {
             vec!["b".to_string(), "a".to_string()]
         );
     }
+
+    #[test]
+    fn index_options_are_parsed() {
+        let schema_str = r#"
+        type Entity
+        @index(fields: ["blockNumber"], method: "brin")
+        @index(fields: ["a", "b"], order: "desc", where: "b IS NOT NULL")
+        @index(fields: ["a", "blockNumber"])
+        @index(fields: ["tags"], method: "gin")
+        {
+            id: ID!
+            a: String!
+            b: String
+            blockNumber: Int!
+            tags: [String!]!
+        }
+        "#;
+
+        let gql_doc = setup_document(schema_str).expect("Failed to parse schema string");
+        let schema = Schema::from_document(gql_doc).expect("Failed to parse schema from doc");
+        let entity = schema.entities.get("Entity").expect("Entity not found");
+
+        assert_eq!(
+            entity.multi_field_indexes[0].1,
+            IndexOptions {
+                method: Some(IndexMethod::Brin),
+                order: None,
+                where_predicate: None,
+            }
+        );
+        assert_eq!(
+            entity.multi_field_indexes[1].1,
+            IndexOptions {
+                method: None,
+                order: Some(IndexOrder::Desc),
+                where_predicate: Some("b IS NOT NULL".to_string()),
+            }
+        );
+        assert_eq!(entity.multi_field_indexes[2].1, IndexOptions::default());
+        assert_eq!(
+            entity.multi_field_indexes[3].1,
+            IndexOptions {
+                method: Some(IndexMethod::Gin),
+                order: None,
+                where_predicate: None,
+            }
+        );
+
+        //Single field index with options is created as a composite index
+        //instead of a default index on the column
+        assert_eq!(entity.get_composite_indices().len(), 4);
+        let block_number_field = entity.fields.get("blockNumber").unwrap();
+        assert!(block_number_field.is_indexed_field(entity));
+        assert!(
+            !block_number_field
+                .get_postgres_field(&schema, entity)
+                .unwrap()
+                .unwrap()
+                .is_index
+        );
+    }
+
+    #[test]
+    fn index_options_errors() {
+        let invalid_indexes = vec![
+            r#"@index(fields: ["a", "b"], method: "hash")"#,
+            r#"@index(fields: ["a"], method: "brin", order: "desc")"#,
+            r#"@index(fields: ["a"], method: "gist")"#,
+            r#"@index(fields: ["a"], order: "up")"#,
+            r#"@index(fields: ["a"], where: "")"#,
+            r#"@index(fields: ["a"], unique: true)"#,
+            r#"@index(fields: ["a"], method: "gin")"#,
+            r#"@index(fields: ["list", "a"], method: "gin")"#,
+        ];
+
+        for invalid_index in invalid_indexes {
+            let schema_str = format!(
+                r#"
+                type Entity {invalid_index} {{
+                    id: ID!
+                    a: String!
+                    b: String!
+                    list: [String!]!
+                }}
+                "#
+            );
+
+            let gql_doc = setup_document(&schema_str).expect("Failed to parse schema string");
+            let result = Schema::from_document(gql_doc);
+
+            assert!(result.is_err(), "Expected error for {invalid_index}");
+            let err_message = format!("{:?}", result.unwrap_err());
+            assert!(
+                err_message.contains("EE219"),
+                "Unexpected error {err_message}"
+            );
+        }
+    }
+
+    fn get_test_project_paths() -> ParsedProjectPaths {
+        let test_dir = format!("{}/test", env!("CARGO_MANIFEST_DIR"));
+        ParsedProjectPaths::new(&test_dir, "generated/", "configs/config1.yaml")
+            .expect("Failed creating parsed_paths")
+    }
+
+    #[test]
+    fn schema_split_across_files_is_merged() {
+        let schema = Schema::parse_from_file(
+            &get_test_project_paths(),
+            &Some(SingleOrList::Single(
+                "../schemas/split/*.graphql".to_string(),
+            )),
+        )
+        .expect("Failed parsing split schema");
+
+        let mut entity_names = schema.entities.keys().cloned().collect::<Vec<_>>();
+        entity_names.sort();
+        assert_eq!(entity_names, vec!["Account", "Token"]);
+        assert!(schema.enums.contains_key("TokenStatus"));
+    }
+
+    #[test]
+    fn schema_type_defined_in_multiple_files_names_both_files() {
+        let err = Schema::parse_from_file(
+            &get_test_project_paths(),
+            &Some(SingleOrList::List(vec![
+                "../schemas/split/*.graphql".to_string(),
+                "../schemas/duplicate-account.graphql".to_string(),
+            ])),
+        )
+        .expect_err("Expected duplicate type error");
+
+        let err_message = format!("{:?}", err);
+        assert!(err_message.contains("EE220"));
+        assert!(err_message.contains("split/accounts.graphql"));
+        assert!(err_message.contains("duplicate-account.graphql"));
+    }
+
+    #[test]
+    fn schema_glob_without_matches_fails() {
+        let err = Schema::parse_from_file(
+            &get_test_project_paths(),
+            &Some(SingleOrList::Single(
+                "../schemas/missing/*.graphql".to_string(),
+            )),
+        )
+        .expect_err("Expected missing schema files error");
+
+        assert!(format!("{:?}", err).contains("EE200"));
+    }
+
+    #[test]
+    fn int8_and_bytes_ids_are_supported() {
+        let schema_str = r#"
+type Transfer {
+  id: Int8!
+  token: Token!
+}
+type Token {
+  id: Bytes!
+  transfers: [Transfer!]! @derivedFrom(field: "token")
+}
+type Approval {
+  id: ID!
+  transfer: Transfer!
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+
+        let transfer = schema.entities.get("Transfer").unwrap();
+        assert_eq!(transfer.get_id_scalar(), GqlScalar::Int8);
+        assert_eq!(
+            transfer.get_id_rescript_type(&schema).unwrap().to_string(),
+            "bigint"
+        );
+
+        let approval = schema.entities.get("Approval").unwrap();
+        assert_eq!(approval.get_id_scalar(), GqlScalar::ID);
+        assert_eq!(
+            approval.get_id_rescript_type(&schema).unwrap().to_string(),
+            "string"
+        );
+
+        //Relationship columns take the type of the referenced id
+        let transfer_pg_field = approval
+            .fields
+            .get("transfer")
+            .unwrap()
+            .get_postgres_field(&schema, approval)
+            .unwrap()
+            .unwrap();
+        assert_eq!(transfer_pg_field.field_type, PGPrimitive::Int8);
+
+        let token_pg_field = transfer
+            .fields
+            .get("token")
+            .unwrap()
+            .get_postgres_field(&schema, transfer)
+            .unwrap()
+            .unwrap();
+        assert_eq!(token_pg_field.field_type, PGPrimitive::Text);
+    }
+
+    #[test]
+    fn invalid_id_types_error() {
+        let expect_error = |schema_str: &str| {
+            let gql_doc = setup_document(schema_str).unwrap();
+            let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+            assert!(err_message.contains("EE227"), "Got {err_message}");
+        };
+
+        expect_error(r#"type Token { id: ID }"#);
+        expect_error(r#"type Token { id: Int! }"#);
+        expect_error(r#"type Token { id: [ID!]! }"#);
+        expect_error(r#"type Token { id: Bytes! @config(storage: "bytea") }"#);
+        expect_error(
+            r#"type Swap @entity(timeseries: true) { id: ID! timestamp: Timestamp! amount: BigInt! }
+            type SwapStats @aggregation(intervals: ["hour"], source: "Swap") {
+              id: Int8!
+              timestamp: Timestamp!
+              volume: BigInt! @aggregate(fn: "sum", arg: "amount")
+            }"#,
+        );
+    }
+
+    #[test]
+    fn composite_key_entity() {
+        let schema_str = r#"
+type Balance @entity(compositeKey: ["account", "tokenId"]) {
+  account: String!
+  tokenId: BigInt!
+  amount: BigInt!
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+
+        let balance = schema.entities.get("Balance").unwrap();
+        assert_eq!(
+            balance.composite_key,
+            Some(vec!["account".to_string(), "tokenId".to_string()])
+        );
+        assert_eq!(
+            balance.get_id_rescript_type(&schema).unwrap().to_string(),
+            "(string, bigint)"
+        );
+
+        let primary_key_field_names = balance
+            .get_fields()
+            .into_iter()
+            .filter_map(|field| field.get_postgres_field(&schema, balance).unwrap())
+            .filter(|pg_field| pg_field.is_primary_key)
+            .map(|pg_field| pg_field.field_name)
+            .collect::<Vec<_>>();
+        assert_eq!(primary_key_field_names, vec!["account", "tokenId"]);
+    }
+
+    #[test]
+    fn invalid_composite_key_errors() {
+        let expect_error = |schema_str: &str, code: &str| {
+            let gql_doc = setup_document(schema_str).unwrap();
+            let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+            assert!(err_message.contains(code), "Got {err_message}");
+        };
+
+        expect_error(
+            r#"type Balance @entity(compositeKey: "account") { account: String! }"#,
+            "EE218",
+        );
+        expect_error(
+            r#"type Balance @entity(compositeKey: ["account"]) { account: String! }"#,
+            "EE231",
+        );
+        expect_error(
+            r#"type Balance @entity(compositeKey: ["account", "account"]) { account: String! }"#,
+            "EE231",
+        );
+        expect_error(
+            r#"type Balance @entity(compositeKey: ["account", "token"]) { account: String! }"#,
+            "EE231",
+        );
+        expect_error(
+            r#"type Balance @entity(compositeKey: ["account", "token"]) {
+              account: String!
+              token: String
+            }"#,
+            "EE231",
+        );
+        expect_error(
+            r#"type Balance @entity(compositeKey: ["account", "token"]) {
+              id: ID!
+              account: String!
+              token: String!
+            }"#,
+            "EE231",
+        );
+        expect_error(
+            r#"type Balance @entity(compositeKey: ["account", "token"]) {
+              account: String!
+              token: String!
+            }
+            type Transfer { id: ID! balance: Balance! }"#,
+            "EE231",
+        );
+        expect_error(
+            r#"type Swap @entity(timeseries: true, compositeKey: ["pool", "timestamp"]) {
+              pool: String!
+              timestamp: Timestamp!
+            }"#,
+            "EE231",
+        );
+    }
 }
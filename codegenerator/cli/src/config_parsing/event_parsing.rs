@@ -1,4 +1,6 @@
-use ethers::abi::{EventParam as EthAbiEventParam, ParamType as EthAbiParamType};
+use ethers::abi::{
+    EventParam as EthAbiEventParam, Param as EthAbiParam, ParamType as EthAbiParamType,
+};
 
 use crate::rescript_types::RescriptTypeIdent;
 
@@ -16,6 +18,15 @@ impl<'a> From<&'a EthAbiEventParam> for EthereumEventParam<'a> {
     }
 }
 
+impl<'a> From<&'a EthAbiParam> for EthereumEventParam<'a> {
+    fn from(abi_type: &'a EthAbiParam) -> EthereumEventParam<'a> {
+        EthereumEventParam {
+            name: &abi_type.name,
+            abi_type: &abi_type.kind,
+        }
+    }
+}
+
 impl EthereumEventParam<'_> {
     /// Returns the depth of the nested type
     /// A value type would return 0
@@ -36,6 +47,26 @@ impl EthereumEventParam<'_> {
         rec(self.abi_type, 0)
     }
 
+    /// Returns the number of bytes the param takes in the abi encoded log data
+    /// None for dynamic types, since their size depends on the value
+    pub fn get_static_abi_size(&self) -> Option<usize> {
+        fn rec(param: &EthAbiParamType) -> Option<usize> {
+            match param {
+                EthAbiParamType::Address
+                | EthAbiParamType::Int(_)
+                | EthAbiParamType::Uint(_)
+                | EthAbiParamType::Bool
+                | EthAbiParamType::FixedBytes(_) => Some(32),
+                EthAbiParamType::FixedArray(p, size) => rec(p).map(|p_size| p_size * size),
+                EthAbiParamType::Tuple(params) => params.iter().map(rec).sum(),
+                EthAbiParamType::Bytes | EthAbiParamType::String | EthAbiParamType::Array(_) => {
+                    None
+                }
+            }
+        }
+        rec(self.abi_type)
+    }
+
     pub fn get_topic_encoder(&self) -> String {
         struct IsValueEncoder(bool);
         struct IsNestedType(bool);
@@ -242,4 +273,21 @@ mod tests {
             "[]".to_string()
         );
     }
+
+    #[test]
+    fn test_static_abi_size() {
+        let event = HumanReadableParser::parse_event(
+            "event MyEvent(address user, uint256[2] amounts, (bool, bytes32) myTuple, (bool, \
+             string) myDynamicTuple, bytes[2] myArr)",
+        )
+        .expect("parsing event");
+
+        let sizes: Vec<_> = event
+            .inputs
+            .iter()
+            .map(|p| EthereumEventParam::from(p).get_static_abi_size())
+            .collect();
+
+        assert_eq!(sizes, vec![Some(32), Some(64), Some(64), None, None]);
+    }
 }
@@ -3,11 +3,14 @@ use crate::{
     config_parsing::{
         chain_helpers::{self, GraphNetwork},
         human_config::{
-            evm::{ContractConfig, EventConfig, HumanConfig, Network},
+            evm::{
+                BlockHandlerConfig, CallConfig, ContractConfig, EventConfig, HumanConfig, Network,
+            },
             NetworkContract,
         },
     },
     constants::project_paths::DEFAULT_SCHEMA_PATH,
+    utils::text::Capitalize,
 };
 use anyhow::{anyhow, Context};
 use regex::Regex;
@@ -109,6 +112,53 @@ pub struct CallHandler {
 #[derive(Debug, Deserialize, Clone)]
 pub struct BlockHandler {
     pub handler: String,
+    pub filter: Option<BlockHandlerFilter>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum BlockHandlerFilter {
+    Call,
+    Once,
+    Polling { every: u64 },
+}
+
+impl BlockHandler {
+    // Subgraph block handlers run from the start block of their data source
+    fn to_block_handler_config(
+        &self,
+        data_source: &DataSource,
+        language: &Language,
+    ) -> anyhow::Result<BlockHandlerConfig> {
+        let start_block = data_source
+            .source
+            .start_block
+            .parse::<u64>()
+            .context("Failed to parse the data source start block")?;
+        let name = format!("{}{}", data_source.name, self.handler.capitalize());
+
+        let (interval, end_block) = match self.filter {
+            None => (None, None),
+            Some(BlockHandlerFilter::Polling { every }) => (Some(every), None),
+            Some(BlockHandlerFilter::Once) => (None, Some(start_block)),
+            Some(BlockHandlerFilter::Call) => {
+                println!(
+                    "The call filter of the block handler {} is not supported. It will be called \
+                     for every block instead.",
+                    self.handler
+                );
+                (None, None)
+            }
+        };
+
+        Ok(BlockHandlerConfig {
+            name,
+            handler: get_event_handler_directory(language),
+            interval,
+            start_block: Some(start_block),
+            end_block,
+        })
+    }
 }
 
 // Logic to get the event handler directory based on the language
@@ -313,6 +363,8 @@ pub async fn generate_config_from_subgraph_id(
             end_block: None,
             confirmed_block_threshold: None,
             contracts: vec![],
+            block_handlers: None,
+            transactions: None,
         };
         // Iterate through contracts to get contract name, abi file path, address and event names
         for contract in contracts {
@@ -348,25 +400,57 @@ pub async fn generate_config_from_subgraph_id(
                                 event: event_name.to_string(),
                                 name: None,
                                 field_selection: None,
+                                filters: None,
+                                wildcard: None,
+                                start_block: None,
+                                end_block: None,
                             };
 
                             Ok(event)
                         })
                         .collect::<anyhow::Result<Vec<_>>>()?;
 
+                    let calls = data_source
+                        .mapping
+                        .call_handlers
+                        .as_ref()
+                        .map(|call_handlers| {
+                            call_handlers
+                                .iter()
+                                .map(|call_handler| CallConfig {
+                                    call: call_handler.function.clone(),
+                                    name: None,
+                                })
+                                .collect()
+                        });
+
                     let contract = NetworkContract {
                         name: data_source.name.to_string(),
                         address: vec![data_source.source.address.to_string()].into(),
+                        factory: None,
+                        start_block: None,
+                        end_block: None,
                         config: Some(ContractConfig {
-                            abi_file_path: Some(format!("abis/{}.json", data_source.name)),
+                            abi_file_path: Some(format!("abis/{}.json", data_source.name).into()),
                             handler: get_event_handler_directory(language),
                             events,
+                            calls,
+                            wildcard: None,
                         }),
                     };
 
                     // Pushing contract to network
                     network.contracts.push(contract.clone());
 
+                    for block_handler in data_source.mapping.block_handlers.iter().flatten() {
+                        let block_handler_config =
+                            block_handler.to_block_handler_config(&data_source, language)?;
+                        network
+                            .block_handlers
+                            .get_or_insert_with(Vec::new)
+                            .push(block_handler_config);
+                    }
+
                     //Create the dir for all abis to be dropped in
                     let abi_dir_path = project_root_path.join("abis");
                     fs::create_dir_all(&abi_dir_path).context("Failed to create abis dir")?;
@@ -445,6 +529,7 @@ mod test {
         config_parsing::{
             chain_helpers::{GraphNetwork, Network},
             graph_migration::get_ipfs_id_from_file_path,
+            human_config::evm::BlockHandlerConfig,
         },
     };
     use std::{collections::HashMap, path::PathBuf};
@@ -570,6 +655,78 @@ mod test {
         assert_eq!(network_contracts, network_contracts_expected);
     }
 
+    #[test]
+    fn test_block_handler_to_block_handler_config() {
+        let data_source: super::DataSource = serde_yaml::from_str(
+            r#"
+kind: ethereum/contract
+name: Pool
+network: mainnet
+source:
+  address: "0x0000000000000000000000000000000000000000"
+  abi: Pool
+  startBlock: "100"
+mapping:
+  kind: ethereum/events
+  apiVersion: 0.0.7
+  language: wasm/assemblyscript
+  entities: []
+  abis: []
+  eventHandlers: []
+  blockHandlers:
+    - handler: handleBlock
+    - handler: handleEveryTenBlocks
+      filter:
+        kind: polling
+        every: 10
+    - handler: handleOnce
+      filter:
+        kind: once
+  file:
+    /: /ipfs/QmZ81YMckH8LxaLd9MnaGugvbvC9Mto3Ye3Vz4ydWE7npt
+"#,
+        )
+        .unwrap();
+
+        let block_handler_configs = data_source
+            .mapping
+            .block_handlers
+            .iter()
+            .flatten()
+            .map(|block_handler| {
+                block_handler.to_block_handler_config(&data_source, &Language::TypeScript)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            block_handler_configs,
+            vec![
+                BlockHandlerConfig {
+                    name: "PoolHandleBlock".to_string(),
+                    handler: "src/EventHandlers.ts".to_string(),
+                    interval: None,
+                    start_block: Some(100),
+                    end_block: None,
+                },
+                BlockHandlerConfig {
+                    name: "PoolHandleEveryTenBlocks".to_string(),
+                    handler: "src/EventHandlers.ts".to_string(),
+                    interval: Some(10),
+                    start_block: Some(100),
+                    end_block: None,
+                },
+                BlockHandlerConfig {
+                    name: "PoolHandleOnce".to_string(),
+                    handler: "src/EventHandlers.ts".to_string(),
+                    interval: None,
+                    start_block: Some(100),
+                    end_block: Some(100),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_valid_ipfs_cid() {
         let subgraph_id_1 = "QmdAmQxQCuGoeqNLuE8m6zH366pY2LkustTRYDhSt85X7w";
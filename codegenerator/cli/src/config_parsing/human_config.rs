@@ -82,12 +82,46 @@ pub struct NetworkContract<T> {
                        dynamically."
     )]
     pub address: Addresses,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Registers the addresses of this contract from an event of a factory \
+                       contract, so no contractRegister is needed in the handler code"
+    )]
+    pub factory: Option<FactoryConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "The block at which the indexer should start ingesting the events of the \
+                       contract. Should be within the block range of the network"
+    )]
+    pub start_block: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "The block after which the events of the contract are no longer indexed. \
+                       Should be within the block range of the network"
+    )]
+    pub end_block: Option<u64>,
     #[serde(flatten)]
     //If this is "None" it should be expected that
     //there is a global config for the contract
     pub config: Option<T>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FactoryConfig {
+    #[schemars(
+        description = "The name of the factory contract. It should be defined on the same network"
+    )]
+    pub contract: String,
+    #[schemars(description = "The name of the factory contract event which creates the contract")]
+    pub event: String,
+    #[schemars(
+        description = "The name of the event param with the address of the created contract. \
+                       Should be an address or an address[] param"
+    )]
+    pub address_param: String,
+}
+
 #[derive(Deserialize)]
 pub struct ConfigDiscriminant {
     pub ecosystem: Option<String>,
@@ -117,7 +151,7 @@ pub mod evm {
     use crate::utils::normalized_list::SingleOrList;
     use schemars::JsonSchema;
     use serde::{Deserialize, Serialize};
-    use std::fmt::Display;
+    use std::{collections::BTreeMap, fmt::Display};
     use strum::Display;
     use subenum::subenum;
 
@@ -137,8 +171,12 @@ pub mod evm {
         #[schemars(description = "Ecosystem of the project.")]
         pub ecosystem: Option<EcosystemTag>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        #[schemars(description = "Custom path to schema.yaml file")]
-        pub schema: Option<String>,
+        #[schemars(
+            description = "Custom path to schema.graphql file. Can also be a list of paths or glob \
+                           patterns (eg. \"schemas/*.graphql\") to split the schema across \
+                           multiple files"
+        )]
+        pub schema: Option<SingleOrList<String>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         #[schemars(
             description = "Global contract definitions that must contain all definitions except \
@@ -413,6 +451,94 @@ pub mod evm {
         pub end_block: Option<u64>,
         #[schemars(description = "All the contracts that should be indexed on the given network")]
         pub contracts: Vec<NetworkContract<ContractConfig>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "A list of handlers that should be called for blocks on the given \
+                           network, independently of any events"
+        )]
+        pub block_handlers: Option<Vec<BlockHandlerConfig>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "A list of handlers that should be called for transactions on the given \
+                           network matching the filters, including transactions without logs. \
+                           Requires a HyperSync data source"
+        )]
+        pub transactions: Option<Vec<TransactionHandlerConfig>>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+    #[serde(deny_unknown_fields)]
+    pub struct BlockHandlerConfig {
+        #[schemars(
+            description = "Name of the block handler in the HyperIndex generated code. Should be \
+                           unique per network"
+        )]
+        pub name: String,
+        #[schemars(
+            description = "The relative path to a file where the block handler is registered"
+        )]
+        pub handler: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Call the handler every N blocks, counting from its start block \
+                           (default: 1)"
+        )]
+        pub interval: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "The block at which the handler should start being called (default: \
+                           the network start_block)"
+        )]
+        pub start_block: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "The block after which the handler should stop being called (default: \
+                           the network end_block)"
+        )]
+        pub end_block: Option<u64>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+    #[serde(deny_unknown_fields)]
+    pub struct TransactionHandlerConfig {
+        #[schemars(
+            description = "Name of the transaction handler in the HyperIndex generated code. \
+                           Should be unique per network"
+        )]
+        pub name: String,
+        #[schemars(
+            description = "The relative path to a file where the transaction handler is registered"
+        )]
+        pub handler: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(description = "Only include transactions sent from one of these addresses")]
+        pub from: Option<SingleOrList<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(description = "Only include transactions sent to one of these addresses")]
+        pub to: Option<SingleOrList<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Only include transactions calling one of these functions. Can be a \
+                           4-byte selector (eg. 0xa9059cbb) or a function signature (eg. \
+                           transfer(address,uint256))"
+        )]
+        pub function_selector: Option<SingleOrList<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(description = "Only include transactions with the given execution status")]
+        pub status: Option<TransactionStatus>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "The transaction fields passed to the handler (default: hash, from, to, \
+                           value and input)"
+        )]
+        pub transaction_fields: Option<Vec<TransactionField>>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
+    #[serde(rename_all = "lowercase", deny_unknown_fields)]
+    pub enum TransactionStatus {
+        Success,
+        Reverted,
     }
 
     #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
@@ -421,9 +547,13 @@ pub mod evm {
         #[serde(skip_serializing_if = "Option::is_none")]
         #[schemars(
             description = "Relative path (from config) to a json abi. If this is used then each \
-                           configured event should simply be referenced by its name"
+                           configured event should simply be referenced by its name. The path \
+                           can also point to a Foundry or Hardhat artifact, or to a Foundry out \
+                           or Hardhat artifacts directory where the artifact of the contract is \
+                           found by its name. A list of paths or glob patterns merges the abis \
+                           of all the files"
         )]
-        pub abi_file_path: Option<String>,
+        pub abi_file_path: Option<SingleOrList<String>>,
         #[schemars(
             description = "The relative path to a file where handlers are registered for the \
                            given contract"
@@ -431,6 +561,37 @@ pub mod evm {
         pub handler: String,
         #[schemars(description = "A list of events that should be indexed on this contract")]
         pub events: Vec<EventConfig>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "A list of contract function calls that should be indexed on this \
+                           contract. Requires HyperSync or an RPC supporting trace_filter. Only \
+                           successful direct calls are indexed, delegate and static calls are \
+                           skipped"
+        )]
+        pub calls: Option<Vec<CallConfig>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Index the events of the contract from any address. Wildcard contracts \
+                           should be defined without addresses"
+        )]
+        pub wildcard: Option<bool>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+    #[serde(deny_unknown_fields)]
+    pub struct CallConfig {
+        #[schemars(description = "The human readable signature of a function 'eg. \
+                                  setOwner(address newOwner)' OR a reference to the name of a \
+                                  function in a json ABI file defined in your contract config. A \
+                                  provided signature will take precedence over what is defined in \
+                                  the json ABI")]
+        pub call: String,
+        #[schemars(
+            description = "Name of the call in the HyperIndex generated code. When ommitted, the \
+                           function name will be used. Should be unique per contract"
+        )]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
     }
 
     #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
@@ -439,12 +600,17 @@ pub mod evm {
         #[schemars(description = "The human readable signature of an event 'eg. \
                                   Transfer(address indexed from, address indexed to, uint256 \
                                   value)' OR a reference to the name of an event in a json ABI \
-                                  file defined in your contract config. A provided signature \
-                                  will take precedence over what is defined in the json ABI")]
+                                  file defined in your contract config. Overloaded events in \
+                                  the json ABI are referenced by the name with param types 'eg. \
+                                  Transfer(address,address,uint256)'. A provided signature will \
+                                  take precedence over what is defined in the json ABI")]
         pub event: String,
         #[schemars(
             description = "Name of the event in the HyperIndex generated code. When ommitted, the \
-                           event field will be used. Should be unique per contract"
+                           event field will be used, or the name with param types 'eg. \
+                           Transfer_address_address_uint256' for overloads of an event \
+                           configured together. Should be unique per contract. Required for \
+                           anonymous events"
         )]
         #[serde(skip_serializing_if = "Option::is_none")]
         pub name: Option<String>,
@@ -454,6 +620,34 @@ pub mod evm {
                            event"
         )]
         pub field_selection: Option<FieldSelection>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Filters on the indexed params of the event applied by the data source. \
+                           Each filter maps param names to a value or a list of values, where any \
+                           of them can match. All the params of a filter should match, and the \
+                           event is indexed when any of the filters matches. Values of string and \
+                           bytes params are compared by their keccak256 hash"
+        )]
+        pub filters: Option<SingleOrList<BTreeMap<String, SingleOrList<String>>>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Index the event from any address, not only the addresses of the \
+                           contract. When ommitted, the wildcard option of the contract will be \
+                           used"
+        )]
+        pub wildcard: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "The block at which the indexer should start ingesting the event. When \
+                           ommitted, the start block of the contract will be used"
+        )]
+        pub start_block: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "The block after which the event is no longer indexed. When ommitted, \
+                           the end block of the contract will be used"
+        )]
+        pub end_block: Option<u64>,
     }
 }
 
@@ -461,6 +655,7 @@ pub mod fuel {
     use std::fmt::Display;
 
     use super::{GlobalContract, NetworkContract, NetworkId};
+    use crate::utils::normalized_list::SingleOrList;
     use schemars::JsonSchema;
     use serde::{Deserialize, Serialize};
     use strum::Display;
@@ -480,8 +675,12 @@ pub mod fuel {
         #[schemars(description = "Ecosystem of the project.")]
         pub ecosystem: EcosystemTag,
         #[serde(skip_serializing_if = "Option::is_none")]
-        #[schemars(description = "Custom path to schema.yaml file")]
-        pub schema: Option<String>,
+        #[schemars(
+            description = "Custom path to schema.graphql file. Can also be a list of paths or glob \
+                           patterns (eg. \"schemas/*.graphql\") to split the schema across \
+                           multiple files"
+        )]
+        pub schema: Option<SingleOrList<String>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         #[schemars(
             description = "Global contract definitions that must contain all definitions except \
@@ -598,7 +797,7 @@ pub mod fuel {
 mod tests {
     use super::{
         evm::{ContractConfig, EventDecoder, HumanConfig, Network},
-        NetworkContract,
+        FactoryConfig, NetworkContract,
     };
     use crate::{config_parsing::human_config::fuel, utils::normalized_list::NormalizedList};
     use pretty_assertions::assert_eq;
@@ -651,10 +850,15 @@ events: []
             address: NormalizedList::from(vec![
                 "0x2E645469f354BB4F5c8a05B3b30A929361cf77eC".to_string()
             ]),
+            factory: None,
+            start_block: None,
+            end_block: None,
             config: Some(ContractConfig {
                 abi_file_path: None,
                 handler: "./src/EventHandler.js".to_string(),
                 events: vec![],
+                calls: None,
+                wildcard: None,
             }),
         };
 
@@ -673,10 +877,15 @@ events: []
         let expected = NetworkContract {
             name: "Contract1".to_string(),
             address: vec![].into(),
+            factory: None,
+            start_block: None,
+            end_block: None,
             config: Some(ContractConfig {
                 abi_file_path: None,
                 handler: "./src/EventHandler.js".to_string(),
                 events: vec![],
+                calls: None,
+                wildcard: None,
             }),
         };
 
@@ -696,10 +905,15 @@ events: []
         let expected = NetworkContract {
             name: "Contract1".to_string(),
             address: vec!["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC".to_string()].into(),
+            factory: None,
+            start_block: None,
+            end_block: None,
             config: Some(ContractConfig {
                 abi_file_path: None,
                 handler: "./src/EventHandler.js".to_string(),
                 events: vec![],
+                calls: None,
+                wildcard: None,
             }),
         };
 
@@ -719,6 +933,9 @@ address: ["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC"]
             address: NormalizedList::from(vec![
                 "0x2E645469f354BB4F5c8a05B3b30A929361cf77eC".to_string()
             ]),
+            factory: None,
+            start_block: None,
+            end_block: None,
             config: None,
         };
 
@@ -760,6 +977,14 @@ address: ["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC"]
         assert!(cfg.networks[0].contracts[0].config.is_some());
         assert!(cfg.networks[0].contracts[1].config.is_some());
         assert_eq!(cfg.networks[0].contracts[1].address, None.into());
+        assert_eq!(
+            cfg.networks[0].contracts[1].factory,
+            Some(FactoryConfig {
+                contract: "Factory".to_string(),
+                event: "PoolCreated".to_string(),
+                address_param: "pool".to_string(),
+            })
+        );
     }
 
     #[test]
@@ -801,6 +1026,9 @@ address: ["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC"]
                     address: "0x4a2ce054e3e94155f7092f7365b212f7f45105b74819c623744ebcc5d065c6ac"
                         .to_string()
                         .into(),
+                    factory: None,
+                    start_block: None,
+                    end_block: None,
                     config: Some(fuel::ContractConfig {
                         abi_file_path: "./abis/spark-orderbook.json".to_string(),
                         handler: "./src/OrderBookHandlers.ts".to_string(),
@@ -888,7 +1116,9 @@ address: ["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC"]
                 start_block: 2_000,
                 confirmed_block_threshold: None,
                 end_block: Some(2_000_000),
-                contracts: vec![]
+                contracts: vec![],
+                block_handlers: None,
+                transactions: None,
             },
             de
         );
@@ -6,5 +6,6 @@ pub mod graph_migration;
 pub mod human_config;
 pub mod hypersync_endpoints;
 pub mod postgres_types;
+pub mod schema_graph;
 pub mod system_config;
 pub mod validation;
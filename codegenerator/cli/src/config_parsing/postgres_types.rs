@@ -7,11 +7,13 @@ pub enum Primitive {
     Boolean,
     Text,
     Integer,
+    Int8,
     Numeric(Option<(u32, u32)>), // (precision, scale)
     DoublePrecision,
     Serial,
     Json,
     Timestamp,
+    Bytea,
     Enum(String),
 }
 
@@ -21,6 +23,7 @@ impl Primitive {
             Self::Boolean => "Boolean".to_string(),
             Self::Text => "Text".to_string(),
             Self::Integer => "Integer".to_string(),
+            Self::Int8 => "Int8".to_string(),
             Self::Numeric(None) => "Numeric".to_string(),
             Self::Numeric(Some((precision, scale))) => {
                 format!("Custom(\"NUMERIC({}, {})\")", precision, scale)
@@ -28,6 +31,7 @@ impl Primitive {
             Self::Serial => "Serial".to_string(),
             Self::Json => "Json".to_string(),
             Self::Timestamp => "Timestamp".to_string(),
+            Self::Bytea => "Bytea".to_string(),
             Self::DoublePrecision => "DoublePrecision".to_string(),
             Self::Enum(enum_name) => format!("Custom(Enums.{enum_name}.enum.name)"),
         }
@@ -58,4 +62,20 @@ pub struct Field {
     pub is_nullable: bool,
     pub is_array: bool,
     pub field_type: Primitive,
+    ///Column comment, serialized as a Rescript string literal
+    #[serde(serialize_with = "serialize_string_literal")]
+    pub description: Option<String>,
+    ///Previous name of the field, set with the @renamedFrom directive
+    pub renamed_from: Option<String>,
+}
+
+fn serialize_string_literal<S>(value: &Option<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    value
+        .as_ref()
+        .map(|value| serde_json::to_string(value).map_err(serde::ser::Error::custom))
+        .transpose()?
+        .serialize(serializer)
 }
@@ -0,0 +1,514 @@
+use super::{
+    entity_parsing::{Entity, Field, FieldType, GqlScalar, Schema, UserDefinedFieldType},
+    system_config::SystemConfig,
+};
+use crate::rescript_types::RescriptRecordField;
+use anyhow::Context;
+use itertools::Itertools;
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+///An entity written to by a contract event handler
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventEntityWrite {
+    pub contract_name: String,
+    pub event_name: String,
+    pub entity_name: String,
+}
+
+impl EventEntityWrite {
+    fn get_event_label(&self) -> String {
+        format!("{}.{}", self.contract_name, self.event_name)
+    }
+}
+
+///Reads the handler files of all contracts in the config and finds the entities
+///each registered event handler writes to with context.<Entity>.set or
+///context.<Entity>.deleteUnsafe
+pub fn read_event_entity_writes(config: &SystemConfig) -> anyhow::Result<Vec<EventEntityWrite>> {
+    let events: Vec<(String, String)> = config
+        .get_contracts()
+        .into_iter()
+        .flat_map(|contract| {
+            contract
+                .events
+                .iter()
+                .map(|event| (contract.name.clone(), event.name.clone()))
+        })
+        .collect();
+
+    let mut handler_paths = HashSet::new();
+    let mut writes = BTreeSet::new();
+    for contract in config.get_contracts() {
+        let handler_path = contract.get_path_to_handler(&config.parsed_project_paths)?;
+        if !handler_paths.insert(handler_path.clone()) {
+            continue;
+        }
+        let handler_source = std::fs::read_to_string(&handler_path).context(format!(
+            "Failed reading handler file at {}",
+            handler_path.display()
+        ))?;
+        writes.extend(find_event_entity_writes(
+            &handler_source,
+            &events,
+            &config.schema,
+        ));
+    }
+
+    Ok(writes.into_iter().collect())
+}
+
+///Splits the handler source at every handler registration of a known
+///(contract name, event name) pair and collects the entities written to
+///between one registration and the next.
+pub fn find_event_entity_writes(
+    handler_source: &str,
+    events: &[(String, String)],
+    schema: &Schema,
+) -> Vec<EventEntityWrite> {
+    let registration_regex = Regex::new(
+        r"([A-Za-z_][A-Za-z0-9_]*)\.([A-Za-z_][A-Za-z0-9_]*)\.(handler|handlerWithLoader|contractRegister)\b",
+    )
+    .expect("Invalid handler registration regex");
+    let write_regex = Regex::new(r"context\.([A-Za-z_][A-Za-z0-9_]*)\.(set|deleteUnsafe)\b")
+        .expect("Invalid entity write regex");
+    //JavaScript handlers access the entities by their name, while Rescript
+    //handlers use the uncapitalized name with reserved words escaped
+    let entity_names_by_context_key: HashMap<String, &String> = schema
+        .entities
+        .keys()
+        .flat_map(|entity_name| {
+            [
+                (entity_name.clone(), entity_name),
+                (
+                    RescriptRecordField::to_valid_res_name(entity_name),
+                    entity_name,
+                ),
+            ]
+        })
+        .collect();
+
+    let registrations: Vec<_> = registration_regex
+        .captures_iter(handler_source)
+        .filter(|captures| {
+            events.iter().any(|(contract_name, event_name)| {
+                contract_name == &captures[1] && event_name == &captures[2]
+            })
+        })
+        .collect();
+
+    let mut writes = BTreeSet::new();
+    for (i, registration) in registrations.iter().enumerate() {
+        if &registration[3] == "contractRegister" {
+            continue;
+        }
+        let start = registration.get(0).expect("Match always has group 0").end();
+        let end = registrations
+            .get(i + 1)
+            .map_or(handler_source.len(), |next| {
+                next.get(0).expect("Match always has group 0").start()
+            });
+
+        for write in write_regex.captures_iter(&handler_source[start..end]) {
+            if let Some(entity_name) = entity_names_by_context_key.get(&write[1]) {
+                writes.insert(EventEntityWrite {
+                    contract_name: registration[1].to_string(),
+                    event_name: registration[2].to_string(),
+                    entity_name: entity_name.to_string(),
+                });
+            }
+        }
+    }
+
+    writes.into_iter().collect()
+}
+
+///Edge between two entities, from a field on one entity to the entity it references
+struct EntityEdge<'a> {
+    from: &'a Entity,
+    field: &'a Field,
+    to: &'a Entity,
+}
+
+impl EntityEdge<'_> {
+    fn is_derived(&self) -> bool {
+        self.field.field_type.is_derived_from()
+    }
+}
+
+///Renders the entities of a [`Schema`] along with their relationships as a diagram
+pub struct SchemaGraph<'a> {
+    schema: &'a Schema,
+    event_writes: Vec<EventEntityWrite>,
+}
+
+impl<'a> SchemaGraph<'a> {
+    pub fn new(schema: &'a Schema, event_writes: Vec<EventEntityWrite>) -> Self {
+        Self {
+            schema,
+            event_writes,
+        }
+    }
+
+    fn get_entities(&self) -> Vec<&'a Entity> {
+        self.schema
+            .entities
+            .values()
+            .sorted_by_key(|entity| &entity.name)
+            .collect()
+    }
+
+    fn get_edges(&self) -> anyhow::Result<Vec<EntityEdge<'a>>> {
+        let mut edges = vec![];
+        for from in self.get_entities() {
+            for (field, to) in from.get_related_entities(self.schema)? {
+                edges.push(EntityEdge { from, field, to });
+            }
+        }
+        Ok(edges)
+    }
+
+    ///Keys of a field in the diagram, PK for the primary key fields and FK for entity references
+    fn get_field_keys(&self, entity: &Entity, field: &Field) -> anyhow::Result<Vec<&'static str>> {
+        let mut keys = vec![];
+        if field.is_primary_key(entity) {
+            keys.push("PK");
+        }
+        if !field.field_type.is_derived_from() && field.field_type.is_entity_field(self.schema)? {
+            keys.push("FK");
+        }
+        Ok(keys)
+    }
+
+    ///Notes for indexes and derived fields
+    fn get_field_notes(entity: &Entity, field: &Field) -> Vec<String> {
+        let mut notes = get_field_index_notes(entity, field);
+        if let FieldType::DerivedFromField {
+            derived_from_field, ..
+        } = &field.field_type
+        {
+            notes.push(format!("derived from {derived_from_field}"));
+        }
+        notes
+    }
+
+    ///Renders the graph as a Mermaid entity relationship diagram
+    pub fn to_mermaid(&self) -> anyhow::Result<String> {
+        let mut lines = vec!["erDiagram".to_string()];
+
+        for entity in self.get_entities() {
+            lines.push(format!("    {} {{", entity.name));
+            for field in entity.get_fields() {
+                let mut line = format!(
+                    "        {} {}",
+                    get_mermaid_type_name(&field.field_type),
+                    field.name
+                );
+                let keys = self.get_field_keys(entity, field)?;
+                if !keys.is_empty() {
+                    line.push_str(&format!(" {}", keys.join(", ")));
+                }
+                let notes = Self::get_field_notes(entity, field);
+                if !notes.is_empty() {
+                    line.push_str(&format!(" \"{}\"", notes.join(", ")));
+                }
+                lines.push(line);
+            }
+            lines.push("    }".to_string());
+        }
+
+        for edge in self.get_edges()? {
+            let cardinality = if edge.is_derived() {
+                "||..o{"
+            } else if edge.field.field_type.is_optional() {
+                "}o--o|"
+            } else {
+                "}o--||"
+            };
+            lines.push(format!(
+                "    {} {cardinality} {} : \"{}\"",
+                edge.from.name, edge.to.name, edge.field.name
+            ));
+        }
+
+        for write in &self.event_writes {
+            lines.push(format!(
+                "    {}_{} }}o..o{{ {} : \"writes\"",
+                write.contract_name, write.event_name, write.entity_name
+            ));
+        }
+
+        Ok(lines.join("\n"))
+    }
+
+    ///Renders the graph as a Graphviz dot digraph
+    pub fn to_dot(&self) -> anyhow::Result<String> {
+        let mut lines = vec![
+            "digraph Schema {".to_string(),
+            "    rankdir=LR;".to_string(),
+            "    node [shape=record];".to_string(),
+        ];
+
+        for entity in self.get_entities() {
+            let mut rows = vec![escape_dot_record(&entity.name)];
+            for field in entity.get_fields() {
+                let mut annotations: Vec<String> = self
+                    .get_field_keys(entity, field)?
+                    .into_iter()
+                    .map(|key| key.to_string())
+                    .collect();
+                annotations.extend(Self::get_field_notes(entity, field));
+                let mut row = format!("{}: {}", field.name, get_field_type_name(&field.field_type));
+                if !annotations.is_empty() {
+                    row.push_str(&format!(" ({})", annotations.join(", ")));
+                }
+                rows.push(format!("{}\\l", escape_dot_record(&row)));
+            }
+            lines.push(format!(
+                "    \"{}\" [label=\"{{{}}}\"];",
+                entity.name,
+                rows.join("|")
+            ));
+        }
+
+        for edge in self.get_edges()? {
+            let style = if edge.is_derived() {
+                ", style=dashed"
+            } else {
+                ""
+            };
+            lines.push(format!(
+                "    \"{}\" -> \"{}\" [label=\"{}\"{style}];",
+                edge.from.name, edge.to.name, edge.field.name
+            ));
+        }
+
+        let event_labels: BTreeSet<String> = self
+            .event_writes
+            .iter()
+            .map(EventEntityWrite::get_event_label)
+            .collect();
+        for event_label in event_labels {
+            lines.push(format!("    \"{event_label}\" [shape=box, style=rounded];"));
+        }
+        for write in &self.event_writes {
+            lines.push(format!(
+                "    \"{}\" -> \"{}\" [label=\"writes\", style=dotted];",
+                write.get_event_label(),
+                write.entity_name
+            ));
+        }
+
+        lines.push("}".to_string());
+        Ok(lines.join("\n"))
+    }
+}
+
+///Notes for the single field and composite indexes a field is part of
+pub fn get_field_index_notes(entity: &Entity, field: &Field) -> Vec<String> {
+    let mut notes = vec![];
+    if field.is_indexed_field(entity) {
+        notes.push("indexed".to_string());
+    }
+    for composite_index in entity.get_composite_indices() {
+        let field_names = composite_index.get_field_names();
+        if field_names.len() > 1 && field_names.contains(&field.name) {
+            notes.push(format!("index({})", field_names.join(", ")));
+        }
+    }
+    notes
+}
+
+fn get_scalar_name(gql_scalar: &GqlScalar) -> String {
+    match gql_scalar {
+        GqlScalar::Custom(name) => name.clone(),
+        gql_scalar => gql_scalar.to_string(),
+    }
+}
+
+fn get_user_defined_type_name(field_type: &UserDefinedFieldType) -> String {
+    match field_type {
+        UserDefinedFieldType::Single(gql_scalar) => get_scalar_name(gql_scalar),
+        UserDefinedFieldType::ListType(field_type) => {
+            format!("[{}]", get_user_defined_type_name(field_type))
+        }
+        UserDefinedFieldType::NonNullType(field_type) => {
+            format!("{}!", get_user_defined_type_name(field_type))
+        }
+    }
+}
+
+///The field type as it is written in the schema
+pub fn get_field_type_name(field_type: &FieldType) -> String {
+    match field_type {
+        FieldType::DerivedFromField { entity_name, .. } => format!("[{entity_name}!]!"),
+        FieldType::RegularField { field_type, .. } => get_user_defined_type_name(field_type),
+    }
+}
+
+///Mermaid attribute types only allow word characters, so lists are
+///written as Type[] and nullability is dropped
+fn get_mermaid_type_name(field_type: &FieldType) -> String {
+    fn rec(field_type: &UserDefinedFieldType) -> String {
+        match field_type {
+            UserDefinedFieldType::Single(gql_scalar) => get_scalar_name(gql_scalar),
+            UserDefinedFieldType::ListType(field_type) => format!("{}[]", rec(field_type)),
+            UserDefinedFieldType::NonNullType(field_type) => rec(field_type),
+        }
+    }
+    match field_type {
+        FieldType::DerivedFromField { entity_name, .. } => format!("{entity_name}[]"),
+        FieldType::RegularField { field_type, .. } => rec(field_type),
+    }
+}
+
+fn escape_dot_record(label: &str) -> String {
+    label
+        .chars()
+        .map(|c| match c {
+            '"' | '{' | '}' | '|' | '<' | '>' | '\\' => format!("\\{c}"),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_event_entity_writes, EventEntityWrite, SchemaGraph};
+    use crate::config_parsing::entity_parsing::Schema;
+    use graphql_parser::schema::parse_schema;
+    use pretty_assertions::assert_eq;
+
+    fn setup_schema() -> Schema {
+        let schema_str = r#"
+            type User {
+              id: ID!
+              name: String! @index
+              tokens: [Token!]! @derivedFrom(field: "owner")
+            }
+
+            type Token @index(fields: ["owner", "tokenId"]) {
+              id: ID!
+              tokenId: BigInt!
+              owner: User!
+              approved: User
+            }
+        "#;
+        let doc = parse_schema::<String>(schema_str).unwrap();
+        Schema::from_document(doc).unwrap()
+    }
+
+    fn event_write(contract_name: &str, event_name: &str, entity_name: &str) -> EventEntityWrite {
+        EventEntityWrite {
+            contract_name: contract_name.to_string(),
+            event_name: event_name.to_string(),
+            entity_name: entity_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn renders_mermaid() {
+        let schema = setup_schema();
+        let graph = SchemaGraph::new(&schema, vec![event_write("Nft", "Transfer", "Token")]);
+
+        let expected = r#"erDiagram
+    Token {
+        User approved FK
+        ID id PK
+        User owner FK "index(owner, tokenId)"
+        BigInt tokenId "index(owner, tokenId)"
+    }
+    User {
+        ID id PK
+        String name "indexed"
+        Token[] tokens "derived from owner"
+    }
+    Token }o--o| User : "approved"
+    Token }o--|| User : "owner"
+    User ||..o{ Token : "tokens"
+    Nft_Transfer }o..o{ Token : "writes""#;
+
+        assert_eq!(graph.to_mermaid().unwrap(), expected);
+    }
+
+    #[test]
+    fn renders_dot() {
+        let schema = setup_schema();
+        let graph = SchemaGraph::new(&schema, vec![event_write("Nft", "Transfer", "Token")]);
+
+        let expected = r#"digraph Schema {
+    rankdir=LR;
+    node [shape=record];
+    "Token" [label="{Token|approved: User (FK)\l|id: ID! (PK)\l|owner: User! (FK, index(owner, tokenId))\l|tokenId: BigInt! (index(owner, tokenId))\l}"];
+    "User" [label="{User|id: ID! (PK)\l|name: String! (indexed)\l|tokens: [Token!]! (derived from owner)\l}"];
+    "Token" -> "User" [label="approved"];
+    "Token" -> "User" [label="owner"];
+    "User" -> "Token" [label="tokens", style=dashed];
+    "Nft.Transfer" [shape=box, style=rounded];
+    "Nft.Transfer" -> "Token" [label="writes", style=dotted];
+}"#;
+
+        assert_eq!(graph.to_dot().unwrap(), expected);
+    }
+
+    #[test]
+    fn finds_entities_written_by_handlers() {
+        let schema = setup_schema();
+        let events = vec![
+            ("Nft".to_string(), "Transfer".to_string()),
+            ("Nft".to_string(), "Mint".to_string()),
+            ("Factory".to_string(), "NftCreated".to_string()),
+        ];
+        let handler_source = r#"
+            Handlers.Nft.Transfer.handler(async ({event, context}) => {
+              context.Token.set(token)
+              context.Other.set(other)
+            })
+
+            Factory.NftCreated.contractRegister(({event, context}) => {
+              context.addNft(event.params.nft)
+            })
+
+            Nft.Mint.handlerWithLoader({
+              loader: async ({event, context}) => context.User.get(event.params.to),
+              handler: async ({event, context}) => {
+                context.User.set(user)
+                context.Token.deleteUnsafe(event.params.id)
+              },
+            })
+        "#;
+
+        assert_eq!(
+            find_event_entity_writes(handler_source, &events, &schema),
+            vec![
+                event_write("Nft", "Mint", "Token"),
+                event_write("Nft", "Mint", "User"),
+                event_write("Nft", "Transfer", "Token"),
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_entities_written_by_rescript_handlers() {
+        let schema_str = r#"
+            type Token { id: ID! }
+            type Type { id: ID! }
+        "#;
+        let schema = Schema::from_document(parse_schema::<String>(schema_str).unwrap()).unwrap();
+        let events = vec![("Nft".to_string(), "Transfer".to_string())];
+        let handler_source = r#"
+            Handlers.Nft.Transfer.handler(async ({event, context}) => {
+              context.token.set(token)
+              context.type_.deleteUnsafe(event.params.id)
+            })
+        "#;
+
+        assert_eq!(
+            find_event_entity_writes(handler_source, &events, &schema),
+            vec![
+                event_write("Nft", "Transfer", "Token"),
+                event_write("Nft", "Transfer", "Type"),
+            ]
+        );
+    }
+}
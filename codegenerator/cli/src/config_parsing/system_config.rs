@@ -1,11 +1,12 @@
 use super::{
     chain_helpers::get_confirmed_block_threshold_from_id,
-    entity_parsing::{Entity, GraphQLEnum, Schema},
+    entity_parsing::{CustomScalar, Entity, GraphQLEnum, Schema},
+    event_parsing::EthereumEventParam,
     human_config::{
         self,
         evm::{
-            EventConfig as EvmEventConfig, EventDecoder, HumanConfig as EvmConfig,
-            Network as EvmNetwork,
+            CallConfig as EvmCallConfig, EventConfig as EvmEventConfig, EventDecoder,
+            HumanConfig as EvmConfig, Network as EvmNetwork, TransactionStatus,
         },
         fuel::{EventConfig as FuelEventConfig, HumanConfig as FuelConfig},
         HumanConfig,
@@ -15,19 +16,22 @@ use super::{
 };
 use crate::{
     config_parsing::human_config::evm::{RpcBlockField, RpcTransactionField},
-    constants::{links, project_paths::DEFAULT_SCHEMA_PATH},
+    constants::links,
     fuel::abi::{FuelAbi, BURN_EVENT_NAME, CALL_EVENT_NAME, MINT_EVENT_NAME, TRANSFER_EVENT_NAME},
     project_paths::{path_utils, ParsedProjectPaths},
     rescript_types::RescriptTypeIdent,
-    utils::unique_hashmap,
+    utils::{normalized_list::SingleOrList, unique_hashmap},
 };
 use anyhow::{anyhow, Context, Result};
 use dotenvy::{EnvLoader, EnvMap, EnvSequence};
-use ethers::abi::{ethabi::Event as EthAbiEvent, EventExt, EventParam, HumanReadableParser};
+use ethers::abi::{
+    ethabi::{Event as EthAbiEvent, Function as EthAbiFunction},
+    EventExt, EventParam, HumanReadableParser, Param,
+};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fs,
     path::PathBuf,
 };
@@ -373,7 +377,8 @@ DefaultForMissingAndEmpty with empty env and empty default: ""
 #[derive(Debug)]
 pub struct SystemConfig {
     pub name: String,
-    pub schema_path: String,
+    ///Schema paths or glob patterns from the config, None for the default schema path
+    pub schema_paths: Option<SingleOrList<String>>,
     pub parsed_project_paths: ParsedProjectPaths,
     pub networks: NetworkMap,
     pub contracts: ContractMap,
@@ -447,6 +452,13 @@ impl SystemConfig {
         enums
     }
 
+    pub fn get_gql_scalars(&self) -> Vec<&CustomScalar> {
+        let mut scalars: Vec<&CustomScalar> = self.schema.scalars.values().collect();
+        //For consistent templating in alphabetical order
+        scalars.sort_by_key(|s| s.name.clone());
+        scalars
+    }
+
     pub fn get_gql_enum_names_set(&self) -> HashSet<EntityKey> {
         self.schema.enums.keys().cloned().collect()
     }
@@ -457,14 +469,34 @@ impl SystemConfig {
         networks
     }
 
-    pub fn get_path_to_schema(&self) -> Result<PathBuf> {
-        let schema_path = path_utils::get_config_path_relative_to_root(
-            &self.parsed_project_paths,
-            PathBuf::from(&self.schema_path),
-        )
-        .context("Failed creating a relative path to schema")?;
+    ///Block handlers unique by name across all networks, sorted by name
+    pub fn get_block_handlers(&self) -> Vec<&BlockHandler> {
+        let mut block_handlers: Vec<&BlockHandler> = self
+            .get_networks()
+            .into_iter()
+            .flat_map(|network| network.block_handlers.iter())
+            .unique_by(|block_handler| block_handler.name.clone())
+            .collect();
+        //For consistent templating in alphabetical order
+        block_handlers.sort_by_key(|b| b.name.clone());
+        block_handlers
+    }
+
+    ///Transaction handlers unique by name across all networks, sorted by name
+    pub fn get_transaction_handlers(&self) -> Vec<&TransactionHandler> {
+        let mut transaction_handlers: Vec<&TransactionHandler> = self
+            .get_networks()
+            .into_iter()
+            .flat_map(|network| network.transaction_handlers.iter())
+            .unique_by(|transaction_handler| transaction_handler.name.clone())
+            .collect();
+        //For consistent templating in alphabetical order
+        transaction_handlers.sort_by_key(|t| t.name.clone());
+        transaction_handlers
+    }
 
-        Ok(schema_path)
+    pub fn get_paths_to_schema(&self) -> Result<Vec<PathBuf>> {
+        Schema::resolve_schema_paths(&self.parsed_project_paths, &self.schema_paths)
     }
 
     pub fn get_all_paths_to_handlers(&self) -> Result<Vec<PathBuf>> {
@@ -472,6 +504,16 @@ impl SystemConfig {
             .get_contracts()
             .into_iter()
             .map(|c| c.get_path_to_handler(&self.parsed_project_paths))
+            .chain(
+                self.get_block_handlers()
+                    .into_iter()
+                    .map(|b| b.get_path_to_handler(&self.parsed_project_paths)),
+            )
+            .chain(
+                self.get_transaction_handlers()
+                    .into_iter()
+                    .map(|t| t.get_path_to_handler(&self.parsed_project_paths)),
+            )
             .collect::<Result<HashSet<_>>>()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -485,7 +527,7 @@ impl SystemConfig {
         let mut filtered_unique_abi_files = self
             .get_contracts()
             .into_iter()
-            .filter_map(|c| c.abi.get_path())
+            .flat_map(|c| c.abi.get_paths())
             .collect::<HashSet<_>>()
             .into_iter()
             .collect::<Vec<_>>();
@@ -512,21 +554,41 @@ impl SystemConfig {
                 //Add all global contracts
                 if let Some(global_contracts) = &evm_config.contracts {
                     for g_contract in global_contracts {
-                        let (events, evm_abi) = Event::from_evm_events_config(
-                            g_contract.config.events.clone(),
+                        let abi_from_file = EvmAbi::from_file(
                             &g_contract.config.abi_file_path,
+                            &g_contract.name,
                             &project_paths,
+                        )
+                        .context(format!(
+                            "Failed loading abi of global contract {}",
+                            g_contract.name,
+                        ))?;
+
+                        let (events, evm_abi) = Event::from_evm_events_config(
+                            g_contract.config.events.clone(),
+                            &abi_from_file,
                             has_rpc_sync_src,
+                            g_contract.config.wildcard.unwrap_or(false),
                         )
                         .context(format!(
                             "Failed parsing abi types for events in global contract {}",
                             g_contract.name,
                         ))?;
 
+                        let calls = Call::from_evm_calls_config(
+                            g_contract.config.calls.as_ref(),
+                            &abi_from_file,
+                        )
+                        .context(format!(
+                            "Failed parsing abi types for calls in global contract {}",
+                            g_contract.name,
+                        ))?;
+
                         let contract = Contract::new(
                             g_contract.name.clone(),
                             g_contract.config.handler.clone(),
                             events,
+                            calls,
                             Abi::Evm(evm_abi),
                         )
                         .context("Failed parsing globally defined contract")?;
@@ -542,11 +604,21 @@ impl SystemConfig {
                         //Add values for local contract
                         match contract.config {
                             Some(l_contract) => {
-                                let (events, evm_abi) = Event::from_evm_events_config(
-                                    l_contract.events,
+                                let abi_from_file = EvmAbi::from_file(
                                     &l_contract.abi_file_path,
+                                    &contract.name,
                                     &project_paths,
+                                )
+                                .context(format!(
+                                    "Failed loading abi of contract {} on network {}",
+                                    contract.name, network.id,
+                                ))?;
+
+                                let (events, evm_abi) = Event::from_evm_events_config(
+                                    l_contract.events,
+                                    &abi_from_file,
                                     has_rpc_sync_src,
+                                    l_contract.wildcard.unwrap_or(false),
                                 )
                                 .context(format!(
                                     "Failed parsing abi types for events in contract {} on \
@@ -554,10 +626,21 @@ impl SystemConfig {
                                     contract.name, network.id,
                                 ))?;
 
+                                let calls = Call::from_evm_calls_config(
+                                    l_contract.calls.as_ref(),
+                                    &abi_from_file,
+                                )
+                                .context(format!(
+                                    "Failed parsing abi types for calls in contract {} on \
+                                     network {}",
+                                    contract.name, network.id,
+                                ))?;
+
                                 let contract = Contract::new(
                                     contract.name,
                                     l_contract.handler,
                                     events,
+                                    calls,
                                     Abi::Evm(evm_abi),
                                 )
                                 .context(format!(
@@ -603,12 +686,50 @@ impl SystemConfig {
                         .contracts
                         .iter()
                         .cloned()
-                        .map(|c| NetworkContract {
-                            name: c.name,
-                            addresses: c.address.into(),
+                        .map(|c| -> Result<NetworkContract> {
+                            let factory = match &c.factory {
+                                Some(factory_config) => Some(
+                                    Factory::from_evm_config(
+                                        factory_config,
+                                        &c.name,
+                                        network,
+                                        &contracts,
+                                    )
+                                    .context(format!(
+                                        "Failed parsing factory of contract {} on network {}",
+                                        c.name, network.id
+                                    ))?,
+                                ),
+                                None => None,
+                            };
+                            Ok(NetworkContract {
+                                name: c.name,
+                                addresses: c.address.into(),
+                                factory,
+                                start_block: c.start_block,
+                                end_block: c.end_block,
+                            })
                         })
+                        .collect::<Result<_>>()?;
+
+                    let block_handlers = network
+                        .block_handlers
+                        .iter()
+                        .flatten()
+                        .map(|block_handler| BlockHandler::from_evm_config(block_handler, network))
                         .collect();
 
+                    let transaction_handlers = network
+                        .transactions
+                        .iter()
+                        .flatten()
+                        .map(TransactionHandler::from_evm_config)
+                        .collect::<Result<_>>()
+                        .context(format!(
+                            "Failed parsing transaction handlers at network id {}",
+                            network.id
+                        ))?;
+
                     let network = Network {
                         id: network.id,
                         confirmed_block_threshold: network
@@ -618,12 +739,20 @@ impl SystemConfig {
                         end_block: network.end_block,
                         sync_source,
                         contracts,
+                        block_handlers,
+                        transaction_handlers,
                     };
 
                     unique_hashmap::try_insert(&mut networks, network.id.clone(), network)
                         .context("Failed inserting network at networks map")?;
                 }
 
+                BlockHandler::validate_handler_paths(&networks)?;
+                TransactionHandler::validate_definitions(&networks)?;
+                Contract::validate_wildcard_addresses(&networks, &contracts)?;
+                Contract::validate_wildcard_anonymous_events(&contracts)?;
+                Contract::validate_block_ranges(&networks, &contracts)?;
+
                 let field_selection = FieldSelection::try_from_config_field_selection(
                     evm_config.field_selection.clone().unwrap_or(
                         human_config::evm::FieldSelection {
@@ -637,10 +766,7 @@ impl SystemConfig {
                 Ok(SystemConfig {
                     name: evm_config.name.clone(),
                     parsed_project_paths: project_paths.clone(),
-                    schema_path: evm_config
-                        .schema
-                        .clone()
-                        .unwrap_or_else(|| DEFAULT_SCHEMA_PATH.to_string()),
+                    schema_paths: evm_config.schema.clone(),
                     networks,
                     contracts,
                     unordered_multichain_mode: evm_config
@@ -672,6 +798,7 @@ impl SystemConfig {
                             g_contract.name.clone(),
                             g_contract.config.handler.clone(),
                             events,
+                            vec![],
                             Abi::Fuel(fuel_abi),
                         )?;
 
@@ -701,6 +828,7 @@ impl SystemConfig {
                                     contract.name.clone(),
                                     l_contract.handler,
                                     events,
+                                    vec![],
                                     Abi::Fuel(fuel_abi),
                                 )?;
 
@@ -752,11 +880,22 @@ impl SystemConfig {
                         .contracts
                         .iter()
                         .cloned()
-                        .map(|c| NetworkContract {
-                            name: c.name,
-                            addresses: c.address.into(),
+                        .map(|c| match c.factory {
+                            Some(_) => Err(anyhow!(
+                                "EE129: The contract {} on network {} has a factory, but \
+                                 factory contracts are not supported for Fuel yet.",
+                                c.name,
+                                network.id
+                            )),
+                            None => Ok(NetworkContract {
+                                name: c.name,
+                                addresses: c.address.into(),
+                                factory: None,
+                                start_block: c.start_block,
+                                end_block: c.end_block,
+                            }),
                         })
-                        .collect();
+                        .collect::<Result<_>>()?;
 
                     let network = Network {
                         id: network.id as u64,
@@ -765,19 +904,20 @@ impl SystemConfig {
                         confirmed_block_threshold: 0,
                         sync_source,
                         contracts,
+                        block_handlers: vec![],
+                        transaction_handlers: vec![],
                     };
 
                     unique_hashmap::try_insert(&mut networks, network.id.clone(), network)
                         .context("Failed inserting network at networks map")?;
                 }
 
+                Contract::validate_block_ranges(&networks, &contracts)?;
+
                 Ok(SystemConfig {
                     name: fuel_config.name.clone(),
                     parsed_project_paths: project_paths.clone(),
-                    schema_path: fuel_config
-                        .schema
-                        .clone()
-                        .unwrap_or_else(|| DEFAULT_SCHEMA_PATH.to_string()),
+                    schema_paths: fuel_config.schema.clone(),
                     networks,
                     contracts,
                     unordered_multichain_mode: false,
@@ -1013,12 +1153,200 @@ pub struct Network {
     pub end_block: Option<u64>,
     pub confirmed_block_threshold: i32,
     pub contracts: Vec<NetworkContract>,
+    pub block_handlers: Vec<BlockHandler>,
+    pub transaction_handlers: Vec<TransactionHandler>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockHandler {
+    pub name: String,
+    pub handler_path: String,
+    pub interval: u64,
+    pub start_block: u64,
+    pub end_block: Option<u64>,
+}
+
+impl BlockHandler {
+    pub fn from_evm_config(
+        block_handler: &human_config::evm::BlockHandlerConfig,
+        network: &EvmNetwork,
+    ) -> Self {
+        Self {
+            name: block_handler.name.clone(),
+            handler_path: block_handler.handler.clone(),
+            interval: block_handler.interval.unwrap_or(1),
+            start_block: block_handler.start_block.unwrap_or(network.start_block),
+            end_block: block_handler.end_block.or(network.end_block),
+        }
+    }
+
+    pub fn get_path_to_handler(&self, project_paths: &ParsedProjectPaths) -> Result<PathBuf> {
+        let handler_path = path_utils::get_config_path_relative_to_root(
+            project_paths,
+            PathBuf::from(&self.handler_path),
+        )
+        .context(format!(
+            "Failed creating a relative path to handler in block handler {}",
+            self.name
+        ))?;
+
+        Ok(handler_path)
+    }
+
+    ///A block handler is registered once in the generated code,
+    ///so it should point to the same handler file on every network
+    fn validate_handler_paths(networks: &NetworkMap) -> Result<()> {
+        let mut handler_paths: HashMap<&String, &String> = HashMap::new();
+        let mut networks: Vec<&Network> = networks.values().collect();
+        networks.sort_by_key(|n| n.id);
+        for block_handler in networks.iter().flat_map(|n| n.block_handlers.iter()) {
+            match handler_paths.get(&block_handler.name) {
+                Some(handler_path) if *handler_path != &block_handler.handler_path => {
+                    return Err(anyhow!(
+                        "EE116: The block handler {} is defined with different handler paths \
+                         (\"{}\" and \"{}\"). A block handler with the same name should use the \
+                         same handler file on every network.",
+                        block_handler.name,
+                        handler_path,
+                        block_handler.handler_path
+                    ));
+                }
+                Some(_) => (),
+                None => {
+                    handler_paths.insert(&block_handler.name, &block_handler.handler_path);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionHandler {
+    pub name: String,
+    pub handler_path: String,
+    pub from: Vec<String>,
+    pub to: Vec<String>,
+    pub function_selectors: Vec<String>,
+    pub status: Option<TransactionStatus>,
+    pub transaction_fields: Vec<SelectedField>,
+}
+
+impl TransactionHandler {
+    pub fn from_evm_config(
+        transaction_handler: &human_config::evm::TransactionHandlerConfig,
+    ) -> Result<Self> {
+        use human_config::evm::TransactionField;
+
+        let function_selectors = transaction_handler
+            .function_selector
+            .clone()
+            .map(Vec::<String>::from)
+            .unwrap_or_default()
+            .iter()
+            .map(|function_selector| {
+                if function_selector.contains('(') {
+                    let function = Call::get_abi_function(function_selector, &None)?;
+                    Ok(ethers::core::utils::hex::encode_prefixed(
+                        function.short_signature(),
+                    ))
+                } else {
+                    Ok(function_selector.to_lowercase())
+                }
+            })
+            .collect::<Result<_>>()?;
+
+        let transaction_fields = FieldSelection::try_from_config_field_selection(
+            human_config::evm::FieldSelection {
+                transaction_fields: Some(transaction_handler.transaction_fields.clone().unwrap_or(
+                    vec![
+                        TransactionField::Hash,
+                        TransactionField::From,
+                        TransactionField::To,
+                        TransactionField::Value,
+                        TransactionField::Input,
+                    ],
+                )),
+                block_fields: None,
+            },
+            false,
+        )
+        .context(format!(
+            "Failed parsing transaction fields of transaction handler {}",
+            transaction_handler.name
+        ))?
+        .transaction_fields;
+
+        Ok(Self {
+            name: transaction_handler.name.clone(),
+            handler_path: transaction_handler.handler.clone(),
+            from: transaction_handler
+                .from
+                .clone()
+                .map(Vec::from)
+                .unwrap_or_default(),
+            to: transaction_handler
+                .to
+                .clone()
+                .map(Vec::from)
+                .unwrap_or_default(),
+            function_selectors,
+            status: transaction_handler.status.clone(),
+            transaction_fields,
+        })
+    }
+
+    pub fn get_path_to_handler(&self, project_paths: &ParsedProjectPaths) -> Result<PathBuf> {
+        let handler_path = path_utils::get_config_path_relative_to_root(
+            project_paths,
+            PathBuf::from(&self.handler_path),
+        )
+        .context(format!(
+            "Failed creating a relative path to handler in transaction handler {}",
+            self.name
+        ))?;
+
+        Ok(handler_path)
+    }
+
+    ///A transaction handler is generated once with a single transaction type,
+    ///so it should use the same handler file and fields on every network
+    fn validate_definitions(networks: &NetworkMap) -> Result<()> {
+        let mut definitions: HashMap<&String, &TransactionHandler> = HashMap::new();
+        let mut networks: Vec<&Network> = networks.values().collect();
+        networks.sort_by_key(|n| n.id);
+        for transaction_handler in networks.iter().flat_map(|n| n.transaction_handlers.iter()) {
+            match definitions.get(&transaction_handler.name) {
+                Some(definition)
+                    if definition.handler_path != transaction_handler.handler_path
+                        || definition.transaction_fields
+                            != transaction_handler.transaction_fields =>
+                {
+                    return Err(anyhow!(
+                        "EE123: The transaction handler {} is defined with different handler \
+                         paths or transaction fields on different networks. A transaction \
+                         handler with the same name should use the same handler file and \
+                         transaction fields on every network.",
+                        transaction_handler.name
+                    ));
+                }
+                Some(_) => (),
+                None => {
+                    definitions.insert(&transaction_handler.name, transaction_handler);
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct NetworkContract {
     pub name: ContractNameKey,
     pub addresses: Vec<String>,
+    pub factory: Option<Factory>,
+    pub start_block: Option<u64>,
+    pub end_block: Option<u64>,
 }
 
 impl NetworkContract {
@@ -1032,10 +1360,99 @@ impl NetworkContract {
     }
 }
 
+///The addresses of a contract which are registered from an event of a factory contract
+#[derive(Debug, Clone, PartialEq)]
+pub struct Factory {
+    pub contract_name: ContractNameKey,
+    pub event_name: String,
+    pub address_param: String,
+    ///Every address of the param is registered when it's an address[]
+    pub is_address_array: bool,
+}
+
+impl Factory {
+    fn from_evm_config(
+        factory_config: &human_config::FactoryConfig,
+        contract_name: &str,
+        network: &EvmNetwork,
+        contracts: &ContractMap,
+    ) -> Result<Self> {
+        use ethers::abi::ParamType;
+
+        let is_factory_on_network = network
+            .contracts
+            .iter()
+            .any(|c| c.name == factory_config.contract);
+        let factory_contract = match contracts.get(&factory_config.contract) {
+            Some(factory_contract) if is_factory_on_network => factory_contract,
+            _ => {
+                return Err(anyhow!(
+                    "EE129: The factory contract {} of the contract {} should be defined on the \
+                     network {}.",
+                    factory_config.contract,
+                    contract_name,
+                    network.id
+                ))
+            }
+        };
+
+        let params = factory_contract
+            .events
+            .iter()
+            .find(|event| event.name == factory_config.event)
+            .and_then(|event| match &event.kind {
+                EventKind::Params(params) => Some(params),
+                EventKind::Fuel(_) => None,
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "EE129: The event {} is not defined on the factory contract {}.",
+                    factory_config.event,
+                    factory_config.contract
+                )
+            })?;
+
+        let param = params
+            .iter()
+            .find(|param| param.name == factory_config.address_param)
+            .ok_or_else(|| {
+                anyhow!(
+                    "EE129: The event {} of the factory contract {} doesn't have the param {}. \
+                     Available params: {}",
+                    factory_config.event,
+                    factory_config.contract,
+                    factory_config.address_param,
+                    params.iter().map(|param| param.name.as_str()).join(", ")
+                )
+            })?;
+
+        let is_address_array = match &param.kind {
+            ParamType::Address => false,
+            ParamType::Array(item) if **item == ParamType::Address => true,
+            kind => {
+                return Err(anyhow!(
+                    "EE129: The param {} of the factory event {} has the type {}. Only address \
+                     and address[] params can be used to register contract addresses.",
+                    factory_config.address_param,
+                    factory_config.event,
+                    kind
+                ))
+            }
+        };
+
+        Ok(Self {
+            contract_name: factory_config.contract.clone(),
+            event_name: factory_config.event.clone(),
+            address_param: factory_config.address_param.clone(),
+            is_address_array,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct EvmAbi {
-    // The path is not always present since we allow to get ABI from events
-    pub path: Option<PathBuf>,
+    // The paths are empty when the ABI is only defined by the event signatures
+    pub paths: Vec<PathBuf>,
     pub raw: String,
     typed: ethers::abi::Abi,
 }
@@ -1072,46 +1489,244 @@ impl EvmAbi {
             .collect()
     }
 
-    pub fn from_file(
-        abi_file_path: &Option<String>,
+    ///Returns the full signature of the event with the given topic0 sighash
+    pub fn get_event_signature_by_sighash(&self, sighash: &str) -> Option<String> {
+        self.typed
+            .events()
+            .find(|event| {
+                ethers::core::utils::hex::encode_prefixed(ethers::utils::keccak256(
+                    event.abi_signature().as_bytes(),
+                )) == sighash
+            })
+            .map(Self::event_signature_from_abi_event)
+    }
+
+    ///Resolves the abi_file_path of a contract to the ABI files. Every path can be a json ABI,
+    ///a glob pattern, or a Foundry out/Hardhat artifacts directory with the contract artifact
+    fn resolve_paths(
+        abi_file_path: &str,
+        contract_name: &str,
         project_paths: &ParsedProjectPaths,
-    ) -> Result<Option<Self>> {
-        match &abi_file_path {
-            None => Ok(None),
-            Some(abi_file_path) => {
-                #[derive(Deserialize)]
-                #[serde(untagged)]
-                enum AbiOrNestedAbi {
-                    Abi(ethers::abi::Abi),
-                    NestedAbi { abi: ethers::abi::Abi },
-                }
+    ) -> Result<Vec<PathBuf>> {
+        let path = path_utils::get_config_path_relative_to_root(
+            project_paths,
+            PathBuf::from(abi_file_path),
+        )
+        .context("Failed to get path to ABI relative to the root of the project")?;
 
-                let relative_path_buf = PathBuf::from(abi_file_path);
-                let path =
-                    path_utils::get_config_path_relative_to_root(project_paths, relative_path_buf)
-                        .context("Failed to get path to ABI relative to the root of the project")?;
-                let mut raw = fs::read_to_string(&path)
-                    .context(format!("Failed to read ABI file at \"{}\"", abi_file_path))?;
+        let is_glob_pattern = abi_file_path.contains(['*', '?', '[']);
+        if !is_glob_pattern && !path.is_dir() {
+            return Ok(vec![path]);
+        }
 
-                // Abi files generated by the hardhat plugin can contain a nested abi field. This code to support that.
-                let typed = match serde_json::from_str::<AbiOrNestedAbi>(&raw).context(format!(
-                    "Failed to decode ABI file at \"{}\"",
-                    abi_file_path
-                ))? {
-                    AbiOrNestedAbi::Abi(abi) => abi,
-                    AbiOrNestedAbi::NestedAbi { abi } => {
-                        raw = serde_json::to_string(&abi)
-                            .context("Failed serializing ABI from nested field")?;
-                        abi
-                    }
-                };
-                Ok(Some(Self {
-                    path: Some(path),
-                    raw,
-                    typed,
-                }))
+        let pattern = if is_glob_pattern {
+            path
+        } else {
+            //Foundry stores artifacts at out/<Name>.sol/<Name>.json and Hardhat
+            //at artifacts/<source path>/<Name>.sol/<Name>.json
+            path.join("**")
+                .join(format!("{contract_name}.sol"))
+                .join(format!("{contract_name}.json"))
+        };
+        let pattern = pattern
+            .to_str()
+            .ok_or_else(|| anyhow!("EE127: ABI path {abi_file_path} is not valid unicode"))?;
+        let mut matched_paths = glob::glob(pattern)
+            .context(format!(
+                "EE127: Invalid glob pattern for ABI files {abi_file_path}"
+            ))?
+            .collect::<Result<Vec<_>, _>>()
+            .context(format!(
+                "EE127: Failed to read ABI files matching {abi_file_path}"
+            ))?;
+
+        match (is_glob_pattern, matched_paths.len()) {
+            (true, 0) => Err(anyhow!(
+                "EE127: No ABI files found matching the pattern {abi_file_path}."
+            )),
+            (false, 0) => Err(anyhow!(
+                "EE127: No artifact of the contract {contract_name} found in the directory \
+                 {abi_file_path}. Expected a Foundry or Hardhat artifact at \
+                 <directory>/**/{contract_name}.sol/{contract_name}.json, otherwise set the path \
+                 to the ABI file."
+            )),
+            (false, 1) => Ok(matched_paths),
+            (false, _) => Err(anyhow!(
+                "EE127: Found multiple artifacts of the contract {contract_name} in the \
+                 directory {abi_file_path}: {}. Set the path to one of the artifacts instead.",
+                matched_paths.iter().map(|p| p.display()).join(", ")
+            )),
+            (true, _) => {
+                //Keep the order of the merged ABI deterministic
+                matched_paths.sort();
+                Ok(matched_paths)
+            }
+        }
+    }
+
+    fn read_file(path: &PathBuf) -> Result<(String, ethers::abi::Abi)> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum AbiOrNestedAbi {
+            Abi(ethers::abi::Abi),
+            NestedAbi { abi: ethers::abi::Abi },
+        }
+
+        let raw = fs::read_to_string(path)
+            .context(format!("Failed to read ABI file at \"{}\"", path.display()))?;
+
+        // Foundry and Hardhat artifacts contain the abi in a nested field.
+        match serde_json::from_str::<AbiOrNestedAbi>(&raw).context(format!(
+            "Failed to decode ABI file at \"{}\"",
+            path.display()
+        ))? {
+            AbiOrNestedAbi::Abi(abi) => Ok((raw, abi)),
+            AbiOrNestedAbi::NestedAbi { abi } => {
+                let raw = serde_json::to_string(&abi)
+                    .context("Failed serializing ABI from nested field")?;
+                Ok((raw, abi))
+            }
+        }
+    }
+
+    ///Adds the item to its overloads, unless the same definition is already there.
+    ///Fails when the overload has a different definition in another file
+    fn merge_overload<T: Clone + PartialEq>(
+        overloads: &mut Vec<T>,
+        item: &T,
+        get_signature: impl Fn(&T) -> String,
+        path: &PathBuf,
+        sources: &mut HashMap<String, PathBuf>,
+    ) -> Result<()> {
+        let signature = get_signature(item);
+        match overloads
+            .iter()
+            .find(|overload| get_signature(overload) == signature)
+        {
+            Some(overload) if overload == item => Ok(()),
+            Some(_) => Err(anyhow!(
+                "EE128: The {} is defined differently in the ABI files \"{}\" and \"{}\". Make \
+                 sure the definitions match or remove one of the files from the abi_file_path \
+                 of the contract.",
+                signature,
+                sources
+                    .get(&signature)
+                    .map(|source_path| source_path.display().to_string())
+                    .unwrap_or_default(),
+                path.display()
+            )),
+            None => {
+                sources.insert(signature, path.clone());
+                overloads.push(item.clone());
+                Ok(())
+            }
+        }
+    }
+
+    ///Merges the ABIs of multiple files, so events and functions of all of them can be indexed
+    fn merge(abis: &[(PathBuf, ethers::abi::Abi)]) -> Result<ethers::abi::Abi> {
+        let get_signature = |kind: &str, name: &String, inputs: Vec<&ethers::abi::ParamType>| {
+            format!(
+                "{kind} {name}({})",
+                inputs.iter().map(|kind| kind.to_string()).join(",")
+            )
+        };
+
+        let mut merged = ethers::abi::Abi::default();
+        let mut sources: HashMap<String, PathBuf> = HashMap::new();
+        for (path, abi) in abis {
+            merged.constructor = merged.constructor.or(abi.constructor.clone());
+            merged.receive |= abi.receive;
+            merged.fallback |= abi.fallback;
+            for event in abi.events() {
+                Self::merge_overload(
+                    merged.events.entry(event.name.clone()).or_default(),
+                    event,
+                    |event| {
+                        get_signature(
+                            "event",
+                            &event.name,
+                            event.inputs.iter().map(|i| &i.kind).collect(),
+                        )
+                    },
+                    path,
+                    &mut sources,
+                )?;
+            }
+            for function in abi.functions() {
+                Self::merge_overload(
+                    merged.functions.entry(function.name.clone()).or_default(),
+                    function,
+                    |function| {
+                        get_signature(
+                            "function",
+                            &function.name,
+                            function.inputs.iter().map(|i| &i.kind).collect(),
+                        )
+                    },
+                    path,
+                    &mut sources,
+                )?;
+            }
+            for error in abi.errors() {
+                Self::merge_overload(
+                    merged.errors.entry(error.name.clone()).or_default(),
+                    error,
+                    |error| {
+                        get_signature(
+                            "error",
+                            &error.name,
+                            error.inputs.iter().map(|i| &i.kind).collect(),
+                        )
+                    },
+                    path,
+                    &mut sources,
+                )?;
             }
         }
+        Ok(merged)
+    }
+
+    pub fn from_file(
+        abi_file_path: &Option<SingleOrList<String>>,
+        contract_name: &str,
+        project_paths: &ParsedProjectPaths,
+    ) -> Result<Option<Self>> {
+        let abi_file_paths: Vec<String> = match abi_file_path {
+            None => return Ok(None),
+            Some(abi_file_path) => abi_file_path.clone().into(),
+        };
+
+        let mut paths = vec![];
+        for abi_file_path in abi_file_paths.iter() {
+            paths.extend(Self::resolve_paths(
+                abi_file_path,
+                contract_name,
+                project_paths,
+            )?);
+        }
+        let paths: Vec<PathBuf> = paths.into_iter().unique().collect();
+
+        let mut abis = paths
+            .iter()
+            .map(Self::read_file)
+            .collect::<Result<Vec<_>>>()?;
+
+        let (raw, typed) = if abis.len() == 1 {
+            abis.remove(0)
+        } else {
+            let typed = Self::merge(
+                &paths
+                    .iter()
+                    .cloned()
+                    .zip(abis.into_iter().map(|(_, abi)| abi))
+                    .collect::<Vec<_>>(),
+            )?;
+            let raw = serde_json::to_string(&typed).context("Failed serializing merged ABI")?;
+            (raw, typed)
+        };
+
+        Ok(Some(Self { paths, raw, typed }))
     }
 }
 
@@ -1122,10 +1737,10 @@ pub enum Abi {
 }
 
 impl Abi {
-    fn get_path(&self) -> Option<PathBuf> {
+    fn get_paths(&self) -> Vec<PathBuf> {
         match self {
-            Abi::Evm(abi) => abi.path.clone(),
-            Abi::Fuel(abi) => Some(abi.path_buf.clone()),
+            Abi::Evm(abi) => abi.paths.clone(),
+            Abi::Fuel(abi) => vec![abi.path_buf.clone()],
         }
     }
 }
@@ -1136,48 +1751,190 @@ pub struct Contract {
     pub handler_path: String,
     pub abi: Abi,
     pub events: Vec<Event>,
+    pub calls: Vec<Call>,
 }
 
 impl Contract {
-    pub fn new(name: String, handler_path: String, events: Vec<Event>, abi: Abi) -> Result<Self> {
+    pub fn new(
+        name: String,
+        handler_path: String,
+        events: Vec<Event>,
+        calls: Vec<Call>,
+        abi: Abi,
+    ) -> Result<Self> {
         // TODO: Validatate that all event names are unique
         validate_names_valid_rescript(
             &events.iter().map(|e| e.name.clone()).collect(),
             "event".to_string(),
         )?;
+        validate_names_valid_rescript(
+            &calls.iter().map(|c| c.name.clone()).collect(),
+            "call".to_string(),
+        )?;
+        Self::validate_anonymous_events(&name, &events)?;
 
         Ok(Self {
             name,
             events,
+            calls,
             handler_path,
             abi,
         })
     }
 
-    pub fn get_path_to_handler(&self, project_paths: &ParsedProjectPaths) -> Result<PathBuf> {
-        let handler_path = path_utils::get_config_path_relative_to_root(
-            project_paths,
-            PathBuf::from(&self.handler_path),
-        )
-        .context(format!(
-            "Failed creating a relative path to handler in contract {}",
-            self.name
-        ))?;
-
-        Ok(handler_path)
+    ///A contract is wildcard when all of its events are wildcard,
+    ///so its addresses would never be used to select data
+    pub fn is_wildcard(&self) -> bool {
+        !self.events.is_empty()
+            && self.events.iter().all(|e| e.is_wildcard)
+            && self.calls.is_empty()
     }
 
-    pub fn get_chain_ids(&self, system_config: &SystemConfig) -> Vec<u64> {
-        system_config
-            .get_networks()
-            .iter()
-            .filter_map(|network| {
-                if network.contracts.iter().any(|c| c.name == self.name) {
-                    Some(network.id)
-                } else {
-                    None
+    ///Anonymous events are routed by their shape, so the logs of two anonymous events
+    ///of a contract with the same shape can't be told apart
+    fn validate_anonymous_events(contract_name: &str, events: &[Event]) -> Result<()> {
+        let mut event_names_by_shape: HashMap<String, &String> = HashMap::new();
+        for event in events.iter().filter(|e| e.is_anonymous) {
+            if let EventKind::Params(params) = &event.kind {
+                let shape = Event::get_anonymous_shape(params);
+                if let Some(other_event_name) = event_names_by_shape.insert(shape, &event.name) {
+                    return Err(anyhow!(
+                        "EE131: The anonymous events {} and {} of the contract {} have the same \
+                         number of indexed params and the same data size, so their logs can't be \
+                         told apart. Keep only one of them in the config.",
+                        other_event_name,
+                        event.name,
+                        contract_name
+                    ));
                 }
-            })
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_wildcard_addresses(networks: &NetworkMap, contracts: &ContractMap) -> Result<()> {
+        let mut networks: Vec<&Network> = networks.values().collect();
+        networks.sort_by_key(|n| n.id);
+        for network in networks {
+            for network_contract in network.contracts.iter() {
+                let is_wildcard = contracts
+                    .get(&network_contract.name)
+                    .map_or(false, |contract| contract.is_wildcard());
+                if is_wildcard && !network_contract.addresses.is_empty() {
+                    return Err(anyhow!(
+                        "EE125: The contract {} on network {} has addresses, but all of its \
+                         events are wildcard and indexed from any address. Remove the addresses \
+                         of the contract or disable wildcard for some of its events.",
+                        network_contract.name,
+                        network.id
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///Wildcard events are selected by topic0 only, which anonymous events don't have
+    fn validate_wildcard_anonymous_events(contracts: &ContractMap) -> Result<()> {
+        let mut contracts: Vec<&Contract> = contracts.values().collect();
+        contracts.sort_by(|a, b| a.name.cmp(&b.name));
+        for contract in contracts {
+            if let Some(event) = contract
+                .events
+                .iter()
+                .find(|e| e.is_anonymous && e.is_wildcard)
+            {
+                return Err(anyhow!(
+                    "EE132: The anonymous event {} of the contract {} is wildcard, but anonymous \
+                     events have no topic0 to select them from any address. Disable wildcard \
+                     for the event or give the contract addresses.",
+                    event.name,
+                    contract.name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_block_ranges(networks: &NetworkMap, contracts: &ContractMap) -> Result<()> {
+        let format_block_range = |start_block: u64, end_block: Option<u64>| match end_block {
+            Some(end_block) => format!("{start_block}-{end_block}"),
+            None => format!("{start_block}-latest"),
+        };
+
+        let mut networks: Vec<&Network> = networks.values().collect();
+        networks.sort_by_key(|n| n.id);
+        for network in networks {
+            let is_within_network = |block: u64| {
+                block >= network.start_block && network.end_block.map_or(true, |end| block <= end)
+            };
+            for network_contract in network.contracts.iter() {
+                let mut block_ranges = vec![(
+                    format!("contract {}", network_contract.name),
+                    network_contract.start_block,
+                    network_contract.end_block,
+                )];
+                if let Some(contract) = contracts.get(&network_contract.name) {
+                    block_ranges.extend(contract.events.iter().map(|event| {
+                        (
+                            format!("event {}.{}", network_contract.name, event.name),
+                            event.start_block.or(network_contract.start_block),
+                            event.end_block.or(network_contract.end_block),
+                        )
+                    }));
+                }
+
+                for (subject, start_block, end_block) in block_ranges {
+                    let is_valid = start_block.map_or(true, is_within_network)
+                        && end_block.map_or(true, is_within_network)
+                        && match (start_block, end_block) {
+                            (Some(start_block), Some(end_block)) => start_block <= end_block,
+                            _ => true,
+                        };
+                    if !is_valid {
+                        return Err(anyhow!(
+                            "EE130: The block range {} of the {} on network {} is invalid. It \
+                             should start before it ends and be within the block range {} of the \
+                             network.",
+                            format_block_range(
+                                start_block.unwrap_or(network.start_block),
+                                end_block.or(network.end_block)
+                            ),
+                            subject,
+                            network.id,
+                            format_block_range(network.start_block, network.end_block),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_path_to_handler(&self, project_paths: &ParsedProjectPaths) -> Result<PathBuf> {
+        let handler_path = path_utils::get_config_path_relative_to_root(
+            project_paths,
+            PathBuf::from(&self.handler_path),
+        )
+        .context(format!(
+            "Failed creating a relative path to handler in contract {}",
+            self.name
+        ))?;
+
+        Ok(handler_path)
+    }
+
+    pub fn get_chain_ids(&self, system_config: &SystemConfig) -> Vec<u64> {
+        system_config
+            .get_networks()
+            .iter()
+            .filter_map(|network| {
+                if network.contracts.iter().any(|c| c.name == self.name) {
+                    Some(network.id)
+                } else {
+                    None
+                }
+            })
             .collect()
     }
 }
@@ -1203,9 +1960,178 @@ pub struct Event {
     pub name: String,
     pub sighash: String,
     pub field_selection: Option<FieldSelection>,
+    ///Anonymous events don't have a topic0 and are routed by their shape instead
+    pub is_anonymous: bool,
+    ///Groups of conditions on the indexed params applied by the data source.
+    ///An event matches when all the conditions of any group match
+    pub filters: Vec<Vec<EventFilterCondition>>,
+    ///Wildcard events are indexed from any address
+    pub is_wildcard: bool,
+    ///Overrides the block range of the network contract for the event
+    pub start_block: Option<u64>,
+    pub end_block: Option<u64>,
+}
+
+///A condition on an indexed event param, which matches any of the values
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventFilterCondition {
+    pub param: EventParam,
+    ///Values normalized for the param type. Numbers are decimal and string or bytes
+    ///params are represented by their keccak256 hash, the same way they are stored in topics
+    pub values: Vec<String>,
+}
+
+impl EventFilterCondition {
+    fn normalize_value(param: &EventParam, value: &str) -> Result<String> {
+        use ethers::{
+            abi::ParamType,
+            core::utils::hex,
+            types::{I256, U256},
+        };
+
+        let value = value.trim();
+        let decode_hex = |value: &str| {
+            value
+                .strip_prefix("0x")
+                .and_then(|value| hex::decode(value).ok())
+                .ok_or_else(|| anyhow!("{} is not a 0x prefixed hex value", value))
+        };
+
+        match &param.kind {
+            ParamType::Address => {
+                if validation::is_valid_ethereum_address(value) {
+                    Ok(value.to_string())
+                } else {
+                    Err(anyhow!("{} is not a valid address", value))
+                }
+            }
+            ParamType::Uint(size) => {
+                let number = match value.strip_prefix("0x") {
+                    Some(hex_value) => U256::from_str_radix(hex_value, 16),
+                    None => U256::from_dec_str(value),
+                }
+                .map_err(|_| anyhow!("{} is not a valid unsigned integer", value))?;
+                if number.bits() > *size {
+                    return Err(anyhow!("{} doesn't fit into uint{}", value, size));
+                }
+                Ok(number.to_string())
+            }
+            ParamType::Int(_size) => {
+                let number = match value.strip_prefix("0x") {
+                    Some(_) => I256::from_hex_str(value),
+                    None => I256::from_dec_str(value),
+                }
+                .map_err(|_| anyhow!("{} is not a valid integer", value))?;
+                Ok(number.to_string())
+            }
+            ParamType::Bool => match value {
+                "true" | "false" => Ok(value.to_string()),
+                _ => Err(anyhow!("{} is not a boolean", value)),
+            },
+            ParamType::FixedBytes(size) => {
+                let bytes = decode_hex(value)?;
+                if bytes.len() != *size {
+                    return Err(anyhow!("{} should be {} bytes long", value, size));
+                }
+                Ok(hex::encode_prefixed(bytes))
+            }
+            ParamType::String => Ok(hex::encode_prefixed(ethers::utils::keccak256(
+                value.as_bytes(),
+            ))),
+            ParamType::Bytes => {
+                let bytes = decode_hex(value)?;
+                Ok(hex::encode_prefixed(ethers::utils::keccak256(bytes)))
+            }
+            kind => Err(anyhow!(
+                "Filtering by {} params isn't supported in the config. Use eventFilters in \
+                 the handler instead",
+                kind
+            )),
+        }
+    }
+
+    fn from_config(
+        filters: &SingleOrList<BTreeMap<String, SingleOrList<String>>>,
+        params: &[EventParam],
+        event_name: &str,
+    ) -> Result<Vec<Vec<Self>>> {
+        Vec::from(filters.clone())
+            .into_iter()
+            .map(|filter| {
+                if filter.is_empty() {
+                    return Err(anyhow!(
+                        "EE124: The event {} has an empty filter. Provide at least one param to \
+                         filter by.",
+                        event_name
+                    ));
+                }
+                filter
+                    .into_iter()
+                    .map(|(param_name, values)| {
+                        let param = params
+                            .iter()
+                            .find(|param| param.name == param_name)
+                            .ok_or_else(|| {
+                                anyhow!(
+                                    "EE124: The event {} doesn't have a param {} to filter by.",
+                                    event_name,
+                                    param_name
+                                )
+                            })?;
+                        if !param.indexed {
+                            return Err(anyhow!(
+                                "EE124: The param {} of the event {} isn't indexed. Only \
+                                 indexed params can be filtered by the data source.",
+                                param_name,
+                                event_name
+                            ));
+                        }
+                        let values = Vec::from(values)
+                            .iter()
+                            .map(|value| Self::normalize_value(param, value))
+                            .collect::<Result<Vec<_>>>()
+                            .map_err(|err| {
+                                anyhow!(
+                                    "EE124: Invalid filter value for the param {} of the event \
+                                     {}: {}",
+                                    param_name,
+                                    event_name,
+                                    err
+                                )
+                            })?;
+                        if values.is_empty() {
+                            return Err(anyhow!(
+                                "EE124: The filter for the param {} of the event {} doesn't \
+                                 have any values.",
+                                param_name,
+                                event_name
+                            ));
+                        }
+                        Ok(EventFilterCondition {
+                            param: param.clone(),
+                            values,
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 }
 
 impl Event {
+    ///Anonymous events are identified by the number of indexed params
+    ///and the byte size of the log data, or "dynamic" when it can vary
+    pub fn get_anonymous_shape(params: &[EventParam]) -> String {
+        let topic_count = params.iter().filter(|p| p.indexed).count();
+        let data_shape = params
+            .iter()
+            .filter(|p| !p.indexed)
+            .map(|p| EthereumEventParam::from(p).get_static_abi_size())
+            .sum::<Option<usize>>()
+            .map_or("dynamic".to_string(), |size| size.to_string());
+        format!("{topic_count}_{data_shape}")
+    }
+
     fn get_abi_event(event_string: &String, opt_abi: &Option<EvmAbi>) -> Result<EthAbiEvent> {
         let parse_event_sig = |sig: &str| -> Result<EthAbiEvent> {
             match HumanReadableParser::parse_event(sig) {
@@ -1224,41 +2150,114 @@ impl Event {
         if event_string.starts_with("event ") {
             parse_event_sig(event_string)
         } else if event_string.contains('(') {
-            let signature = format!("event {}", event_string);
-            parse_event_sig(&signature)
+            //A signature with only the param types selects an overload from the abi
+            let overload_signature = event_string.split_whitespace().collect::<String>();
+            let abi_event = opt_abi.as_ref().and_then(|abi| {
+                abi.typed
+                    .events()
+                    .find(|event| Self::get_overload_signature(event) == overload_signature)
+            });
+            match abi_event {
+                Some(event) => Ok(event.clone()),
+                None => {
+                    let signature = format!("event {}", event_string);
+                    parse_event_sig(&signature)
+                }
+            }
         } else {
             match opt_abi {
                 Some(abi) => {
-                    let event = abi
+                    let overloads = abi
                         .typed
-                        .event(event_string)
+                        .events_by_name(event_string)
                         .context(format!("Failed retrieving event {} from abi", event_string))?;
-                    Ok(event.clone())
+                    match overloads.as_slice() {
+                        [event] => Ok(event.clone()),
+                        overloads => Err(anyhow!(
+                            "EE126: The event {} is overloaded in the abi, so the name is \
+                             ambiguous. Select one of the overloads by its signature instead: {}",
+                            event_string,
+                            overloads
+                                .iter()
+                                .map(Self::get_overload_signature)
+                                .join(", ")
+                        )),
+                    }
                 }
                 None => Err(anyhow!("No abi file provided for event {}", event_string)),
             }
         }
     }
 
+    ///The event name with the param types, eg. Transfer(address,address,uint256)
+    fn get_overload_signature(event: &EthAbiEvent) -> String {
+        format!(
+            "{}({})",
+            event.name,
+            event
+                .inputs
+                .iter()
+                .map(|input| input.kind.to_string())
+                .join(",")
+        )
+    }
+
+    ///A unique name for an overloaded event, eg. Transfer_address_address_uint256
+    fn get_overload_name(event: &EthAbiEvent) -> String {
+        event
+            .inputs
+            .iter()
+            .flat_map(|input| {
+                input
+                    .kind
+                    .to_string()
+                    .replace("[]", " array")
+                    .split(|c: char| !c.is_ascii_alphanumeric())
+                    .filter(|part| !part.is_empty())
+                    .map(|part| part.to_string())
+                    .collect::<Vec<_>>()
+            })
+            .fold(event.name.clone(), |name, part| format!("{name}_{part}"))
+    }
+
     pub fn from_evm_events_config(
         events_config: Vec<EvmEventConfig>,
-        abi_file_path: &Option<String>,
-        project_paths: &ParsedProjectPaths,
+        abi_from_file: &Option<EvmAbi>,
         has_rpc_sync_src: bool,
+        is_wildcard_contract: bool,
     ) -> Result<(Vec<Self>, EvmAbi)> {
-        let abi_from_file = EvmAbi::from_file(&abi_file_path, &project_paths)?;
-
         let mut events = vec![];
         let mut events_abi = ethers::abi::Abi::default();
 
-        for event_config in events_config.iter() {
-            let mut event = Event::get_abi_event(&event_config.event, &abi_from_file)?;
+        let abi_events = events_config
+            .iter()
+            .map(|event_config| Event::get_abi_event(&event_config.event, abi_from_file))
+            .collect::<Result<Vec<_>>>()?;
+        //Only overloads configured together get unique names by default, so selecting
+        //a single overload keeps the name of the event
+        let is_overloaded =
+            |abi_name: &String| abi_events.iter().filter(|e| &e.name == abi_name).count() > 1;
+
+        for (event_config, mut event) in events_config.iter().zip(abi_events.iter().cloned()) {
             let sighash = ethers::core::utils::hex::encode_prefixed(ethers::utils::keccak256(
                 event.abi_signature().as_bytes(),
             ));
 
             let abi_name = event.name.clone();
-            let name = event_config.name.clone().unwrap_or(abi_name.clone());
+            let is_anonymous = event.anonymous;
+            if is_anonymous && event_config.name.is_none() {
+                return Err(anyhow!(
+                    "EE112: The anonymous event {} requires an explicit name in the config. \
+                     Anonymous events don't have a topic0, so set the name field of the event \
+                     to distinguish it from other events of the contract.",
+                    abi_name
+                ));
+            }
+            let name = match event_config.name {
+                Some(ref name) => name.clone(),
+                None if is_overloaded(&abi_name) => Event::get_overload_name(&event),
+                None => abi_name.clone(),
+            };
 
             let normalized_unnamed_params: Vec<EventParam> = event
                 .clone()
@@ -1280,6 +2279,13 @@ impl Event {
             // instead of an object with named fields.
             event.inputs = normalized_unnamed_params.clone();
 
+            let filters = match event_config.filters {
+                Some(ref filters) => {
+                    EventFilterCondition::from_config(filters, &normalized_unnamed_params, &name)?
+                }
+                None => vec![],
+            };
+
             events_abi.events.entry(abi_name).or_default().push(event);
             events.push(Event {
                 name,
@@ -1294,6 +2300,11 @@ impl Event {
                     }
                     None => None,
                 },
+                is_anonymous,
+                filters,
+                is_wildcard: event_config.wildcard.unwrap_or(is_wildcard_contract),
+                start_block: event_config.start_block,
+                end_block: event_config.end_block,
             })
         }
 
@@ -1303,9 +2314,9 @@ impl Event {
         Ok((
             events,
             EvmAbi {
-                path: match abi_from_file {
-                    Some(abi) => abi.path.clone(),
-                    None => None,
+                paths: match abi_from_file {
+                    Some(abi) => abi.paths.clone(),
+                    None => vec![],
                 },
                 raw: events_abi_raw,
                 typed: events_abi,
@@ -1373,6 +2384,11 @@ impl Event {
                         kind: EventKind::Fuel(FuelEventKind::LogData(log.data_type)),
                         sighash: log.id,
                         field_selection: None,
+                        is_anonymous: false,
+                        filters: vec![],
+                        is_wildcard: false,
+                        start_block: None,
+                        end_block: None,
                     }
                 }
                 EventType::Mint => Event {
@@ -1380,24 +2396,44 @@ impl Event {
                     kind: EventKind::Fuel(FuelEventKind::Mint),
                     sighash: "mint".to_string(),
                     field_selection: None,
+                    is_anonymous: false,
+                    filters: vec![],
+                    is_wildcard: false,
+                    start_block: None,
+                    end_block: None,
                 },
                 EventType::Burn => Event {
                     name: event_config.name.clone(),
                     kind: EventKind::Fuel(FuelEventKind::Burn),
                     sighash: "burn".to_string(),
                     field_selection: None,
+                    is_anonymous: false,
+                    filters: vec![],
+                    is_wildcard: false,
+                    start_block: None,
+                    end_block: None,
                 },
                 EventType::Transfer => Event {
                     name: event_config.name.clone(),
                     kind: EventKind::Fuel(FuelEventKind::Transfer),
                     sighash: "transfer".to_string(),
                     field_selection: None,
+                    is_anonymous: false,
+                    filters: vec![],
+                    is_wildcard: false,
+                    start_block: None,
+                    end_block: None,
                 },
                 EventType::Call => Event {
                     name: event_config.name.clone(),
                     kind: EventKind::Fuel(FuelEventKind::Call),
                     sighash: "call".to_string(),
                     field_selection: None,
+                    is_anonymous: false,
+                    filters: vec![],
+                    is_wildcard: false,
+                    start_block: None,
+                    end_block: None,
                 },
             };
 
@@ -1409,6 +2445,104 @@ impl Event {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    pub name: String,
+    pub function_selector: String,
+    pub inputs: Vec<Param>,
+    pub outputs: Vec<Param>,
+}
+
+impl Call {
+    fn get_abi_function(call_string: &String, opt_abi: &Option<EvmAbi>) -> Result<EthAbiFunction> {
+        let parse_function_sig = |sig: &str| -> Result<EthAbiFunction> {
+            match HumanReadableParser::parse_function(sig) {
+                Ok(function) => Ok(function),
+                Err(err) => Err(anyhow!(
+                    "EE117: Unable to parse function signature {} due to the following error: \
+                     {}. Please refer to our docs on how to correctly define a human readable \
+                     ABI.",
+                    sig,
+                    err
+                )),
+            }
+        };
+
+        let call_string = call_string.trim();
+
+        if call_string.starts_with("function ") {
+            parse_function_sig(call_string)
+        } else if call_string.contains('(') {
+            let signature = format!("function {}", call_string);
+            parse_function_sig(&signature)
+        } else {
+            match opt_abi {
+                Some(abi) => {
+                    let function = abi.typed.function(call_string).context(format!(
+                        "Failed retrieving function {} from abi",
+                        call_string
+                    ))?;
+                    Ok(function.clone())
+                }
+                None => Err(anyhow!("No abi file provided for call {}", call_string)),
+            }
+        }
+    }
+
+    ///Unnamed params are named by their position, so they can be decoded
+    ///into an object with named fields
+    fn normalize_unnamed_params(params: Vec<Param>) -> Vec<Param> {
+        params
+            .into_iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let name = if p.name.is_empty() {
+                    format!("_{}", i)
+                } else {
+                    p.name
+                };
+                Param { name, ..p }
+            })
+            .collect()
+    }
+
+    pub fn from_evm_calls_config(
+        calls_config: Option<&Vec<EvmCallConfig>>,
+        abi_from_file: &Option<EvmAbi>,
+    ) -> Result<Vec<Self>> {
+        let calls_config = match calls_config {
+            None => return Ok(vec![]),
+            Some(calls_config) => calls_config,
+        };
+
+        let mut calls: Vec<Self> = vec![];
+
+        for call_config in calls_config.iter() {
+            let function = Self::get_abi_function(&call_config.call, abi_from_file)?;
+            let name = call_config.name.clone().unwrap_or(function.name.clone());
+
+            if calls.iter().any(|call| call.name == name) {
+                return Err(anyhow!(
+                    "EE118: The call name {} is used more than once in the contract. Set the \
+                     name field of the call to distinguish overloaded functions.",
+                    name
+                ));
+            }
+
+            calls.push(Call {
+                name,
+                function_selector: ethers::core::utils::hex::encode_prefixed(
+                    function.short_signature(),
+                ),
+                inputs: Self::normalize_unnamed_params(function.inputs),
+                outputs: Self::normalize_unnamed_params(function.outputs),
+            })
+        }
+
+        Ok(calls)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SelectedField {
     pub name: String,
@@ -1622,10 +2756,10 @@ impl FieldSelection {
 mod test {
     use std::path::PathBuf;
 
-    use super::SystemConfig;
+    use super::{Abi, Contract, SystemConfig};
     use crate::{
         config_parsing::{
-            human_config::evm::HumanConfig as EvmConfig,
+            human_config::evm::{EventConfig as EvmEventConfig, HumanConfig as EvmConfig},
             system_config::{Event, SyncConfig, SyncSource},
         },
         project_paths::ParsedProjectPaths,
@@ -1635,6 +2769,31 @@ mod test {
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
+    ///Event config of the config file with only the event signature set
+    fn event_config(event: &str) -> EvmEventConfig {
+        EvmEventConfig {
+            event: event.to_string(),
+            name: None,
+            field_selection: None,
+            filters: None,
+            wildcard: None,
+            start_block: None,
+            end_block: None,
+        }
+    }
+
+    ///Contract with the events parsed from human readable signatures
+    fn make_contract(name: &str, event_configs: Vec<EvmEventConfig>) -> anyhow::Result<Contract> {
+        let (events, evm_abi) = Event::from_evm_events_config(event_configs, &None, false, false)?;
+        Contract::new(
+            name.to_string(),
+            "./src/EventHandlers.ts".to_string(),
+            events,
+            vec![],
+            Abi::Evm(evm_abi),
+        )
+    }
+
     #[test]
     fn renders_nested_f32() {
         let hbs = Handlebars::new();
@@ -1817,6 +2976,177 @@ mod test {
         );
     }
 
+    #[test]
+    fn anonymous_events_require_explicit_name() {
+        let make_event_config = |name: Option<&str>| EvmEventConfig {
+            name: name.map(|name| name.to_string()),
+            ..event_config("MyEvent(uint256 indexed myArg) anonymous")
+        };
+
+        let error =
+            Event::from_evm_events_config(vec![make_event_config(None)], &None, false, false)
+                .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE112: The anonymous event MyEvent requires an explicit name in the config. \
+             Anonymous events don't have a topic0, so set the name field of the event to \
+             distinguish it from other events of the contract."
+        );
+
+        let (events, _) = Event::from_evm_events_config(
+            vec![make_event_config(Some("MyAnonymousEvent"))],
+            &None,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(events[0].name, "MyAnonymousEvent");
+        assert!(events[0].is_anonymous);
+    }
+
+    #[test]
+    fn calls_are_parsed_from_function_signatures() {
+        use crate::config_parsing::{
+            human_config::evm::CallConfig as EvmCallConfig, system_config::Call,
+        };
+        use ethers::abi::ParamType;
+
+        let make_call_config = |call: &str, name: Option<&str>| EvmCallConfig {
+            call: call.to_string(),
+            name: name.map(|name| name.to_string()),
+        };
+
+        let calls = Call::from_evm_calls_config(
+            Some(&vec![
+                make_call_config("setOwner(address newOwner)", None),
+                make_call_config(
+                    "function balanceOf(address) view returns (uint256)",
+                    Some("GetBalance"),
+                ),
+            ]),
+            &None,
+        )
+        .unwrap();
+
+        assert_eq!(calls[0].name, "setOwner");
+        assert_eq!(calls[0].function_selector, "0x13af4035");
+        assert_eq!(calls[0].inputs[0].name, "newOwner");
+        assert!(calls[0].outputs.is_empty());
+        assert_eq!(calls[1].name, "GetBalance");
+        assert_eq!(calls[1].function_selector, "0x70a08231");
+        assert_eq!(calls[1].inputs[0].name, "_0");
+        assert_eq!(calls[1].outputs[0].name, "_0");
+        assert_eq!(calls[1].outputs[0].kind, ParamType::Uint(256));
+
+        let error = Call::from_evm_calls_config(
+            Some(&vec![
+                make_call_config("transfer(address to, uint256 amount)", None),
+                make_call_config("transfer(address to)", None),
+            ]),
+            &None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE118: The call name transfer is used more than once in the contract. Set the name \
+             field of the call to distinguish overloaded functions."
+        );
+    }
+
+    #[test]
+    fn block_handler_defaults_to_network_blocks() {
+        use crate::config_parsing::{
+            human_config::evm::Network as EvmNetwork, system_config::BlockHandler,
+        };
+
+        let network: EvmNetwork = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "start_block": 100,
+            "end_block": 200,
+            "contracts": [],
+            "block_handlers": [
+                {"name": "EveryBlock", "handler": "./src/BlockHandlers.ts"},
+                {
+                    "name": "EveryTenBlocks",
+                    "handler": "./src/BlockHandlers.ts",
+                    "interval": 10,
+                    "start_block": 150
+                }
+            ]
+        }))
+        .unwrap();
+
+        let block_handlers: Vec<BlockHandler> = network
+            .block_handlers
+            .iter()
+            .flatten()
+            .map(|block_handler| BlockHandler::from_evm_config(block_handler, &network))
+            .collect();
+
+        assert_eq!(
+            block_handlers,
+            vec![
+                BlockHandler {
+                    name: "EveryBlock".to_string(),
+                    handler_path: "./src/BlockHandlers.ts".to_string(),
+                    interval: 1,
+                    start_block: 100,
+                    end_block: Some(200),
+                },
+                BlockHandler {
+                    name: "EveryTenBlocks".to_string(),
+                    handler_path: "./src/BlockHandlers.ts".to_string(),
+                    interval: 10,
+                    start_block: 150,
+                    end_block: Some(200),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn transaction_handler_resolves_function_signatures() {
+        use crate::{
+            config_parsing::{
+                human_config::evm::{TransactionHandlerConfig, TransactionStatus},
+                system_config::{SelectedField, TransactionHandler},
+            },
+            rescript_types::RescriptTypeIdent,
+        };
+
+        let config: TransactionHandlerConfig = serde_json::from_value(serde_json::json!({
+            "name": "Transfers",
+            "handler": "./src/TransactionHandlers.ts",
+            "to": "0x2E645469f354BB4F5c8a05B3b30A929361cf77eC",
+            "function_selector": ["0xA9059CBB", "transferFrom(address,address,uint256)"],
+            "status": "success",
+            "transaction_fields": ["hash", "gasUsed"]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            TransactionHandler::from_evm_config(&config).unwrap(),
+            TransactionHandler {
+                name: "Transfers".to_string(),
+                handler_path: "./src/TransactionHandlers.ts".to_string(),
+                from: vec![],
+                to: vec!["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC".to_string()],
+                function_selectors: vec!["0xa9059cbb".to_string(), "0x23b872dd".to_string()],
+                status: Some(TransactionStatus::Success),
+                transaction_fields: vec![
+                    SelectedField {
+                        name: "Hash".to_string(),
+                        data_type: RescriptTypeIdent::String,
+                    },
+                    SelectedField {
+                        name: "GasUsed".to_string(),
+                        data_type: RescriptTypeIdent::BigInt,
+                    },
+                ],
+            }
+        );
+    }
+
     #[test]
     fn test_parse_url() {
         let valid_url_1 = "https://eth-mainnet.g.alchemy.com/v2/T7uPV59s7knYTOUardPPX0hq7n7_rQwv";
@@ -1875,6 +3205,8 @@ mod test {
             end_block: None,
             confirmed_block_threshold: None,
             contracts: vec![],
+            block_handlers: None,
+            transactions: None,
         };
 
         let sync_source = SyncSource::from_evm_network_config(network, None).unwrap();
@@ -1886,4 +3218,418 @@ mod test {
             _ => panic!("Expected HypersyncConfig"),
         }
     }
+
+    #[test]
+    fn parses_event_filters_for_indexed_params() {
+        use super::EventFilterCondition;
+        use std::collections::BTreeMap;
+
+        let params = vec![
+            EventParam {
+                name: "from".to_string(),
+                kind: ParamType::Address,
+                indexed: true,
+            },
+            EventParam {
+                name: "to".to_string(),
+                kind: ParamType::Address,
+                indexed: true,
+            },
+            EventParam {
+                name: "value".to_string(),
+                kind: ParamType::Uint(256),
+                indexed: false,
+            },
+        ];
+        let treasury = "0x2E645469f354BB4F5c8a05B3b30A929361cf77eC".to_string();
+
+        let filters = EventFilterCondition::from_config(
+            &BTreeMap::from([("to".to_string(), treasury.clone().into())]).into(),
+            &params,
+            "Transfer",
+        )
+        .unwrap();
+        assert_eq!(
+            filters,
+            vec![vec![EventFilterCondition {
+                param: params[1].clone(),
+                values: vec![treasury.clone()],
+            }]]
+        );
+
+        let error = EventFilterCondition::from_config(
+            &BTreeMap::from([("value".to_string(), "1".to_string().into())]).into(),
+            &params,
+            "Transfer",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE124: The param value of the event Transfer isn't indexed. Only indexed params can \
+             be filtered by the data source."
+        );
+    }
+
+    #[test]
+    fn rejects_anonymous_events_with_the_same_shape() {
+        let make_event_config = |event: &str, name: &str| EvmEventConfig {
+            name: Some(name.to_string()),
+            ..event_config(event)
+        };
+
+        let error = make_contract(
+            "Pool",
+            vec![
+                make_event_config(
+                    "Deposit(address indexed user, uint256 amount) anonymous",
+                    "Deposit",
+                ),
+                make_event_config(
+                    "Withdraw(address indexed user, uint256 amount) anonymous",
+                    "Withdraw",
+                ),
+            ],
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE131: The anonymous events Deposit and Withdraw of the contract Pool have the same \
+             number of indexed params and the same data size, so their logs can't be told apart. \
+             Keep only one of them in the config."
+        );
+
+        make_contract(
+            "Pool",
+            vec![
+                make_event_config(
+                    "Deposit(address indexed user, uint256 amount) anonymous",
+                    "Deposit",
+                ),
+                make_event_config(
+                    "Withdraw(address indexed user, string reason) anonymous",
+                    "Withdraw",
+                ),
+            ],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn rejects_wildcard_anonymous_events() {
+        use super::ContractMap;
+
+        let make_contracts = |wildcard: Option<bool>| {
+            let contract = make_contract(
+                "Pool",
+                vec![EvmEventConfig {
+                    wildcard,
+                    ..event_config("Deposit(address indexed user, uint256 amount) anonymous")
+                }],
+            )
+            .unwrap();
+            let mut contracts = ContractMap::new();
+            contracts.insert(contract.name.clone(), contract);
+            contracts
+        };
+
+        let error =
+            Contract::validate_wildcard_anonymous_events(&make_contracts(Some(true))).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE132: The anonymous event Deposit of the contract Pool is wildcard, but anonymous \
+             events have no topic0 to select them from any address. Disable wildcard for the \
+             event or give the contract addresses."
+        );
+
+        Contract::validate_wildcard_anonymous_events(&make_contracts(None)).unwrap();
+    }
+
+    #[test]
+    fn event_wildcard_falls_back_to_contract_wildcard() {
+        let make_event_config = |event: &str, wildcard: Option<bool>| EvmEventConfig {
+            wildcard,
+            ..event_config(event)
+        };
+
+        let (events, _) = Event::from_evm_events_config(
+            vec![
+                make_event_config("Transfer(address indexed from, address indexed to)", None),
+                make_event_config("Approval(address indexed owner)", Some(false)),
+            ],
+            &None,
+            false,
+            true,
+        )
+        .unwrap();
+        assert!(events[0].is_wildcard);
+        assert!(!events[1].is_wildcard);
+    }
+
+    #[test]
+    fn selects_overloaded_events_by_signature() {
+        use super::EvmAbi;
+
+        let raw = r#"[
+            {"type":"event","name":"Transfer","anonymous":false,"inputs":[
+                {"name":"from","type":"address","indexed":true},
+                {"name":"to","type":"address","indexed":true},
+                {"name":"value","type":"uint256","indexed":false}
+            ]},
+            {"type":"event","name":"Transfer","anonymous":false,"inputs":[
+                {"name":"from","type":"address","indexed":true},
+                {"name":"to","type":"address","indexed":true},
+                {"name":"ids","type":"uint256[]","indexed":false}
+            ]}
+        ]"#;
+        let abi = Some(EvmAbi {
+            paths: vec![],
+            raw: raw.to_string(),
+            typed: serde_json::from_str(raw).unwrap(),
+        });
+
+        let error = Event::get_abi_event(&"Transfer".to_string(), &abi).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE126: The event Transfer is overloaded in the abi, so the name is ambiguous. Select \
+             one of the overloads by its signature instead: Transfer(address,address,uint256), \
+             Transfer(address,address,uint256[])"
+        );
+
+        let event =
+            Event::get_abi_event(&"Transfer(address, address, uint256[])".to_string(), &abi)
+                .unwrap();
+        assert_eq!(event.inputs[2].name, "ids");
+        assert!(event.inputs[0].indexed);
+        assert_eq!(
+            Event::get_overload_name(&event),
+            "Transfer_address_address_uint256_array"
+        );
+
+        let get_names = |event_configs: Vec<EvmEventConfig>| {
+            let (events, _) =
+                Event::from_evm_events_config(event_configs, &abi, false, false).unwrap();
+            events
+                .into_iter()
+                .map(|event| event.name)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            get_names(vec![event_config("Transfer(address, address, uint256)")]),
+            vec!["Transfer"],
+            "A single selected overload keeps the event name"
+        );
+        assert_eq!(
+            get_names(vec![
+                event_config("Transfer(address, address, uint256)"),
+                event_config("Transfer(address, address, uint256[])"),
+            ]),
+            vec![
+                "Transfer_address_address_uint256",
+                "Transfer_address_address_uint256_array"
+            ],
+            "Overloads configured together get unique names"
+        );
+    }
+
+    #[test]
+    fn loads_and_merges_abis_from_artifacts() {
+        use super::EvmAbi;
+        use tempdir::TempDir;
+
+        let project_dir = TempDir::new("abi_artifacts_test").expect("Failed creating tempdir");
+        let project_root = project_dir.path().to_str().unwrap();
+        let write_file = |path: &str, content: &str| {
+            let path = project_dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+        let transfer = r#"{"type":"event","name":"Transfer","anonymous":false,"inputs":[
+            {"name":"from","type":"address","indexed":true},
+            {"name":"value","type":"uint256","indexed":false}
+        ]}"#;
+        let approval = r#"{"type":"event","name":"Approval","anonymous":false,"inputs":[
+            {"name":"owner","type":"address","indexed":true}
+        ]}"#;
+        write_file(
+            "out/Token.sol/Token.json",
+            &format!(r#"{{"abi":[{transfer}],"bytecode":{{"object":"0x"}}}}"#),
+        );
+        write_file("facets/A.json", &format!("[{transfer}]"));
+        write_file("facets/B.json", &format!("[{transfer},{approval}]"));
+        write_file(
+            "conflict/A.json",
+            &format!(
+                "[{}]",
+                transfer.replace(r#""indexed":false"#, r#""indexed":true"#)
+            ),
+        );
+
+        let project_paths = ParsedProjectPaths::new(project_root, "generated/", "config.yaml")
+            .expect("Failed creating parsed_paths");
+        let from_file = |abi_file_path: Vec<&str>| {
+            EvmAbi::from_file(
+                &Some(
+                    abi_file_path
+                        .into_iter()
+                        .map(|path| path.to_string())
+                        .collect::<Vec<_>>()
+                        .into(),
+                ),
+                "Token",
+                &project_paths,
+            )
+        };
+
+        let artifact_abi = from_file(vec!["./out"]).unwrap().unwrap();
+        assert_eq!(
+            artifact_abi.paths,
+            vec![project_dir.path().join("out/Token.sol/Token.json")]
+        );
+        assert_eq!(artifact_abi.typed.events().count(), 1);
+
+        let merged_abi = from_file(vec!["./facets/*.json"]).unwrap().unwrap();
+        assert_eq!(merged_abi.paths.len(), 2);
+        assert_eq!(
+            merged_abi
+                .typed
+                .events()
+                .map(|event| event.name.clone())
+                .collect::<Vec<_>>(),
+            vec!["Approval", "Transfer"]
+        );
+
+        let error = from_file(vec!["./facets/A.json", "./conflict/A.json"]).unwrap_err();
+        assert!(error.to_string().starts_with(
+            "EE128: The event Transfer(address,uint256) is defined differently in the ABI files"
+        ));
+    }
+
+    #[test]
+    fn validates_factory_event_address_param() {
+        use super::Factory;
+        use crate::config_parsing::human_config::{evm::Network as EvmNetwork, FactoryConfig};
+        use std::collections::HashMap;
+
+        let factory_contract = make_contract(
+            "Factory",
+            vec![event_config(
+                "PoolCreated(address indexed token0, address pool, address[] hooks)",
+            )],
+        )
+        .unwrap();
+        let contracts = HashMap::from([("Factory".to_string(), factory_contract)]);
+        let network: EvmNetwork = serde_yaml::from_str(
+            r#"
+id: 1
+start_block: 0
+contracts:
+  - name: Factory
+    address: "0x1F98431c8aD98523631AE4a59f267346ea31F984"
+  - name: Pool
+    factory:
+      contract: Factory
+      event: PoolCreated
+      address_param: pool
+"#,
+        )
+        .unwrap();
+        let from_evm_config = |contract: &str, event: &str, address_param: &str| {
+            Factory::from_evm_config(
+                &FactoryConfig {
+                    contract: contract.to_string(),
+                    event: event.to_string(),
+                    address_param: address_param.to_string(),
+                },
+                "Pool",
+                &network,
+                &contracts,
+            )
+        };
+
+        assert_eq!(
+            from_evm_config("Factory", "PoolCreated", "pool").unwrap(),
+            Factory {
+                contract_name: "Factory".to_string(),
+                event_name: "PoolCreated".to_string(),
+                address_param: "pool".to_string(),
+                is_address_array: false,
+            }
+        );
+        assert!(
+            from_evm_config("Factory", "PoolCreated", "hooks")
+                .unwrap()
+                .is_address_array
+        );
+        assert_eq!(
+            from_evm_config("Registry", "PoolCreated", "pool")
+                .unwrap_err()
+                .to_string(),
+            "EE129: The factory contract Registry of the contract Pool should be defined on the \
+             network 1."
+        );
+        assert_eq!(
+            from_evm_config("Factory", "PoolCreated", "token1")
+                .unwrap_err()
+                .to_string(),
+            "EE129: The event PoolCreated of the factory contract Factory doesn't have the param \
+             token1. Available params: token0, pool, hooks"
+        );
+    }
+
+    #[test]
+    fn validates_contract_and_event_block_ranges() {
+        use super::{HumanConfig, Schema};
+
+        let parse_config = |contract_start_block: Option<u64>,
+                            event_start_block: Option<u64>,
+                            event_end_block: Option<u64>| {
+            let evm_config: EvmConfig = serde_json::from_value(json!({
+                "name": "block-ranges",
+                "networks": [{
+                    "id": 1,
+                    "start_block": 100,
+                    "end_block": 1000,
+                    "contracts": [{
+                        "name": "Pool",
+                        "address": "0x1F98431c8aD98523631AE4a59f267346ea31F984",
+                        "handler": "./src/EventHandlers.ts",
+                        "start_block": contract_start_block,
+                        "events": [{
+                            "event": "Swap(address indexed sender, int256 amount)",
+                            "start_block": event_start_block,
+                            "end_block": event_end_block,
+                        }],
+                    }],
+                }],
+            }))
+            .unwrap();
+            SystemConfig::from_human_config(
+                HumanConfig::Evm(evm_config),
+                Schema::empty(),
+                &ParsedProjectPaths::default(),
+            )
+        };
+
+        let config = parse_config(Some(500), None, Some(800)).unwrap();
+        let network_contract = &config.get_networks()[0].contracts[0];
+        assert_eq!(network_contract.start_block, Some(500));
+        assert_eq!(network_contract.end_block, None);
+        let contract = network_contract.get_contract(&config).unwrap();
+        assert_eq!(contract.events[0].start_block, None);
+        assert_eq!(contract.events[0].end_block, Some(800));
+
+        assert_eq!(
+            parse_config(Some(50), None, None).unwrap_err().to_string(),
+            "EE130: The block range 50-1000 of the contract Pool on network 1 is invalid. It \
+             should start before it ends and be within the block range 100-1000 of the network."
+        );
+        assert_eq!(
+            parse_config(Some(500), None, Some(400))
+                .unwrap_err()
+                .to_string(),
+            "EE130: The block range 500-400 of the event Pool.Swap on network 1 is invalid. It \
+             should start before it ends and be within the block range 100-1000 of the network."
+        );
+    }
 }
@@ -23,6 +23,11 @@ pub fn is_valid_ethereum_address(address: &str) -> bool {
     re.is_match(address)
 }
 
+pub fn is_valid_function_selector(function_selector: &str) -> bool {
+    let re = Regex::new(r"^0x[0-9a-fA-F]{8}$").unwrap();
+    re.is_match(function_selector)
+}
+
 // Contracts must have unique names in the config file.
 // Contract names are not case-sensitive.
 // This is regardless of networks.
@@ -172,10 +177,105 @@ impl human_config::evm::Network {
         }
         Ok(())
     }
+
+    pub fn validate_block_handlers(&self) -> anyhow::Result<()> {
+        let mut names = HashSet::new();
+        for block_handler in self.block_handlers.iter().flatten() {
+            if !names.insert(&block_handler.name) {
+                return Err(anyhow!(
+                    "EE114: The config file has multiple block handlers named {} for network id: \
+                     {}. Block handler names must be unique per network.",
+                    block_handler.name,
+                    self.id
+                ));
+            }
+            if block_handler.interval == Some(0) {
+                return Err(anyhow!(
+                    "EE113: The block handler {} for network id: {} has an interval of 0. The \
+                     interval must be greater than 0.",
+                    block_handler.name,
+                    self.id
+                ));
+            }
+            let start_block = block_handler.start_block.unwrap_or(self.start_block);
+            if let Some(end_block) = block_handler.end_block.or(self.end_block) {
+                if end_block < start_block {
+                    return Err(anyhow!(
+                        "EE115: The block handler {} for network id: {} has an end_block that is \
+                         less than its start_block. The end_block must be greater than the \
+                         start_block.",
+                        block_handler.name,
+                        self.id
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn validate_transaction_handlers(&self) -> anyhow::Result<()> {
+        let transaction_handlers = match &self.transactions {
+            Some(transaction_handlers) if !transaction_handlers.is_empty() => transaction_handlers,
+            _ => return Ok(()),
+        };
+        if self.rpc_config.is_some() {
+            return Err(anyhow!(
+                "EE122: Transaction handlers on network id: {} require a HyperSync data source. \
+                 Remove the rpc_config or use a network supported by HyperSync.",
+                self.id
+            ));
+        }
+        let mut names = HashSet::new();
+        for transaction_handler in transaction_handlers {
+            if !names.insert(&transaction_handler.name) {
+                return Err(anyhow!(
+                    "EE119: The config file has multiple transaction handlers named {} for \
+                     network id: {}. Transaction handler names must be unique per network.",
+                    transaction_handler.name,
+                    self.id
+                ));
+            }
+            let addresses = [&transaction_handler.from, &transaction_handler.to]
+                .into_iter()
+                .flatten()
+                .flat_map(|addresses| Vec::<String>::from(addresses.clone()));
+            for address in addresses {
+                if !is_valid_ethereum_address(&address) {
+                    return Err(anyhow!(
+                        "EE120: The transaction handler {} for network id: {} has an invalid \
+                         address \"{}\" in its from or to filter.",
+                        transaction_handler.name,
+                        self.id,
+                        address
+                    ));
+                }
+            }
+            if let Some(function_selectors) = &transaction_handler.function_selector {
+                for function_selector in Vec::<String>::from(function_selectors.clone()) {
+                    if !function_selector.contains('(')
+                        && !is_valid_function_selector(&function_selector)
+                    {
+                        return Err(anyhow!(
+                            "EE121: The transaction handler {} for network id: {} has an invalid \
+                             function_selector \"{}\". It should be a 4-byte hex selector (eg. \
+                             0xa9059cbb) or a function signature (eg. \
+                             transfer(address,uint256)).",
+                            transaction_handler.name,
+                            self.id,
+                            function_selector
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 pub fn validate_deserialized_config_yaml(evm_config: &HumanConfig) -> anyhow::Result<()> {
     let mut contract_names = Vec::new();
+    let mut block_handler_names = Vec::new();
+    let mut transaction_handler_names = Vec::new();
 
     if let Some(global_contracts) = &evm_config.contracts {
         for global_contract in global_contracts {
@@ -187,6 +287,16 @@ pub fn validate_deserialized_config_yaml(evm_config: &HumanConfig) -> anyhow::Re
         // validate endblock is a greater than the startblock
         network.validate_endblock_lte_startblock()?;
         network.validate_finite_endblock_networks(evm_config)?;
+        network.validate_block_handlers()?;
+        network.validate_transaction_handlers()?;
+
+        for block_handler in network.block_handlers.iter().flatten() {
+            block_handler_names.push(block_handler.name.clone());
+        }
+
+        for transaction_handler in network.transactions.iter().flatten() {
+            transaction_handler_names.push(transaction_handler.name.clone());
+        }
 
         for contract in &network.contracts {
             if let Some(_) = contract.config.as_ref() {
@@ -212,6 +322,11 @@ pub fn validate_deserialized_config_yaml(evm_config: &HumanConfig) -> anyhow::Re
     }
 
     validate_names_valid_rescript(&contract_names, "contract".to_string())?;
+    validate_names_valid_rescript(&block_handler_names, "block handler".to_string())?;
+    validate_names_valid_rescript(
+        &transaction_handler_names,
+        "transaction handler".to_string(),
+    )?;
 
     Ok(())
 }
@@ -223,23 +338,6 @@ pub fn check_enums_for_internal_reserved_words(enum_name_words: Vec<String>) ->
         .collect()
 }
 
-// Checking that schema does not include any reserved words
-pub fn check_names_from_schema_for_reserved_words(schema_words: Vec<String>) -> Vec<String> {
-    // Checking that schema does not include any reserved words
-    let mut detected_reserved_words_in_schema = Vec::new();
-    // Creating a deduplicated set of reserved words from javascript or rescript
-    let mut word_set: HashSet<&str> = HashSet::new();
-    word_set.extend(JAVASCRIPT_RESERVED_WORDS.iter());
-    word_set.extend(RESCRIPT_RESERVED_WORDS.iter());
-    for word in schema_words {
-        if word_set.contains(word.as_str()) {
-            detected_reserved_words_in_schema.push(word);
-        }
-    }
-
-    detected_reserved_words_in_schema
-}
-
 pub fn check_schema_enums_are_valid_postgres(enum_names: &Vec<String>) -> Vec<String> {
     let mut detected_enum_not_valid = Vec::new();
     for name in enum_names {
@@ -380,16 +478,6 @@ mod tests {
         assert_eq!(flagged_words, empty_vec);
     }
 
-    #[test]
-    fn test_names_from_schema_for_reserved_words() {
-        let names_from_schema = "Greeting id greetings lastGreeting lazy open catch"
-            .split(" ")
-            .map(|s| s.to_string())
-            .collect();
-        let flagged_words = super::check_names_from_schema_for_reserved_words(names_from_schema);
-        assert_eq!(flagged_words, vec!["lazy", "open", "catch"]);
-    }
-
     #[test]
     fn test_contract_names_validation() {
         let valid_result = super::validate_names_valid_rescript(
@@ -444,4 +532,114 @@ mod tests {
              alphanumeric characters and underscores."
         );
     }
+
+    #[test]
+    fn invalid_block_handlers() {
+        let make_network = |block_handlers: serde_json::Value| {
+            serde_json::from_value::<crate::config_parsing::human_config::evm::Network>(
+                serde_json::json!({
+                    "id": 1,
+                    "start_block": 100,
+                    "contracts": [],
+                    "block_handlers": block_handlers
+                }),
+            )
+            .unwrap()
+        };
+
+        let error = make_network(serde_json::json!([
+            {"name": "EveryBlock", "handler": "./src/BlockHandlers.ts", "interval": 0}
+        ]))
+        .validate_block_handlers()
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE113: The block handler EveryBlock for network id: 1 has an interval of 0. The \
+             interval must be greater than 0."
+        );
+
+        let error = make_network(serde_json::json!([
+            {"name": "EveryBlock", "handler": "./src/BlockHandlers.ts"},
+            {"name": "EveryBlock", "handler": "./src/BlockHandlers.ts", "interval": 2}
+        ]))
+        .validate_block_handlers()
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE114: The config file has multiple block handlers named EveryBlock for network id: \
+             1. Block handler names must be unique per network."
+        );
+
+        let error = make_network(serde_json::json!([
+            {"name": "EveryBlock", "handler": "./src/BlockHandlers.ts", "end_block": 50}
+        ]))
+        .validate_block_handlers()
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE115: The block handler EveryBlock for network id: 1 has an end_block that is less \
+             than its start_block. The end_block must be greater than the start_block."
+        );
+    }
+
+    #[test]
+    fn invalid_transaction_handlers() {
+        let make_network = |transactions: serde_json::Value| {
+            serde_json::from_value::<crate::config_parsing::human_config::evm::Network>(
+                serde_json::json!({
+                    "id": 1,
+                    "start_block": 100,
+                    "contracts": [],
+                    "transactions": transactions
+                }),
+            )
+            .unwrap()
+        };
+
+        let error = make_network(serde_json::json!([
+            {"name": "Transfers", "handler": "./src/TransactionHandlers.ts"},
+            {"name": "Transfers", "handler": "./src/TransactionHandlers.ts", "status": "success"}
+        ]))
+        .validate_transaction_handlers()
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE119: The config file has multiple transaction handlers named Transfers for network \
+             id: 1. Transaction handler names must be unique per network."
+        );
+
+        let error = make_network(serde_json::json!([
+            {"name": "Transfers", "handler": "./src/TransactionHandlers.ts", "to": ["0x123"]}
+        ]))
+        .validate_transaction_handlers()
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE120: The transaction handler Transfers for network id: 1 has an invalid address \
+             \"0x123\" in its from or to filter."
+        );
+
+        let error = make_network(serde_json::json!([
+            {"name": "Transfers", "handler": "./src/TransactionHandlers.ts", "function_selector": "0xa9059c"}
+        ]))
+        .validate_transaction_handlers()
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "EE121: The transaction handler Transfers for network id: 1 has an invalid \
+             function_selector \"0xa9059c\". It should be a 4-byte hex selector (eg. 0xa9059cbb) \
+             or a function signature (eg. transfer(address,uint256))."
+        );
+
+        assert!(make_network(serde_json::json!([
+            {
+                "name": "Transfers",
+                "handler": "./src/TransactionHandlers.ts",
+                "from": "0x2E645469f354BB4F5c8a05B3b30A929361cf77eC",
+                "function_selector": ["0xa9059cbb", "transferFrom(address,address,uint256)"]
+            }
+        ]))
+        .validate_transaction_handlers()
+        .is_ok());
+    }
 }
@@ -128,17 +128,29 @@ pub async fn run_dev(project_paths: ParsedProjectPaths) -> Result<()> {
                     PersistedStateExists::Corrupted => println!("Invalid DB persisted state"),
                     PersistedStateExists::Exists(_) => print_changes_detected(changes_detected),
                 }
-                println!("Running db migrations");
 
-                let should_drop_raw_events = !should_sync_from_raw_events;
-
-                commands::db_migrate::run_db_setup(
+                let ran_schema_migrations = commands::db_migrate::run_schema_migrations(
                     &project_paths,
-                    should_drop_raw_events,
+                    &config,
                     &current_state,
+                    &persisted_state_db,
                 )
                 .await
-                .context("Failed running db setup command")?;
+                .context("Failed running db schema migrations")?;
+
+                if !ran_schema_migrations {
+                    println!("Running db migrations");
+
+                    let should_drop_raw_events = !should_sync_from_raw_events;
+
+                    commands::db_migrate::run_db_setup(
+                        &project_paths,
+                        should_drop_raw_events,
+                        &current_state,
+                    )
+                    .await
+                    .context("Failed running db setup command")?;
+                }
             }
 
             if should_sync_from_raw_events {
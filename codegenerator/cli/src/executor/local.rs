@@ -2,7 +2,7 @@ use crate::{
     cli_args::clap_definitions::{DbMigrateSubcommands, LocalCommandTypes, LocalDockerSubcommands},
     commands,
     config_parsing::system_config::SystemConfig,
-    persisted_state::PersistedState,
+    persisted_state::{PersistedState, PersistedStateExists},
     project_paths::ParsedProjectPaths,
 };
 use anyhow::{Context, Result};
@@ -22,21 +22,37 @@ pub async fn run_local(
         },
         LocalCommandTypes::DbMigrate(subcommand) => {
             //Use a closure just so running local dow doesn't need to construct persisted state
-            let get_persisted_state = || -> Result<PersistedState> {
+            let get_config_and_persisted_state = || -> Result<(SystemConfig, PersistedState)> {
                 let config = SystemConfig::parse_from_project_files(&project_paths)
                     .context("Failed parsing config")?;
 
                 let persisted_state = PersistedState::get_current_state(&config)
                     .context("Failed constructing persisted state")?;
 
-                Ok(persisted_state)
+                Ok((config, persisted_state))
             };
 
             match subcommand {
                 DbMigrateSubcommands::Up => {
-                    let persisted_state = get_persisted_state()?;
-                    commands::db_migrate::run_up_migrations(&project_paths, &persisted_state)
-                        .await?;
+                    let (config, persisted_state) = get_config_and_persisted_state()?;
+                    let persisted_state_db = PersistedStateExists::read_from_db()
+                        .await
+                        .context("Failed to read persisted state from the DB")?;
+
+                    //Schema changes which keep the indexed data are applied like in envio dev
+                    let ran_schema_migrations = commands::db_migrate::run_schema_migrations(
+                        &project_paths,
+                        &config,
+                        &persisted_state,
+                        &persisted_state_db,
+                    )
+                    .await
+                    .context("Failed running db schema migrations")?;
+
+                    if !ran_schema_migrations {
+                        commands::db_migrate::run_up_migrations(&project_paths, &persisted_state)
+                            .await?;
+                    }
                 }
 
                 DbMigrateSubcommands::Down => {
@@ -44,7 +60,7 @@ pub async fn run_local(
                 }
 
                 DbMigrateSubcommands::Setup => {
-                    let persisted_state = get_persisted_state()?;
+                    let (_, persisted_state) = get_config_and_persisted_state()?;
                     const SHOULD_DROP_RAW_EVENTS: bool = true;
                     commands::db_migrate::run_db_setup(
                         &project_paths,
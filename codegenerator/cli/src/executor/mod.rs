@@ -1,8 +1,13 @@
 use crate::{
-    clap_definitions::{JsonSchema, Script},
+    clap_definitions::{GraphFormat, JsonSchema, SchemaSubcommands, Script},
     cli_args::clap_definitions::{CommandLineArgs, CommandType},
     commands,
-    config_parsing::{human_config, system_config::SystemConfig},
+    config_parsing::{
+        human_config,
+        schema_graph::{self, SchemaGraph},
+        system_config::SystemConfig,
+    },
+    hbs_templating::docs_templates::DocsTemplate,
     persisted_state::{PersistedState, PersistedStateExists, CURRENT_CRATE_VERSION},
     project_paths::ParsedProjectPaths,
     scripts,
@@ -65,13 +70,13 @@ pub async fn execute(command_line_args: CommandLineArgs) -> Result<()> {
                 std::env::set_var("ENVIO_SAVE_BENCHMARK_DATA", "true");
             }
 
-            if start_args.restart {
-                let config = SystemConfig::parse_from_project_files(&parsed_project_paths)
-                    .context("Failed parsing config")?;
+            let config = SystemConfig::parse_from_project_files(&parsed_project_paths)
+                .context("Failed parsing config")?;
 
-                let persisted_state = PersistedState::get_current_state(&config)
-                    .context("Failed constructing persisted state")?;
+            let persisted_state = PersistedState::get_current_state(&config)
+                .context("Failed constructing persisted state")?;
 
+            if start_args.restart {
                 const SHOULD_DROP_RAW_EVENTS: bool = true;
 
                 commands::db_migrate::run_db_setup(
@@ -80,6 +85,20 @@ pub async fn execute(command_line_args: CommandLineArgs) -> Result<()> {
                     &persisted_state,
                 )
                 .await?;
+            } else {
+                //Apply the schema changes which keep the indexed data, like envio dev
+                let persisted_state_db = PersistedStateExists::read_from_db()
+                    .await
+                    .context("Failed to read persisted state from the DB")?;
+
+                commands::db_migrate::run_schema_migrations(
+                    &parsed_project_paths,
+                    &config,
+                    &persisted_state,
+                    &persisted_state_db,
+                )
+                .await
+                .context("Failed running db schema migrations")?;
             }
             const SHOULD_SYNC_FROM_RAW_EVENTS: bool = false;
             const SHOULD_OPEN_HASURA: bool = false;
@@ -95,6 +114,40 @@ pub async fn execute(command_line_args: CommandLineArgs) -> Result<()> {
             local::run_local(&local_commands, &parsed_project_paths).await?;
         }
 
+        CommandType::Schema(SchemaSubcommands::Graph(graph_args)) => {
+            let config = SystemConfig::parse_from_project_files(&parsed_project_paths)
+                .context("Failed parsing config")?;
+
+            let event_writes = if graph_args.events {
+                schema_graph::read_event_entity_writes(&config)
+                    .context("Failed finding entities written by event handlers")?
+            } else {
+                vec![]
+            };
+
+            let graph = SchemaGraph::new(&config.schema, event_writes);
+            let output = match graph_args.format {
+                GraphFormat::Mermaid => graph.to_mermaid(),
+                GraphFormat::Dot => graph.to_dot(),
+            }
+            .context("Failed rendering schema graph")?;
+            println!("{output}");
+        }
+
+        CommandType::Docs(docs_args) => {
+            let config = SystemConfig::parse_from_project_files(&parsed_project_paths)
+                .context("Failed parsing config")?;
+
+            let docs_dir = parsed_project_paths
+                .project_root
+                .join(&docs_args.docs_directory);
+            DocsTemplate::from_config(&config)
+                .context("Failed creating docs template")?
+                .generate_templates(&docs_dir)
+                .context("Failed generating docs")?;
+            println!("Docs written to {}", docs_dir.display());
+        }
+
         CommandType::BenchmarkSummary => {
             commands::benchmark::print_summary(&parsed_project_paths).await?
         }
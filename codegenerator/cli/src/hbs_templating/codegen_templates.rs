@@ -3,8 +3,13 @@ use std::{collections::HashMap, collections::HashSet, path::PathBuf, vec};
 use super::hbs_dir_generator::HandleBarsDirGenerator;
 use crate::{
     config_parsing::{
-        entity_parsing::{Entity, Field, GraphQLEnum, MultiFieldIndex, Schema},
+        entity_parsing::{
+            AggregateFn, Aggregation, AggregationInterval, CustomScalar, Entity, Field,
+            FullTextSearch, GqlScalar, GraphQLEnum, IndexMethod, IndexOrder, MultiFieldIndex,
+            Schema,
+        },
         event_parsing::{abi_to_rescript_type, EthereumEventParam},
+        human_config::evm::TransactionStatus,
         postgres_types,
         system_config::{
             self, Abi, Ecosystem, EventKind, FuelEventKind, HyperfuelConfig, HypersyncConfig,
@@ -23,7 +28,7 @@ use crate::{
     utils::text::{Capitalize, CapitalizedOptions, CaseOptions},
 };
 use anyhow::{anyhow, Context, Result};
-use ethers::abi::EventParam;
+use ethers::abi::{EventParam, Param, ParamType};
 use pathdiff::diff_paths;
 use serde::Serialize;
 
@@ -67,6 +72,39 @@ impl GraphQlEnumTypeTemplate {
     }
 }
 
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct GraphQlScalarTypeTemplate {
+    pub name: CapitalizedOptions,
+    pub res_type: String,
+    pub res_schema: String,
+    pub default_value: String,
+    ///Regex serialized as a Rescript string literal
+    pub pattern: Option<String>,
+}
+
+impl GraphQlScalarTypeTemplate {
+    fn from_config_gql_scalar(gql_scalar: &CustomScalar, schema: &Schema) -> Result<Self> {
+        let res_type = gql_scalar.get_base_rescript_type(schema).context(format!(
+            "Failed getting rescript type of scalar: {}",
+            gql_scalar.name
+        ))?;
+        let pattern = gql_scalar
+            .pattern
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .context("Failed serializing scalar pattern")?;
+
+        Ok(GraphQlScalarTypeTemplate {
+            name: gql_scalar.name.to_capitalized_options(),
+            res_type: res_type.to_string(),
+            res_schema: res_type.to_rescript_schema(&RescriptSchemaMode::ForDb),
+            default_value: res_type.get_default_value_rescript(),
+            pattern,
+        })
+    }
+}
+
 #[derive(Serialize, Debug, PartialEq, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum RelationshipTypeTemplate {
@@ -149,6 +187,10 @@ impl<T: HasIsDerivedFrom + Clone> FilteredTemplateLists<T> {
 #[derive(Serialize, Debug, PartialEq, Clone)]
 pub struct EntityParamTypeTemplate {
     pub field_name: CapitalizedOptions,
+    ///Name of the record field, with reserved words escaped
+    pub res_name: String,
+    ///Original key of the record field when it differs from res_name
+    pub as_name: Option<String>,
     pub res_type: RescriptTypeIdent,
     pub res_schema_code: String,
     pub is_entity_field: bool,
@@ -159,6 +201,31 @@ pub struct EntityParamTypeTemplate {
     ///Used to determine if you can run a where
     ///query on this field.
     pub is_queryable_field: bool,
+    ///Content of the doc comment on the generated record field
+    pub doc_comment: Option<String>,
+}
+
+///Content of a generated /** */ doc comment. The closing token is escaped so
+///a description can't end the comment early.
+fn to_doc_comment(
+    description: &Option<String>,
+    deprecation_reason: &Option<String>,
+) -> Option<String> {
+    let lines = description
+        .iter()
+        .cloned()
+        .chain(
+            deprecation_reason
+                .iter()
+                .map(|reason| format!("@deprecated {reason}")),
+        )
+        .collect::<Vec<_>>();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n").replace("*/", "*\\/"))
+    }
 }
 
 impl HasIsDerivedFrom for EntityParamTypeTemplate {
@@ -186,14 +253,25 @@ impl EntityParamTypeTemplate {
         //Both of these cases have indexes on them and should exist
         let is_queryable_field = is_indexed_field || is_derived_lookup_field;
 
+        let field_name = field.name.to_capitalized_options();
+        let key = if is_entity_field {
+            format!("{}_id", field_name.uncapitalized)
+        } else {
+            field_name.uncapitalized.clone()
+        };
+        let res_name = RescriptRecordField::to_valid_res_name(&key);
+
         Ok(EntityParamTypeTemplate {
-            field_name: field.name.to_capitalized_options(),
+            as_name: if res_name == key { None } else { Some(key) },
+            res_name,
+            field_name,
             res_schema_code: res_type.to_rescript_schema(&RescriptSchemaMode::ForDb),
             res_type,
             is_derived_from,
             is_entity_field,
             is_indexed_field,
             is_queryable_field,
+            doc_comment: to_doc_comment(&field.description, &field.deprecation_reason),
         })
     }
 }
@@ -216,16 +294,188 @@ pub struct DerivedFieldTemplate {
     pub derived_from_field: String,
 }
 
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct CompositeIndexTemplate {
+    pub fields: Vec<String>,
+    ///Rescript code for the optional Table.indexMethod variant
+    pub method: Option<String>,
+    ///Rescript code for the optional Table.indexOrder variant
+    pub order: Option<String>,
+    ///Rescript string literal with the partial index predicate
+    pub where_predicate: Option<String>,
+}
+
+impl CompositeIndexTemplate {
+    fn from_multi_field_index(multi_field_index: &MultiFieldIndex) -> Result<Self> {
+        let options = multi_field_index.get_options();
+        let method = options.method.map(|method| {
+            match method {
+                IndexMethod::Btree => "Btree",
+                IndexMethod::Hash => "Hash",
+                IndexMethod::Gin => "Gin",
+                IndexMethod::Brin => "Brin",
+            }
+            .to_string()
+        });
+        let order = options.order.map(|order| {
+            match order {
+                IndexOrder::Asc => "Asc",
+                IndexOrder::Desc => "Desc",
+            }
+            .to_string()
+        });
+        let where_predicate = options
+            .where_predicate
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .context("Failed serializing index where predicate")?;
+
+        Ok(CompositeIndexTemplate {
+            fields: multi_field_index.get_field_names().clone(),
+            method,
+            order,
+            where_predicate,
+        })
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct AggregateTemplate {
+    pub field_name: String,
+    ///Rescript code for the Table.aggregateFn variant
+    pub function: String,
+    pub arg: Option<String>,
+}
+
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct AggregationTemplate {
+    pub source: String,
+    ///Rescript code for the Table.aggregationInterval variants
+    pub intervals: Vec<String>,
+    pub dimensions: Vec<String>,
+    pub aggregates: Vec<AggregateTemplate>,
+}
+
+impl AggregationTemplate {
+    fn from_aggregation(
+        aggregation: &Aggregation,
+        entity: &Entity,
+        schema: &Schema,
+    ) -> Result<Self> {
+        //Field names as they are stored in the db, linked entities have an _id suffix
+        let get_db_field_name = |entity: &Entity, field_name: &String| -> Result<String> {
+            let field = entity.fields.get(field_name).ok_or_else(|| {
+                anyhow!(
+                    "Unexpected, field {field_name} does not exist on entity {}",
+                    entity.name
+                )
+            })?;
+            Ok(if field.field_type.is_entity_field(schema)? {
+                format!("{field_name}_id")
+            } else {
+                field_name.clone()
+            })
+        };
+
+        let source = schema.entities.get(&aggregation.source).ok_or_else(|| {
+            anyhow!(
+                "Unexpected, aggregation source {} does not exist",
+                aggregation.source
+            )
+        })?;
+
+        let intervals = aggregation
+            .intervals
+            .iter()
+            .map(|interval| {
+                match interval {
+                    AggregationInterval::Hour => "Hour",
+                    AggregationInterval::Day => "Day",
+                }
+                .to_string()
+            })
+            .collect();
+
+        let dimensions = aggregation
+            .get_dimension_fields(entity)
+            .into_iter()
+            .map(|field| get_db_field_name(entity, &field.name))
+            .collect::<Result<_>>()?;
+
+        let aggregates = aggregation
+            .aggregates
+            .iter()
+            .map(|aggregate| {
+                Ok(AggregateTemplate {
+                    field_name: get_db_field_name(entity, &aggregate.field_name)?,
+                    function: match aggregate.function {
+                        AggregateFn::Sum => "Sum",
+                        AggregateFn::Count => "Count",
+                        AggregateFn::Min => "Min",
+                        AggregateFn::Max => "Max",
+                        AggregateFn::First => "First",
+                        AggregateFn::Last => "Last",
+                    }
+                    .to_string(),
+                    arg: aggregate
+                        .arg
+                        .as_ref()
+                        .map(|arg| get_db_field_name(source, arg))
+                        .transpose()?,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(AggregationTemplate {
+            source: aggregation.source.clone(),
+            intervals,
+            dimensions,
+            aggregates,
+        })
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct FullTextSearchTemplate {
+    pub name: String,
+    pub fields: Vec<String>,
+    pub language: String,
+}
+
+impl FullTextSearchTemplate {
+    fn from_full_text_search(full_text_search: &FullTextSearch) -> Self {
+        FullTextSearchTemplate {
+            name: full_text_search.name.clone(),
+            fields: full_text_search.fields.clone(),
+            language: full_text_search.language.clone(),
+        }
+    }
+}
+
 #[derive(Serialize, Debug, PartialEq, Clone)]
 pub struct EntityRecordTypeTemplate {
     pub name: CapitalizedOptions,
+    ///Uncapitalized name with reserved words escaped, for record fields and types
+    pub res_name: String,
+    ///Rescript type of the entity id
+    pub id_res_type: String,
+    ///Rescript code converting an `id` of id_res_type to the string key of the in memory store
+    pub id_to_key_code: String,
     pub postgres_fields: Vec<postgres_types::Field>,
-    pub composite_indices: Vec<Vec<String>>,
+    pub composite_indices: Vec<CompositeIndexTemplate>,
     pub derived_fields: Vec<DerivedFieldTemplate>,
     pub params: Vec<EntityParamTypeTemplate>,
     pub index_groups: Vec<EntityIndexParamGroup>,
     pub relational_params: FilteredTemplateLists<EntityRelationalTypesTemplate>,
     pub filtered_params: FilteredTemplateLists<EntityParamTypeTemplate>,
+    pub is_immutable: bool,
+    pub aggregation: Option<AggregationTemplate>,
+    pub full_text_searches: Vec<FullTextSearchTemplate>,
+    pub doc_comment: Option<String>,
+    ///Rescript string literal with the table comment
+    pub description: Option<String>,
+    pub renamed_from: Option<String>,
 }
 
 impl EntityRecordTypeTemplate {
@@ -314,10 +564,64 @@ impl EntityRecordTypeTemplate {
             .filter_map(|gql_field| gql_field.get_derived_from_field())
             .collect();
 
-        let composite_indices = entity.get_composite_indices();
+        let composite_indices = entity
+            .get_composite_indices()
+            .into_iter()
+            .map(CompositeIndexTemplate::from_multi_field_index)
+            .collect::<Result<Vec<_>>>()
+            .context(format!(
+                "Failed templating composite indices of entity: {}",
+                entity.name
+            ))?;
+
+        let aggregation = entity
+            .aggregation
+            .as_ref()
+            .map(|aggregation| {
+                AggregationTemplate::from_aggregation(aggregation, entity, &config.schema)
+            })
+            .transpose()
+            .context(format!(
+                "Failed templating aggregation of entity: {}",
+                entity.name
+            ))?;
+
+        let id_res_type = entity
+            .get_id_rescript_type(&config.schema)
+            .context(format!(
+                "Failed getting the id type of entity: {}",
+                entity.name
+            ))?
+            .to_string();
+
+        let id_to_key_code = match (&entity.composite_key, entity.get_id_scalar()) {
+            (Some(_), _) => "id->Table.makeCompositeKey",
+            (None, GqlScalar::Int8) => "id->BigInt.toString",
+            (None, _) => "id",
+        }
+        .to_string();
+
+        let get_id_code = match &entity.composite_key {
+            Some(composite_key) => format!(
+                "({})",
+                composite_key
+                    .iter()
+                    .map(|field_name| format!(
+                        "entity.{}",
+                        RescriptRecordField::to_valid_res_name(field_name)
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            None => "entity.id".to_string(),
+        };
 
         Ok(EntityRecordTypeTemplate {
             name: entity.name.to_capitalized_options(),
+            res_name: RescriptRecordField::to_valid_res_name(&entity.name),
+            id_res_type,
+            id_to_key_code,
+            get_id_code,
             postgres_fields,
             derived_fields,
             composite_indices,
@@ -325,6 +629,21 @@ impl EntityRecordTypeTemplate {
             index_groups,
             relational_params,
             filtered_params,
+            is_immutable: entity.is_immutable,
+            aggregation,
+            full_text_searches: entity
+                .full_text_searches
+                .iter()
+                .map(FullTextSearchTemplate::from_full_text_search)
+                .collect(),
+            doc_comment: to_doc_comment(&entity.description, &None),
+            description: entity
+                .description
+                .as_ref()
+                .map(serde_json::to_string)
+                .transpose()
+                .context("Failed serializing entity description")?,
+            renamed_from: entity.renamed_from.clone(),
         })
     }
 }
@@ -339,11 +658,22 @@ pub struct EventMod {
     pub convert_hyper_sync_event_args_code: String,
     pub event_filter_type: String,
     pub get_topic_selection_code: String,
+    ///Event filters from the config, which are applied to every topic selection of the event
+    pub config_event_filters_code: Option<String>,
     pub custom_field_selection: Option<system_config::FieldSelection>,
     pub fuel_event_kind: Option<FuelEventKind>,
+    pub anonymous_inputs: Option<Vec<EventParam>>,
+    pub is_wildcard: bool,
 }
 
 impl EventMod {
+    fn get_anonymous_event_id(anonymous_inputs: &Vec<EventParam>) -> String {
+        format!(
+            "anonymous_{}",
+            system_config::Event::get_anonymous_shape(anonymous_inputs)
+        )
+    }
+
     fn to_string(&self) -> String {
         let sighash = &self.sighash;
         let topic_count = &self.topic_count;
@@ -368,13 +698,26 @@ impl EventMod {
             )),
         };
 
-        let event_id = match self.fuel_event_kind {
-            None => format!("{sighash}_{topic_count}"),
-            Some(FuelEventKind::Mint) => "mint".to_string(),
-            Some(FuelEventKind::Burn) => "burn".to_string(),
-            Some(FuelEventKind::Call) => "call".to_string(),
-            Some(FuelEventKind::Transfer) => "transfer".to_string(),
-            Some(FuelEventKind::LogData(_)) => sighash.to_string(),
+        let event_id = match (&self.fuel_event_kind, &self.anonymous_inputs) {
+            (None, Some(anonymous_inputs)) => Self::get_anonymous_event_id(anonymous_inputs),
+            (None, None) => format!("{sighash}_{topic_count}"),
+            (Some(FuelEventKind::Mint), _) => "mint".to_string(),
+            (Some(FuelEventKind::Burn), _) => "burn".to_string(),
+            (Some(FuelEventKind::Call), _) => "call".to_string(),
+            (Some(FuelEventKind::Transfer), _) => "transfer".to_string(),
+            (Some(FuelEventKind::LogData(_)), _) => sighash.to_string(),
+        };
+
+        let (anonymous_inputs_code, is_anonymous_arg) = match &self.anonymous_inputs {
+            None => ("None".to_string(), ""),
+            Some(anonymous_inputs) => (
+                format!(
+                    "Some(%raw(`{}`))",
+                    serde_json::to_string(anonymous_inputs)
+                        .expect("event params should be serializable")
+                ),
+                "\n  ~isAnonymous=true,",
+            ),
         };
 
         let (block_type, block_schema, transaction_type, transaction_schema) =
@@ -401,6 +744,27 @@ impl EventMod {
                 ),
             };
 
+        //Wildcard events from the config are registered with the wildcard option by default
+        let is_wildcard_arg = if self.is_wildcard {
+            "\n  ~isWildcard=true,"
+        } else {
+            ""
+        };
+
+        let topic_selection_code = match &self.config_event_filters_code {
+            None => format!("let getTopicSelection = {get_topic_selection_code}"),
+            Some(config_event_filters_code) => format!(
+                r#"let getFilterTopicSelection = {get_topic_selection_code}
+let configTopicSelections = {config_event_filters_code}->getFilterTopicSelection
+let getTopicSelection = eventFilters =>
+  switch eventFilters->getFilterTopicSelection->LogSelection.intersectTopicSelections(configTopicSelections) {{
+  | [] => Js.Exn.raiseError(`The eventFilters of the ${{contractName}}.${{name}} handler don't match any of the filters of the event in config.yaml`)
+  | topicSelections => topicSelections
+  }}
+handlerRegister->HandlerTypes.Register.setDefaultTopicSelections(configTopicSelections)"#
+            ),
+        };
+
         let non_event_mod_code = match fuel_event_kind_code {
             None => "".to_string(),
             Some(fuel_event_kind_code) => format!(
@@ -446,9 +810,10 @@ let blockSchema = {block_schema}
 let transactionSchema = {transaction_schema}
 
 let convertHyperSyncEventArgs = {convert_hyper_sync_event_args_code}
+let anonymousInputs: option<array<Viem.abiParameter>> = {anonymous_inputs_code}
 
 let handlerRegister: HandlerTypes.Register.t = HandlerTypes.Register.make(
-  ~topic0=sighash->EvmTypes.Hex.fromStringUnsafe,
+  ~topic0=sighash->EvmTypes.Hex.fromStringUnsafe,{is_anonymous_arg}{is_wildcard_arg}
   ~contractName,
   ~eventName=name,
 )
@@ -456,7 +821,7 @@ let handlerRegister: HandlerTypes.Register.t = HandlerTypes.Register.make(
 @genType
 type eventFilter = {event_filter_type}
 
-let getTopicSelection = {get_topic_selection_code}
+{topic_selection_code}
 {non_event_mod_code}"#
         )
     }
@@ -496,7 +861,10 @@ impl EventTemplate {
         format!("{{ {field_rows} }}")
     }
 
-    pub fn generate_get_topic_selection_code(params: &Vec<EventParam>) -> String {
+    pub fn generate_get_topic_selection_code(
+        params: &Vec<EventParam>,
+        is_anonymous: bool,
+    ) -> String {
         let indexed_params = params.iter().filter(|param| param.indexed);
 
         //Prefixed with underscore for cases where it is not used to avoid compiler warnings
@@ -506,7 +874,9 @@ impl EventTemplate {
             .enumerate()
             .map(|(i, param)| {
                 let param = EthereumEventParam::from(param);
-                let topic_number = i + 1;
+                //Anonymous events don't have the sighash as topic0,
+                //so the indexed params start from it
+                let topic_number = if is_anonymous { i } else { i + 1 };
                 let param_name = RescriptRecordField::to_valid_res_name(param.name);
                 let topic_encoder = param.get_topic_encoder();
                 let nested_type_flags = match param.get_nested_type_depth() {
@@ -522,14 +892,72 @@ impl EventTemplate {
             })
             .collect::<String>();
 
+        let make_topic_selection_call = if is_anonymous {
+            format!("LogSelection.makeAnonymousTopicSelection({topic_filter_calls})")
+        } else {
+            format!(
+                "LogSelection.makeTopicSelection(~topic0=[sighash->EvmTypes.Hex.\
+                 fromStringUnsafe], {topic_filter_calls})->Utils.unwrapResultExn"
+            )
+        };
+
         format!(
             "(eventFilters) => \
              eventFilters->SingleOrMultiple.normalizeOrThrow->Belt.Array.map({event_filter_arg} \
-             => LogSelection.makeTopicSelection(~topic0=[sighash->EvmTypes.Hex.fromStringUnsafe], \
-             {topic_filter_calls})->Utils.unwrapResultExn)"
+             => {make_topic_selection_call})"
         )
     }
 
+    fn generate_config_event_filter_value_code(kind: &ParamType, value: &str) -> String {
+        match kind {
+            ParamType::Address => format!("\"{value}\"->Address.Evm.fromStringOrThrow"),
+            ParamType::Uint(_) | ParamType::Int(_) => {
+                format!("BigInt.fromStringUnsafe(\"{value}\")")
+            }
+            ParamType::Bool => value.to_string(),
+            _ => format!("\"{value}\""),
+        }
+    }
+
+    pub fn generate_config_event_filters_code(
+        filters: &[Vec<system_config::EventFilterCondition>],
+    ) -> Option<String> {
+        if filters.is_empty() {
+            return None;
+        }
+
+        let filters_code = filters
+            .iter()
+            .map(|conditions| {
+                let fields_code = conditions
+                    .iter()
+                    .map(|condition| {
+                        let values_code = condition
+                            .values
+                            .iter()
+                            .map(|value| {
+                                Self::generate_config_event_filter_value_code(
+                                    &condition.param.kind,
+                                    value,
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        format!(
+                            "{}: SingleOrMultiple.multiple([{values_code}])",
+                            RescriptRecordField::to_valid_res_name(&condition.param.name)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{{{fields_code}}}")
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Some(format!("SingleOrMultiple.multiple([{filters_code}])"))
+    }
+
     pub fn generate_convert_hyper_sync_event_args_code(params: &Vec<EventParam>) -> String {
         if params.is_empty() {
             return Self::CONVERT_HYPER_SYNC_EVENT_ARGS_NOOP.to_string();
@@ -588,8 +1016,11 @@ impl EventTemplate {
                 .to_string(),
             event_filter_type: Self::EVENT_FILTER_TYPE_STUB.to_string(),
             get_topic_selection_code: Self::GET_TOPIC_SELECTION_CODE_STUB.to_string(),
+            config_event_filters_code: None,
             custom_field_selection: config_event.field_selection.clone(),
             fuel_event_kind: Some(fuel_event_kind),
+            anonymous_inputs: None,
+            is_wildcard: config_event.is_wildcard,
         };
         EventTemplate {
             name: event_name,
@@ -613,8 +1044,11 @@ impl EventTemplate {
                 .to_string(),
             event_filter_type: Self::EVENT_FILTER_TYPE_STUB.to_string(),
             get_topic_selection_code: Self::GET_TOPIC_SELECTION_CODE_STUB.to_string(),
+            config_event_filters_code: None,
             custom_field_selection: config_event.field_selection.clone(),
             fuel_event_kind: Some(fuel_event_kind),
+            anonymous_inputs: None,
+            is_wildcard: config_event.is_wildcard,
         };
         EventTemplate {
             name: event_name,
@@ -661,9 +1095,11 @@ impl EventTemplate {
 
                 let event_mod = EventMod {
                     sighash: config_event.sighash.to_string(),
-                    topic_count: params
-                        .iter()
-                        .fold(1, |acc, param| if param.indexed { acc + 1 } else { acc }),
+                    //Anonymous events don't have the topic0 with the sighash
+                    topic_count: params.iter().fold(
+                        if config_event.is_anonymous { 0 } else { 1 },
+                        |acc, param| if param.indexed { acc + 1 } else { acc },
+                    ),
                     event_name: event_name.clone(),
                     data_type: data_type_expr.to_string(),
                     params_raw_event_schema: data_type_expr
@@ -671,9 +1107,21 @@ impl EventTemplate {
                     convert_hyper_sync_event_args_code:
                         Self::generate_convert_hyper_sync_event_args_code(params),
                     event_filter_type: Self::generate_event_filter_type(params),
-                    get_topic_selection_code: Self::generate_get_topic_selection_code(params),
+                    get_topic_selection_code: Self::generate_get_topic_selection_code(
+                        params,
+                        config_event.is_anonymous,
+                    ),
+                    config_event_filters_code: Self::generate_config_event_filters_code(
+                        &config_event.filters,
+                    ),
                     custom_field_selection: config_event.field_selection.clone(),
                     fuel_event_kind: None,
+                    anonymous_inputs: if config_event.is_anonymous {
+                        Some(params.clone())
+                    } else {
+                        None
+                    },
+                    is_wildcard: config_event.is_wildcard,
                 };
 
                 Ok(EventTemplate {
@@ -700,8 +1148,11 @@ impl EventTemplate {
                             event_filter_type: Self::EVENT_FILTER_TYPE_STUB.to_string(),
                             get_topic_selection_code: Self::GET_TOPIC_SELECTION_CODE_STUB
                                 .to_string(),
+                            config_event_filters_code: None,
                             custom_field_selection: config_event.field_selection.clone(),
                             fuel_event_kind: Some(fuel_event_kind),
+                            anonymous_inputs: None,
+                            is_wildcard: config_event.is_wildcard,
                         };
 
                         Ok(EventTemplate {
@@ -726,6 +1177,7 @@ impl EventTemplate {
 pub struct ContractTemplate {
     pub name: CapitalizedOptions,
     pub codegen_events: Vec<EventTemplate>,
+    pub codegen_calls: Vec<CallTemplate>,
     pub chain_ids: Vec<u64>,
     pub module_code: String,
     pub handler: HandlerPathsTemplate,
@@ -745,6 +1197,15 @@ impl ContractTemplate {
             .iter()
             .map(|event| EventTemplate::from_config_event(event))
             .collect::<Result<_>>()?;
+        let codegen_calls = contract
+            .calls
+            .iter()
+            .map(CallTemplate::from_config_call)
+            .collect::<Result<_>>()
+            .context(format!(
+                "Failed templating calls of contract: {}",
+                contract.name
+            ))?;
 
         let module_code = match &contract.abi {
             Abi::Evm(abi) => {
@@ -785,12 +1246,208 @@ let eventSignatures = [{}]"#,
             name,
             handler,
             codegen_events,
+            codegen_calls,
             chain_ids,
             module_code,
         })
     }
 }
 
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct CallTemplate {
+    pub name: CapitalizedOptions,
+    pub module_code: String,
+}
+
+impl CallTemplate {
+    fn params_to_type_expr(params: &[Param]) -> RescriptTypeExpr {
+        if params.is_empty() {
+            RescriptTypeExpr::Identifier(RescriptTypeIdent::Unit)
+        } else {
+            RescriptTypeExpr::Record(
+                params
+                    .iter()
+                    .map(|p| {
+                        RescriptRecordField::new(
+                            p.name.to_string(),
+                            abi_to_rescript_type(&p.into()),
+                        )
+                    })
+                    .collect(),
+            )
+        }
+    }
+
+    fn from_config_call(call: &system_config::Call) -> Result<Self> {
+        let name = call.name.to_capitalized_options();
+        let inputs_type = Self::params_to_type_expr(&call.inputs).to_string();
+        let outputs_type = Self::params_to_type_expr(&call.outputs).to_string();
+        let inputs_abi =
+            serde_json::to_string(&call.inputs).context("Failed serializing call inputs")?;
+        let outputs_abi =
+            serde_json::to_string(&call.outputs).context("Failed serializing call outputs")?;
+
+        let module_code = format!(
+            r#"let name = "{}"
+let contractName = contractName
+let functionSelector = "{}"
+
+@genType
+type inputs = {inputs_type}
+@genType
+type outputs = {outputs_type}
+
+let inputsAbi: array<Viem.abiParameter> = %raw(`{inputs_abi}`)
+let outputsAbi: array<Viem.abiParameter> = %raw(`{outputs_abi}`)
+
+let handlerRegister: HandlerTypes.CallRegister.t = HandlerTypes.CallRegister.make(
+  ~contractName,
+  ~callName=name,
+)"#,
+            call.name, call.function_selector,
+        );
+
+        Ok(CallTemplate { name, module_code })
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct BlockHandlerTemplate {
+    pub name: CapitalizedOptions,
+    pub module_code: String,
+    pub handler: HandlerPathsTemplate,
+}
+
+impl BlockHandlerTemplate {
+    fn generate_module_code(name: &CapitalizedOptions) -> String {
+        format!(
+            r#"let name = "{}"
+let handlerRegister: HandlerTypes.BlockRegister.t = HandlerTypes.BlockRegister.make(
+  ~blockHandlerName=name,
+)"#,
+            name.capitalized
+        )
+    }
+
+    fn from_config_block_handler(
+        block_handler: &system_config::BlockHandler,
+        project_paths: &ParsedProjectPaths,
+    ) -> Result<Self> {
+        let name = block_handler.name.to_capitalized_options();
+        let handler = HandlerPathsTemplate::from_block_handler(block_handler, project_paths)
+            .context("Failed building handler paths template")?;
+        let module_code = Self::generate_module_code(&name);
+
+        Ok(BlockHandlerTemplate {
+            name,
+            module_code,
+            handler,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Clone)]
+pub struct PerNetworkBlockHandlerTemplate {
+    name: CapitalizedOptions,
+    interval: u64,
+    start_block: u64,
+    end_block: Option<u64>,
+}
+
+impl PerNetworkBlockHandlerTemplate {
+    fn from_config_block_handler(block_handler: &system_config::BlockHandler) -> Self {
+        PerNetworkBlockHandlerTemplate {
+            name: block_handler.name.to_capitalized_options(),
+            interval: block_handler.interval,
+            start_block: block_handler.start_block,
+            end_block: block_handler.end_block,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct TransactionHandlerTemplate {
+    pub name: CapitalizedOptions,
+    pub module_code: String,
+    pub handler: HandlerPathsTemplate,
+}
+
+impl TransactionHandlerTemplate {
+    fn generate_module_code(
+        name: &CapitalizedOptions,
+        transaction_fields: &[SelectedField],
+    ) -> String {
+        let field_selection = FieldSelection::new(FieldSelectionOptions {
+            transaction_fields: transaction_fields.to_vec(),
+            block_fields: vec![],
+            transaction_type_name: "transaction".to_string(),
+            block_type_name: "block".to_string(),
+        });
+
+        format!(
+            r#"let name = "{}"
+
+@genType
+type transaction = {}
+
+let transactionSchema = {}
+
+let handlerRegister: HandlerTypes.TransactionRegister.t = HandlerTypes.TransactionRegister.make(
+  ~transactionHandlerName=name,
+)"#,
+            name.capitalized, field_selection.transaction_type, field_selection.transaction_schema
+        )
+    }
+
+    fn from_config_transaction_handler(
+        transaction_handler: &system_config::TransactionHandler,
+        project_paths: &ParsedProjectPaths,
+    ) -> Result<Self> {
+        let name = transaction_handler.name.to_capitalized_options();
+        let handler =
+            HandlerPathsTemplate::from_transaction_handler(transaction_handler, project_paths)
+                .context("Failed building handler paths template")?;
+        let module_code =
+            Self::generate_module_code(&name, &transaction_handler.transaction_fields);
+
+        Ok(TransactionHandlerTemplate {
+            name,
+            module_code,
+            handler,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Clone)]
+pub struct PerNetworkTransactionHandlerTemplate {
+    name: CapitalizedOptions,
+    from: Vec<EthAddress>,
+    to: Vec<EthAddress>,
+    function_selectors: Vec<String>,
+    ///The Config.transactionStatus variant name
+    status: Option<String>,
+}
+
+impl PerNetworkTransactionHandlerTemplate {
+    fn from_config_transaction_handler(
+        transaction_handler: &system_config::TransactionHandler,
+    ) -> Self {
+        PerNetworkTransactionHandlerTemplate {
+            name: transaction_handler.name.to_capitalized_options(),
+            from: transaction_handler.from.clone(),
+            to: transaction_handler.to.clone(),
+            function_selectors: transaction_handler.function_selectors.clone(),
+            status: transaction_handler.status.as_ref().map(|status| {
+                match status {
+                    TransactionStatus::Success => "Success",
+                    TransactionStatus::Reverted => "Reverted",
+                }
+                .to_string()
+            }),
+        }
+    }
+}
+
 #[derive(Serialize, Debug, PartialEq, Clone)]
 pub struct PerNetworkContractEventTemplate {
     pub name: String,
@@ -804,11 +1461,51 @@ impl PerNetworkContractEventTemplate {
     }
 }
 
+#[derive(Debug, Serialize, PartialEq, Clone)]
+pub struct PerNetworkFactoryTemplate {
+    contract_name: CapitalizedOptions,
+    event_name: String,
+    address_param: String,
+    is_address_array: bool,
+}
+
+impl PerNetworkFactoryTemplate {
+    fn from_config_factory(factory: &system_config::Factory) -> Self {
+        PerNetworkFactoryTemplate {
+            contract_name: factory.contract_name.to_capitalized_options(),
+            event_name: factory.event_name.capitalize(),
+            address_param: RescriptRecordField::to_valid_res_name(&factory.address_param),
+            is_address_array: factory.is_address_array,
+        }
+    }
+}
+
+///Handlebars treats 0 as falsy, so the block options are rendered as ReScript code
+fn get_block_option_code(block: Option<u64>) -> String {
+    match block {
+        Some(block) => format!("Some({block})"),
+        None => "None".to_string(),
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Clone)]
+pub struct PerNetworkEventBlockRangeTemplate {
+    event_name: String,
+    start_block_code: String,
+    end_block_code: String,
+}
+
 #[derive(Debug, Serialize, PartialEq, Clone)]
 pub struct PerNetworkContractTemplate {
     name: CapitalizedOptions,
     addresses: Vec<EthAddress>,
     events: Vec<PerNetworkContractEventTemplate>,
+    calls: Vec<PerNetworkContractEventTemplate>,
+    factory: Option<PerNetworkFactoryTemplate>,
+    start_block_code: String,
+    end_block_code: String,
+    //Only for the events overriding the block range of the contract
+    event_block_ranges: Vec<PerNetworkEventBlockRangeTemplate>,
 }
 
 impl PerNetworkContractTemplate {
@@ -826,10 +1523,35 @@ impl PerNetworkContractTemplate {
             .map(|event| PerNetworkContractEventTemplate::new(event.name.clone()))
             .collect();
 
+        let calls = contract
+            .calls
+            .iter()
+            .map(|call| PerNetworkContractEventTemplate::new(call.name.clone()))
+            .collect();
+
+        let event_block_ranges = contract
+            .events
+            .iter()
+            .filter(|event| event.start_block.is_some() || event.end_block.is_some())
+            .map(|event| PerNetworkEventBlockRangeTemplate {
+                event_name: event.name.capitalize(),
+                start_block_code: get_block_option_code(event.start_block),
+                end_block_code: get_block_option_code(event.end_block),
+            })
+            .collect();
+
         Ok(PerNetworkContractTemplate {
             name: network_contract.name.to_capitalized_options(),
             addresses: network_contract.addresses.clone(),
             events,
+            calls,
+            factory: network_contract
+                .factory
+                .as_ref()
+                .map(PerNetworkFactoryTemplate::from_config_factory),
+            start_block_code: get_block_option_code(network_contract.start_block),
+            end_block_code: get_block_option_code(network_contract.end_block),
+            event_block_ranges,
         })
     }
 }
@@ -878,6 +1600,8 @@ impl NetworkTemplate {
 pub struct NetworkConfigTemplate {
     network_config: NetworkTemplate,
     codegen_contracts: Vec<PerNetworkContractTemplate>,
+    codegen_block_handlers: Vec<PerNetworkBlockHandlerTemplate>,
+    codegen_transaction_handlers: Vec<PerNetworkTransactionHandlerTemplate>,
 }
 
 impl NetworkConfigTemplate {
@@ -894,10 +1618,22 @@ impl NetworkConfigTemplate {
             })
             .collect::<Result<_>>()
             .context("Failed mapping network contracts")?;
+        let codegen_block_handlers = network
+            .block_handlers
+            .iter()
+            .map(PerNetworkBlockHandlerTemplate::from_config_block_handler)
+            .collect();
+        let codegen_transaction_handlers = network
+            .transaction_handlers
+            .iter()
+            .map(PerNetworkTransactionHandlerTemplate::from_config_transaction_handler)
+            .collect();
 
         Ok(NetworkConfigTemplate {
             network_config,
             codegen_contracts,
+            codegen_block_handlers,
+            codegen_transaction_handlers,
         })
     }
 }
@@ -1022,8 +1758,11 @@ struct SelectedFieldTemplate {
 pub struct ProjectTemplate {
     project_name: String,
     codegen_contracts: Vec<ContractTemplate>,
+    codegen_block_handlers: Vec<BlockHandlerTemplate>,
+    codegen_transaction_handlers: Vec<TransactionHandlerTemplate>,
     entities: Vec<EntityRecordTypeTemplate>,
     gql_enums: Vec<GraphQlEnumTypeTemplate>,
+    gql_scalars: Vec<GraphQlScalarTypeTemplate>,
     chain_configs: Vec<NetworkConfigTemplate>,
     codegen_out_path: String,
     persisted_state: PersistedStateJsonString,
@@ -1071,6 +1810,27 @@ impl ProjectTemplate {
             .collect::<Result<_>>()
             .context("Failed generating contract template types")?;
 
+        let codegen_block_handlers: Vec<BlockHandlerTemplate> = cfg
+            .get_block_handlers()
+            .iter()
+            .map(|block_handler| {
+                BlockHandlerTemplate::from_config_block_handler(block_handler, project_paths)
+            })
+            .collect::<Result<_>>()
+            .context("Failed generating block handler template types")?;
+
+        let codegen_transaction_handlers: Vec<TransactionHandlerTemplate> = cfg
+            .get_transaction_handlers()
+            .iter()
+            .map(|transaction_handler| {
+                TransactionHandlerTemplate::from_config_transaction_handler(
+                    transaction_handler,
+                    project_paths,
+                )
+            })
+            .collect::<Result<_>>()
+            .context("Failed generating transaction handler template types")?;
+
         let entities: Vec<EntityRecordTypeTemplate> = cfg
             .get_entities()
             .iter()
@@ -1085,6 +1845,15 @@ impl ProjectTemplate {
             .collect::<Result<_>>()
             .context("Failed generating enum template types")?;
 
+        let gql_scalars: Vec<GraphQlScalarTypeTemplate> = cfg
+            .get_gql_scalars()
+            .iter()
+            .map(|gql_scalar| {
+                GraphQlScalarTypeTemplate::from_config_gql_scalar(gql_scalar, &cfg.schema)
+            })
+            .collect::<Result<_>>()
+            .context("Failed generating scalar template types")?;
+
         let chain_configs: Vec<NetworkConfigTemplate> = cfg
             .get_networks()
             .iter()
@@ -1127,8 +1896,11 @@ impl ProjectTemplate {
         Ok(ProjectTemplate {
             project_name: cfg.name.clone(),
             codegen_contracts,
+            codegen_block_handlers,
+            codegen_transaction_handlers,
             entities,
             gql_enums,
+            gql_scalars,
             chain_configs,
             codegen_out_path: gitignore_path_str,
             persisted_state,
@@ -1224,11 +1996,18 @@ mod test {
             name: String::from("Contract1").to_capitalized_options(),
             addresses: vec![address1.clone()],
             events,
+            calls: vec![],
+            factory: None,
+            start_block_code: "None".to_string(),
+            end_block_code: "None".to_string(),
+            event_block_ranges: vec![],
         };
 
         let chain_config_1 = super::NetworkConfigTemplate {
             network_config: network1,
             codegen_contracts: vec![contract1],
+            codegen_block_handlers: vec![],
+            codegen_transaction_handlers: vec![],
         };
 
         let expected_chain_configs = vec![chain_config_1];
@@ -1288,6 +2067,11 @@ mod test {
             name: String::from("Contract1").to_capitalized_options(),
             addresses: vec![address1.clone()],
             events,
+            calls: vec![],
+            factory: None,
+            start_block_code: "None".to_string(),
+            end_block_code: "None".to_string(),
+            event_block_ranges: vec![],
         };
 
         let events = get_per_contract_events_vec_helper(vec!["NewGravatar", "UpdatedGravatar"]);
@@ -1295,15 +2079,24 @@ mod test {
             name: String::from("Contract2").to_capitalized_options(),
             addresses: vec![address2.clone()],
             events,
+            calls: vec![],
+            factory: None,
+            start_block_code: "None".to_string(),
+            end_block_code: "None".to_string(),
+            event_block_ranges: vec![],
         };
 
         let chain_config_1 = super::NetworkConfigTemplate {
             network_config: network1,
             codegen_contracts: vec![contract1],
+            codegen_block_handlers: vec![],
+            codegen_transaction_handlers: vec![],
         };
         let chain_config_2 = super::NetworkConfigTemplate {
             network_config: network2,
             codegen_contracts: vec![contract2],
+            codegen_block_handlers: vec![],
+            codegen_transaction_handlers: vec![],
         };
 
         let expected_chain_configs = vec![chain_config_1, chain_config_2];
@@ -1332,11 +2125,18 @@ mod test {
             name: String::from("Contract1").to_capitalized_options(),
             addresses: vec![address1.clone()],
             events,
+            calls: vec![],
+            factory: None,
+            start_block_code: "None".to_string(),
+            end_block_code: "None".to_string(),
+            event_block_ranges: vec![],
         };
 
         let chain_config_1 = super::NetworkConfigTemplate {
             network_config: network1,
             codegen_contracts: vec![contract1],
+            codegen_block_handlers: vec![],
+            codegen_transaction_handlers: vec![],
         };
 
         let expected_chain_configs = vec![chain_config_1];
@@ -1369,11 +2169,15 @@ mod test {
         let chain_config_1 = super::NetworkConfigTemplate {
             network_config: network1,
             codegen_contracts: vec![],
+            codegen_block_handlers: vec![],
+            codegen_transaction_handlers: vec![],
         };
 
         let chain_config_2 = super::NetworkConfigTemplate {
             network_config: network2,
             codegen_contracts: vec![],
+            codegen_block_handlers: vec![],
+            codegen_transaction_handlers: vec![],
         };
 
         let expected_chain_configs = vec![chain_config_1, chain_config_2];
@@ -1453,6 +2257,7 @@ let convertHyperSyncEventArgs = (decodedEvent: HyperSyncClient.Decoder.decodedEv
         imageUrl: decodedEvent.body->Js.Array2.unsafe_get(3)->HyperSyncClient.Decoder.toUnderlying->Utils.magic,
       }}
     }}
+let anonymousInputs: option<array<Viem.abiParameter>> = None
 
 let handlerRegister: HandlerTypes.Register.t = HandlerTypes.Register.make(
   ~topic0=sighash->EvmTypes.Hex.fromStringUnsafe,
@@ -1477,6 +2282,11 @@ let getTopicSelection = (eventFilters) => eventFilters->SingleOrMultiple.normali
             sighash: "0x50f7d27e90d1a5a38aeed4ceced2e8ec1ff185737aca96d15791b470d3f17363"
                 .to_string(),
             field_selection: None,
+            is_anonymous: false,
+            filters: vec![],
+            is_wildcard: false,
+            start_block: None,
+            end_block: None,
         })
         .unwrap();
 
@@ -1513,6 +2323,7 @@ let blockSchema = Block.schema
 let transactionSchema = Transaction.schema
 
 let convertHyperSyncEventArgs = (Utils.magic: HyperSyncClient.Decoder.decodedEvent => eventArgs)
+let anonymousInputs: option<array<Viem.abiParameter>> = None
 
 let handlerRegister: HandlerTypes.Register.t = HandlerTypes.Register.make(
   ~topic0=sighash->EvmTypes.Hex.fromStringUnsafe,
@@ -1530,6 +2341,60 @@ let getTopicSelection = (eventFilters) => eventFilters->SingleOrMultiple.normali
         );
     }
 
+    #[test]
+    fn event_template_with_config_filters() {
+        use ethers::abi::{EventParam, ParamType};
+
+        let from = EventParam {
+            name: "from".to_string(),
+            kind: ParamType::Address,
+            indexed: true,
+        };
+        let filters = vec![vec![system_config::EventFilterCondition {
+            param: from.clone(),
+            values: vec![
+                "0x2E645469f354BB4F5c8a05B3b30A929361cf77eC".to_string(),
+                "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(),
+            ],
+        }]];
+
+        assert_eq!(
+            EventTemplate::generate_config_event_filters_code(&filters),
+            Some(
+                "SingleOrMultiple.multiple([{from: \
+                 SingleOrMultiple.multiple([\"0x2E645469f354BB4F5c8a05B3b30A929361cf77eC\"->\
+                 Address.Evm.fromStringOrThrow, \
+                 \"0x6B175474E89094C44Da98b954EedeAC495271d0F\"->Address.Evm.fromStringOrThrow])}])"
+                    .to_string()
+            )
+        );
+        assert_eq!(EventTemplate::generate_config_event_filters_code(&[]), None);
+
+        let event_template = EventTemplate::from_config_event(&system_config::Event {
+            name: "Transfer".to_string(),
+            kind: system_config::EventKind::Params(vec![from]),
+            sighash: "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+                .to_string(),
+            field_selection: None,
+            is_anonymous: false,
+            filters,
+            is_wildcard: false,
+            start_block: None,
+            end_block: None,
+        })
+        .unwrap();
+
+        assert!(event_template.module_code.contains(
+            r#"let configTopicSelections = SingleOrMultiple.multiple([{from: SingleOrMultiple.multiple(["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC"->Address.Evm.fromStringOrThrow, "0x6B175474E89094C44Da98b954EedeAC495271d0F"->Address.Evm.fromStringOrThrow])}])->getFilterTopicSelection
+let getTopicSelection = eventFilters =>
+  switch eventFilters->getFilterTopicSelection->LogSelection.intersectTopicSelections(configTopicSelections) {
+  | [] => Js.Exn.raiseError(`The eventFilters of the ${contractName}.${name} handler don't match any of the filters of the event in config.yaml`)
+  | topicSelections => topicSelections
+  }
+handlerRegister->HandlerTypes.Register.setDefaultTopicSelections(configTopicSelections)"#
+        ));
+    }
+
     #[test]
     fn event_template_with_custom_field_selection() {
         let event_template = EventTemplate::from_config_event(&system_config::Event {
@@ -1544,6 +2409,11 @@ let getTopicSelection = (eventFilters) => eventFilters->SingleOrMultiple.normali
                     data_type: RescriptTypeIdent::option(RescriptTypeIdent::Address),
                 }],
             }),
+            is_anonymous: false,
+            filters: vec![],
+            is_wildcard: false,
+            start_block: None,
+            end_block: None,
         })
         .unwrap();
 
@@ -1580,6 +2450,7 @@ let blockSchema = S.object((_): block => {{}})
 let transactionSchema = S.object((s): transaction => {{from: s.field("from", S.option(Address.schema))}})
 
 let convertHyperSyncEventArgs = (Utils.magic: HyperSyncClient.Decoder.decodedEvent => eventArgs)
+let anonymousInputs: option<array<Viem.abiParameter>> = None
 
 let handlerRegister: HandlerTypes.Register.t = HandlerTypes.Register.make(
   ~topic0=sighash->EvmTypes.Hex.fromStringUnsafe,
@@ -1597,6 +2468,126 @@ let getTopicSelection = (eventFilters) => eventFilters->SingleOrMultiple.normali
         );
     }
 
+    #[test]
+    fn block_handler_template_module_code() {
+        let name = "everyTenBlocks".to_string().to_capitalized_options();
+
+        assert_eq!(
+            BlockHandlerTemplate::generate_module_code(&name),
+            r#"let name = "EveryTenBlocks"
+let handlerRegister: HandlerTypes.BlockRegister.t = HandlerTypes.BlockRegister.make(
+  ~blockHandlerName=name,
+)"#
+        );
+    }
+
+    #[test]
+    fn transaction_handler_template_module_code() {
+        let name = "ethTransfers".to_string().to_capitalized_options();
+
+        assert_eq!(
+            TransactionHandlerTemplate::generate_module_code(
+                &name,
+                &[SelectedField {
+                    name: "From".to_string(),
+                    data_type: RescriptTypeIdent::option(RescriptTypeIdent::Address),
+                }]
+            ),
+            r#"let name = "EthTransfers"
+
+@genType
+type transaction = {from: option<Address.t>}
+
+let transactionSchema = S.object((s): transaction => {from: s.field("from", S.option(Address.schema))})
+
+let handlerRegister: HandlerTypes.TransactionRegister.t = HandlerTypes.TransactionRegister.make(
+  ~transactionHandlerName=name,
+)"#
+        );
+    }
+
+    #[test]
+    fn call_template_module_code() {
+        let call = system_config::Call {
+            name: "getBalance".to_string(),
+            function_selector: "0x70a08231".to_string(),
+            inputs: vec![ethers::abi::Param {
+                name: "account".to_string(),
+                kind: ethers::abi::ParamType::Address,
+                internal_type: None,
+            }],
+            outputs: vec![],
+        };
+
+        let call_template = CallTemplate::from_config_call(&call).unwrap();
+
+        assert_eq!(call_template.name.capitalized, "GetBalance");
+        assert_eq!(
+            call_template.module_code,
+            r#"let name = "getBalance"
+let contractName = contractName
+let functionSelector = "0x70a08231"
+
+@genType
+type inputs = {account: Address.t}
+@genType
+type outputs = unit
+
+let inputsAbi: array<Viem.abiParameter> = %raw(`[{"name":"account","type":"address"}]`)
+let outputsAbi: array<Viem.abiParameter> = %raw(`[]`)
+
+let handlerRegister: HandlerTypes.CallRegister.t = HandlerTypes.CallRegister.make(
+  ~contractName,
+  ~callName=name,
+)"#
+        );
+    }
+
+    #[test]
+    fn event_template_with_anonymous_event() {
+        let params = vec![
+            EventParam {
+                name: "from".to_string(),
+                kind: ethers::abi::ParamType::Address,
+                indexed: true,
+            },
+            EventParam {
+                name: "amount".to_string(),
+                kind: ethers::abi::ParamType::Uint(256),
+                indexed: false,
+            },
+        ];
+        let event_template = EventTemplate::from_config_event(&system_config::Event {
+            name: "Transfer".to_string(),
+            kind: system_config::EventKind::Params(params.clone()),
+            sighash: "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+                .to_string(),
+            field_selection: None,
+            is_anonymous: true,
+            filters: vec![],
+            is_wildcard: false,
+            start_block: None,
+            end_block: None,
+        })
+        .unwrap();
+
+        let module_code = event_template.module_code;
+        assert!(module_code.contains("let id = \"anonymous_1_32\"\n"));
+        assert!(module_code.contains(&format!(
+            "let anonymousInputs: option<array<Viem.abiParameter>> = Some(%raw(`{}`))\n",
+            serde_json::to_string(&params).unwrap()
+        )));
+        assert!(module_code.contains("  ~isAnonymous=true,\n"));
+        assert!(module_code.contains(
+            "let getTopicSelection = (eventFilters) => \
+             eventFilters->SingleOrMultiple.normalizeOrThrow->Belt.Array.map(_eventFilter => \
+             LogSelection.makeAnonymousTopicSelection(~topic0=?_eventFilter.from->Belt.Option.\
+             map(topicFilters => \
+             topicFilters->SingleOrMultiple.normalizeOrThrow->Belt.Array.map(TopicFilter.\
+             fromAddress)), ))\n"
+        ));
+    }
+
     #[test]
     fn abi_event_to_record_1() {
         let project_template = get_project_template_helper("config1.yaml");
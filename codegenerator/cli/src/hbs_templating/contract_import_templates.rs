@@ -331,8 +331,12 @@ pub struct Param {
     js_name: String,
     ///Event param name + index if its a tuple ie. myTupleParam_0_1 or just myRegularParam
     entity_key: CapitalizedOptions,
+    ///Entity key as a Rescript record field, with reserved words escaped
+    entity_res_name: String,
     ///Just the event param name accessible on the event type
     event_key: CapitalizedOptions,
+    ///Event key as a Rescript record field, with reserved words escaped
+    event_res_key: String,
     ///List of nested acessors so for a nested tuple Some([0, 1]) this can be used combined with
     ///the event key ie. event.params.myTupleParam[0][1]
     tuple_param_accessor_indexes: Option<Vec<ParamIndex>>,
@@ -344,11 +348,15 @@ impl Param {
     fn from_event_param(flattened_event_param: FlattenedEventParam) -> Result<Self> {
         let js_name = flattened_event_param.event_param.name.to_string();
         let res_name = RescriptRecordField::to_valid_res_name(&js_name);
+        let entity_key = flattened_event_param.get_entity_key();
+        let event_key = flattened_event_param.get_event_param_key();
         Ok(Param {
             res_name,
             js_name,
-            entity_key: flattened_event_param.get_entity_key(),
-            event_key: flattened_event_param.get_event_param_key(),
+            entity_res_name: RescriptRecordField::to_valid_res_name(&entity_key.original),
+            entity_key,
+            event_res_key: RescriptRecordField::to_valid_res_name(&event_key.original),
+            event_key,
             tuple_param_accessor_indexes: flattened_event_param.accessor_indexes,
             graphql_type: FieldType::from_ethabi_type(&flattened_event_param.event_param.kind)
                 .context(format!(
@@ -0,0 +1,359 @@
+use super::hbs_dir_generator::HandleBarsDirGenerator;
+use crate::{
+    config_parsing::{
+        entity_parsing::{Entity, Field, FieldType, GraphQLEnum, Schema},
+        human_config::HumanConfig,
+        schema_graph::{get_field_index_notes, get_field_type_name},
+        system_config::{
+            Abi, Contract, Event, EventKind, FieldSelection, FuelEventKind, Network, SystemConfig,
+        },
+    },
+    template_dirs::TemplateDirs,
+};
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+use std::path::Path;
+
+///Markdown table cells can't contain pipes or line breaks
+fn escape_table_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct FieldSelectionDocsTemplate {
+    block_fields: Vec<String>,
+    transaction_fields: Vec<String>,
+}
+
+impl From<&FieldSelection> for FieldSelectionDocsTemplate {
+    fn from(field_selection: &FieldSelection) -> Self {
+        Self {
+            block_fields: field_selection
+                .block_fields
+                .iter()
+                .map(|field| field.name.clone())
+                .collect(),
+            transaction_fields: field_selection
+                .transaction_fields
+                .iter()
+                .map(|field| field.name.clone())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct NetworkContractDocsTemplate {
+    name: String,
+    anchor: String,
+    addresses: Vec<String>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct NetworkDocsTemplate {
+    id: u64,
+    start_block: u64,
+    end_block: Option<u64>,
+    contracts: Vec<NetworkContractDocsTemplate>,
+}
+
+impl From<&Network> for NetworkDocsTemplate {
+    fn from(network: &Network) -> Self {
+        let mut contracts: Vec<_> = network
+            .contracts
+            .iter()
+            .map(|contract| NetworkContractDocsTemplate {
+                name: contract.name.clone(),
+                anchor: contract.name.to_lowercase(),
+                addresses: contract.addresses.clone(),
+            })
+            .collect();
+        contracts.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            id: network.id,
+            start_block: network.start_block,
+            end_block: network.end_block,
+            contracts,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct EventDocsTemplate {
+    name: String,
+    signature: String,
+    sighash: String,
+    field_selection: Option<FieldSelectionDocsTemplate>,
+}
+
+impl EventDocsTemplate {
+    fn from_config_event(event: &Event, contract: &Contract) -> Result<Self> {
+        let signature = match (&contract.abi, &event.kind) {
+            (Abi::Evm(abi), _) => abi
+                .get_event_signature_by_sighash(&event.sighash)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Unexpected, event {} is missing from the abi of contract {}",
+                        event.name,
+                        contract.name
+                    )
+                })?,
+            (Abi::Fuel(_), EventKind::Fuel(FuelEventKind::LogData(data_type))) => {
+                format!("LogData({data_type})")
+            }
+            (Abi::Fuel(_), EventKind::Fuel(FuelEventKind::Mint)) => "Mint".to_string(),
+            (Abi::Fuel(_), EventKind::Fuel(FuelEventKind::Burn)) => "Burn".to_string(),
+            (Abi::Fuel(_), EventKind::Fuel(FuelEventKind::Transfer)) => "Transfer".to_string(),
+            (Abi::Fuel(_), EventKind::Fuel(FuelEventKind::Call)) => "Call".to_string(),
+            (Abi::Fuel(_), EventKind::Params(_)) => {
+                return Err(anyhow!(
+                    "Unexpected, fuel event {} on contract {} has evm params",
+                    event.name,
+                    contract.name
+                ))
+            }
+        };
+
+        Ok(Self {
+            name: event.name.clone(),
+            signature,
+            sighash: event.sighash.clone(),
+            field_selection: event
+                .field_selection
+                .as_ref()
+                .map(FieldSelectionDocsTemplate::from),
+        })
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct ContractDocsTemplate {
+    name: String,
+    events: Vec<EventDocsTemplate>,
+}
+
+impl ContractDocsTemplate {
+    fn from_config_contract(contract: &Contract) -> Result<Self> {
+        let events = contract
+            .events
+            .iter()
+            .map(|event| EventDocsTemplate::from_config_event(event, contract))
+            .collect::<Result<_>>()
+            .context(format!(
+                "Failed creating docs for events of contract {}",
+                contract.name
+            ))?;
+
+        Ok(Self {
+            name: contract.name.clone(),
+            events,
+        })
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct FieldDocsTemplate {
+    name: String,
+    type_name: String,
+    notes: String,
+    description: String,
+}
+
+impl FieldDocsTemplate {
+    fn from_entity_field(field: &Field, entity: &Entity, schema: &Schema) -> Result<Self> {
+        let mut notes = vec![];
+        if field.is_primary_key(entity) {
+            notes.push("primary key".to_string());
+        }
+        match &field.field_type {
+            FieldType::DerivedFromField {
+                entity_name,
+                derived_from_field,
+            } => notes.push(format!("derived from `{entity_name}.{derived_from_field}`")),
+            FieldType::RegularField { field_type, .. } => {
+                if let Some(linked_entity) = field_type.get_linked_entity(schema)? {
+                    notes.push(format!("references `{linked_entity}`"));
+                }
+            }
+        }
+        notes.extend(get_field_index_notes(entity, field));
+        if let Some(deprecation_reason) = &field.deprecation_reason {
+            notes.push(format!("deprecated: {deprecation_reason}"));
+        }
+
+        Ok(Self {
+            name: field.name.clone(),
+            type_name: escape_table_cell(&get_field_type_name(&field.field_type)),
+            notes: escape_table_cell(&notes.join(", ")),
+            description: escape_table_cell(field.description.as_deref().unwrap_or_default()),
+        })
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct EntityDocsTemplate {
+    name: String,
+    description: Option<String>,
+    fields: Vec<FieldDocsTemplate>,
+}
+
+impl EntityDocsTemplate {
+    fn from_entity(entity: &Entity, schema: &Schema) -> Result<Self> {
+        let fields = entity
+            .get_fields()
+            .into_iter()
+            .map(|field| FieldDocsTemplate::from_entity_field(field, entity, schema))
+            .collect::<Result<_>>()
+            .context(format!("Failed creating docs for entity {}", entity.name))?;
+
+        Ok(Self {
+            name: entity.name.clone(),
+            description: entity.description.clone(),
+            fields,
+        })
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct EnumDocsTemplate {
+    name: String,
+    values: Vec<String>,
+}
+
+impl From<&GraphQLEnum> for EnumDocsTemplate {
+    fn from(gql_enum: &GraphQLEnum) -> Self {
+        Self {
+            name: gql_enum.name.clone(),
+            values: gql_enum.values.clone(),
+        }
+    }
+}
+
+///Template for the Markdown reference of an indexer generated with envio docs
+#[derive(Serialize, Debug, PartialEq)]
+pub struct DocsTemplate {
+    name: String,
+    description: Option<String>,
+    networks: Vec<NetworkDocsTemplate>,
+    contracts: Vec<ContractDocsTemplate>,
+    field_selection: FieldSelectionDocsTemplate,
+    entities: Vec<EntityDocsTemplate>,
+    enums: Vec<EnumDocsTemplate>,
+}
+
+impl DocsTemplate {
+    pub fn from_config(config: &SystemConfig) -> Result<Self> {
+        let description = match &config.human_config {
+            HumanConfig::Evm(evm_config) => evm_config.description.clone(),
+            HumanConfig::Fuel(fuel_config) => fuel_config.description.clone(),
+        };
+
+        let contracts = config
+            .get_contracts()
+            .into_iter()
+            .map(ContractDocsTemplate::from_config_contract)
+            .collect::<Result<_>>()?;
+
+        let entities = config
+            .get_entities()
+            .into_iter()
+            .map(|entity| EntityDocsTemplate::from_entity(entity, &config.schema))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            name: config.name.clone(),
+            description,
+            networks: config
+                .get_networks()
+                .into_iter()
+                .map(NetworkDocsTemplate::from)
+                .collect(),
+            contracts,
+            field_selection: FieldSelectionDocsTemplate::from(&config.field_selection),
+            entities,
+            enums: config
+                .get_gql_enums()
+                .into_iter()
+                .map(EnumDocsTemplate::from)
+                .collect(),
+        })
+    }
+
+    pub fn generate_templates(&self, docs_dir: &Path) -> Result<()> {
+        let template_dirs = TemplateDirs::new();
+        let dynamic_docs_dir = template_dirs
+            .get_docs_dynamic_dir()
+            .context("Failed getting dynamic docs dir")?;
+
+        let hbs = HandleBarsDirGenerator::new(&dynamic_docs_dir, &self, docs_dir);
+        hbs.generate_hbs_templates()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::project_paths::ParsedProjectPaths;
+    use pretty_assertions::assert_eq;
+
+    fn get_docs_template_helper(configs_file_name: &str) -> DocsTemplate {
+        let project_root = format!("{}/test", env!("CARGO_MANIFEST_DIR"));
+        let config = format!("configs/{}", configs_file_name);
+        let project_paths =
+            ParsedProjectPaths::new(&project_root, "generated/", &config).expect("Parsed paths");
+
+        let config = SystemConfig::parse_from_project_files(&project_paths)
+            .expect("Deserialized yml config should be parseable");
+
+        DocsTemplate::from_config(&config).expect("should be able to get docs template")
+    }
+
+    #[test]
+    fn docs_template_from_config1() {
+        let docs_template = get_docs_template_helper("config1.yaml");
+
+        assert_eq!(docs_template.name, "config1");
+        assert_eq!(
+            docs_template.description,
+            Some("Gravatar for Ethereum".to_string())
+        );
+        assert_eq!(
+            docs_template.networks,
+            vec![NetworkDocsTemplate {
+                id: 1,
+                start_block: 0,
+                end_block: None,
+                contracts: vec![NetworkContractDocsTemplate {
+                    name: "Contract1".to_string(),
+                    anchor: "contract1".to_string(),
+                    addresses: vec!["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC".to_string()],
+                }],
+            }]
+        );
+
+        let event_signatures: Vec<_> = docs_template.contracts[0]
+            .events
+            .iter()
+            .map(|event| event.signature.as_str())
+            .collect();
+        assert_eq!(
+            event_signatures,
+            vec![
+                "NewGravatar(uint256 id, address owner, string displayName, string imageUrl)",
+                "UpdatedGravatar(uint256 id, address owner, string displayName, string imageUrl)",
+            ]
+        );
+        assert_eq!(
+            docs_template.field_selection.block_fields,
+            vec!["number", "timestamp", "hash"]
+        );
+    }
+
+    #[test]
+    fn escapes_table_cells() {
+        assert_eq!(escape_table_cell("a | b\nc"), "a \\| b c".to_string());
+    }
+}
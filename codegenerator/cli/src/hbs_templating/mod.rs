@@ -1,4 +1,5 @@
 pub mod codegen_templates;
 pub mod contract_import_templates;
+pub mod docs_templates;
 pub mod hbs_dir_generator;
 pub mod init_templates;
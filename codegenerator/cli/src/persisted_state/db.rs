@@ -1,7 +1,7 @@
 use super::{PersistedState, PersistedStateExists};
 use crate::config_parsing::system_config::EnvState;
 use sqlx::postgres::{PgPool, PgPoolOptions, PgQueryResult};
-use std::env;
+use std::{collections::HashMap, env};
 
 fn get_env_with_default(var: &str, default: &str) -> String {
     env::var(var).unwrap_or_else(|_| default.to_string())
@@ -112,6 +112,37 @@ impl PersistedStateExists {
         }
     }
 }
+///Reads the values of the enum types in the db, in their sort order.
+///Enum types are created with unquoted names, so they are keyed by the
+///lowercased name.
+pub async fn read_db_enum_values() -> Result<HashMap<String, Vec<String>>, sqlx::Error> {
+    let pool = get_pg_pool().await?;
+    let mut env_state = EnvState::new(&std::env::current_dir().unwrap_or_default());
+    let public_schema = env_state
+        .var("ENVIO_PG_PUBLIC_SCHEMA")
+        .unwrap_or_else(|| "public".to_string());
+
+    let rows = sqlx::query_as::<_, (String, String)>(
+        r#"
+        SELECT t.typname::text, e.enumlabel::text
+        FROM pg_type t
+        JOIN pg_enum e ON e.enumtypid = t.oid
+        JOIN pg_namespace n ON n.oid = t.typnamespace
+        WHERE n.nspname = $1
+        ORDER BY t.typname, e.enumsortorder
+        "#,
+    )
+    .bind(public_schema)
+    .fetch_all(&pool)
+    .await?;
+
+    let mut enum_values: HashMap<String, Vec<String>> = HashMap::new();
+    for (enum_name, value) in rows {
+        enum_values.entry(enum_name).or_default().push(value);
+    }
+    Ok(enum_values)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
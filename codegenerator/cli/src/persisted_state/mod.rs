@@ -1,12 +1,20 @@
 mod db;
 mod hash_string;
 
-use crate::{config_parsing::system_config::SystemConfig, project_paths::ParsedProjectPaths};
+use crate::{
+    config_parsing::{
+        entity_parsing::{EnumValuesChange, Schema},
+        system_config::SystemConfig,
+    },
+    project_paths::ParsedProjectPaths,
+};
 use anyhow::Context;
+pub use db::read_db_enum_values;
 use hash_string::HashString;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use std::{
+    collections::HashMap,
     fmt::{self, Display},
     path::PathBuf,
 };
@@ -34,6 +42,26 @@ pub enum StateField {
     HandlerFiles,
 }
 
+///Hashes of the schema as it was before the changes which can be migrated
+///keeping the indexed data. None if the schema doesn't have the change.
+#[derive(Debug, Default)]
+pub struct PreviousSchemaHashes {
+    ///Before applying its @renamedFrom hints
+    pub before_renames: Option<HashString>,
+    ///Before values were appended to its enums
+    pub before_enum_additions: Option<HashString>,
+    ///Before both the renames and the enum additions
+    pub before_renames_and_enum_additions: Option<HashString>,
+}
+
+///The migrations which bring the db schema up to date keeping the indexed data.
+///The renames need to run before the enum additions.
+#[derive(Debug, PartialEq)]
+pub struct SchemaMigrations {
+    pub renames: bool,
+    pub enum_additions: bool,
+}
+
 ///Gets the path to the persisted file in generated folder
 fn get_generated_file_path(project_paths: &ParsedProjectPaths) -> PathBuf {
     project_paths.generated.join(PERSISTED_STATE_FILE_NAME)
@@ -69,9 +97,9 @@ impl PersistedState {
     ///of an envio project. This will be used to diff against db and local file
     ///persisted state.
     pub fn get_current_state(config: &SystemConfig) -> anyhow::Result<Self> {
-        let schema_path = config
-            .get_path_to_schema()
-            .context("Failed getting path to schema")?;
+        let schema_paths = config
+            .get_paths_to_schema()
+            .context("Failed getting paths to schema")?;
 
         let all_handler_paths = config
             .get_all_paths_to_handlers()
@@ -84,11 +112,15 @@ impl PersistedState {
         const HANDLER_FILES_MUST_EXIST: bool = false;
         const ABI_FILES_MUST_EXIST: bool = true;
 
+        //The schema is hashed without the @renamedFrom hints, so they can be
+        //removed once the renames were applied without a new migration
+        let printed_schema =
+            Schema::print_for_hash(&schema_paths).context("Failed hashing schema files")?;
+
         Ok(PersistedState {
             envio_version: CURRENT_CRATE_VERSION.to_string(),
             config_hash: HashString::from_string(config.human_config.to_string()),
-            schema_hash: HashString::from_file_path(schema_path.clone())
-                .context("Failed hashing schema file")?,
+            schema_hash: HashString::from_string(printed_schema.current),
             handler_files_hash: HashString::from_file_paths(
                 all_handler_paths,
                 HANDLER_FILES_MUST_EXIST,
@@ -99,6 +131,56 @@ impl PersistedState {
         })
     }
 
+    ///Hashes the schema had before the changes which can be migrated keeping
+    ///the indexed data. The previous values of the enums are taken from the enum
+    ///types in the db (keyed by their lowercased name).
+    pub fn get_previous_schema_hashes(
+        config: &SystemConfig,
+        db_enum_values: &HashMap<String, Vec<String>>,
+    ) -> anyhow::Result<PreviousSchemaHashes> {
+        let schema_paths = config
+            .get_paths_to_schema()
+            .context("Failed getting paths to schema")?;
+
+        let printed_schema =
+            Schema::print_for_hash(&schema_paths).context("Failed hashing schema files")?;
+
+        let enum_values_before_additions = config
+            .schema
+            .enums
+            .values()
+            .filter_map(|enm| {
+                let db_values = db_enum_values.get(&enm.name.to_lowercase())?;
+                match enm.get_values_change(db_values) {
+                    EnumValuesChange::Appended(_) => Some((enm.name.clone(), db_values.clone())),
+                    _ => None,
+                }
+            })
+            .collect::<HashMap<_, _>>();
+
+        let printed_schema_before_enum_additions = if enum_values_before_additions.is_empty() {
+            None
+        } else {
+            Some(
+                Schema::print_for_hash_with_enum_values(
+                    &schema_paths,
+                    &enum_values_before_additions,
+                )
+                .context("Failed hashing schema files")?,
+            )
+        };
+
+        Ok(PreviousSchemaHashes {
+            before_renames: printed_schema.before_renames.map(HashString::from_string),
+            before_enum_additions: printed_schema_before_enum_additions
+                .as_ref()
+                .map(|printed_schema| HashString::from_string(printed_schema.current.clone())),
+            before_renames_and_enum_additions: printed_schema_before_enum_additions
+                .and_then(|printed_schema| printed_schema.before_renames)
+                .map(HashString::from_string),
+        })
+    }
+
     ///Compares the current state and a persisted state file, returning a boolean of whether
     ///codegen should be run and a vector of the changed fields that make the rerun necessary
     pub fn should_run_codegen(&self, persisted_state_file: &Self) -> (bool, Vec<StateField>) {
@@ -132,6 +214,79 @@ impl PersistedState {
         (!non_matching_fields.is_empty(), non_matching_fields)
     }
 
+    ///Compares the current state and a persisted state on the db, returning whether
+    ///the db can be migrated by only renaming tables and columns. That's the case
+    ///when the schema is the only change and it matches the persisted one before
+    ///its @renamedFrom hints.
+    pub fn should_run_rename_migrations(
+        &self,
+        persisted_state_db: &Self,
+        schema_hash_before_renames: &Option<HashString>,
+    ) -> bool {
+        self.is_only_schema_change_from(persisted_state_db, schema_hash_before_renames)
+    }
+
+    ///Compares the current state and a persisted state on the db, returning whether
+    ///the db can be migrated by only adding values to its enum types. That's the case
+    ///when the schema is the only change and it matches the persisted one without
+    ///the appended enum values.
+    pub fn should_run_enum_migrations(
+        &self,
+        persisted_state_db: &Self,
+        schema_hash_before_enum_additions: &Option<HashString>,
+    ) -> bool {
+        self.is_only_schema_change_from(persisted_state_db, schema_hash_before_enum_additions)
+    }
+
+    ///Compares the current state and a persisted state on the db, returning the
+    ///migrations which bring the db schema up to date keeping the indexed data.
+    ///None if the db needs to be set up again.
+    pub fn get_schema_migrations(
+        &self,
+        persisted_state_db: &Self,
+        previous_schema_hashes: &PreviousSchemaHashes,
+    ) -> Option<SchemaMigrations> {
+        if self.should_run_rename_migrations(
+            persisted_state_db,
+            &previous_schema_hashes.before_renames,
+        ) {
+            Some(SchemaMigrations {
+                renames: true,
+                enum_additions: false,
+            })
+        } else if self.should_run_enum_migrations(
+            persisted_state_db,
+            &previous_schema_hashes.before_enum_additions,
+        ) {
+            Some(SchemaMigrations {
+                renames: false,
+                enum_additions: true,
+            })
+        } else if self.is_only_schema_change_from(
+            persisted_state_db,
+            &previous_schema_hashes.before_renames_and_enum_additions,
+        ) {
+            Some(SchemaMigrations {
+                renames: true,
+                enum_additions: true,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn is_only_schema_change_from(
+        &self,
+        persisted_state_db: &Self,
+        previous_schema_hash: &Option<HashString>,
+    ) -> bool {
+        let any_changes = StateField::iter().collect::<Vec<_>>();
+        let non_matching_fields = self.get_non_matching_fields(persisted_state_db, any_changes);
+
+        non_matching_fields == vec![StateField::Schema]
+            && previous_schema_hash.as_ref() == Some(&persisted_state_db.schema_hash)
+    }
+
     ///Compares the current state and a persisted state on the db,
     ///returning a boolean of whether it should resync from raw events
     ///ie. in the case where nothing has changed except event handlers
@@ -194,7 +349,7 @@ impl From<PersistedState> for PersistedStateJsonString {
 
 #[cfg(test)]
 mod test {
-    use super::PersistedState;
+    use super::{PersistedState, PreviousSchemaHashes, SchemaMigrations};
     use serde_json::json;
 
     #[test]
@@ -328,6 +483,153 @@ mod test {
         );
     }
 
+    #[test]
+    fn should_run_rename_migrations() {
+        let persisted_db: PersistedState = serde_json::from_value(json!({
+            "envio_version": "0.0.1",
+            "config_hash": "<HASH_STRING>",
+            "schema_hash": "<HASH_STRING>",
+            "handler_files_hash": "<HASH_STRING>",
+            "abi_files_hash": "<HASH_STRING>",
+        }))
+        .unwrap();
+
+        let current_state: PersistedState = serde_json::from_value(json!({
+            "envio_version": "0.0.1",
+            "config_hash": "<HASH_STRING>",
+            "schema_hash": "<RENAMED_HASH_STRING>",
+            "handler_files_hash": "<HASH_STRING>",
+            "abi_files_hash": "<HASH_STRING>",
+        }))
+        .unwrap();
+
+        let schema_hash_before_renames = serde_json::from_value(json!("<HASH_STRING>")).unwrap();
+
+        assert!(
+            current_state
+                .should_run_rename_migrations(&persisted_db, &Some(schema_hash_before_renames)),
+            "should run rename migrations since the schema before renames matches the db"
+        );
+
+        let schema_hash_before_renames =
+            serde_json::from_value(json!("<CHANGED_HASH_STRING>")).unwrap();
+
+        assert!(
+            !current_state
+                .should_run_rename_migrations(&persisted_db, &Some(schema_hash_before_renames)),
+            "should not run rename migrations since the schema has other changes"
+        );
+
+        assert!(
+            !current_state.should_run_rename_migrations(&persisted_db, &None),
+            "should not run rename migrations since there are no rename hints"
+        );
+    }
+
+    #[test]
+    fn should_run_enum_migrations() {
+        let persisted_db: PersistedState = serde_json::from_value(json!({
+            "envio_version": "0.0.1",
+            "config_hash": "<HASH_STRING>",
+            "schema_hash": "<HASH_STRING>",
+            "handler_files_hash": "<HASH_STRING>",
+            "abi_files_hash": "<HASH_STRING>",
+        }))
+        .unwrap();
+
+        let current_state: PersistedState = serde_json::from_value(json!({
+            "envio_version": "0.0.1",
+            "config_hash": "<HASH_STRING>",
+            "schema_hash": "<ENUM_ADDITIONS_HASH_STRING>",
+            "handler_files_hash": "<HASH_STRING>",
+            "abi_files_hash": "<HASH_STRING>",
+        }))
+        .unwrap();
+
+        let schema_hash_before_enum_additions =
+            serde_json::from_value(json!("<HASH_STRING>")).unwrap();
+
+        assert!(
+            current_state.should_run_enum_migrations(
+                &persisted_db,
+                &Some(schema_hash_before_enum_additions)
+            ),
+            "should run enum migrations since the schema without the added values matches the db"
+        );
+
+        assert!(
+            !current_state.should_run_enum_migrations(&persisted_db, &None),
+            "should not run enum migrations since no enum only had values appended"
+        );
+    }
+
+    #[test]
+    fn gets_schema_migrations() {
+        let persisted_db: PersistedState = serde_json::from_value(json!({
+            "envio_version": "0.0.1",
+            "config_hash": "<HASH_STRING>",
+            "schema_hash": "<HASH_STRING>",
+            "handler_files_hash": "<HASH_STRING>",
+            "abi_files_hash": "<HASH_STRING>",
+        }))
+        .unwrap();
+
+        let current_state: PersistedState = serde_json::from_value(json!({
+            "envio_version": "0.0.1",
+            "config_hash": "<HASH_STRING>",
+            "schema_hash": "<RENAMED_AND_ENUM_ADDITIONS_HASH_STRING>",
+            "handler_files_hash": "<HASH_STRING>",
+            "abi_files_hash": "<HASH_STRING>",
+        }))
+        .unwrap();
+
+        let hash = |hash: &str| Some(serde_json::from_value(json!(hash)).unwrap());
+
+        assert_eq!(
+            current_state.get_schema_migrations(
+                &persisted_db,
+                &PreviousSchemaHashes {
+                    before_renames: hash("<ENUM_ADDITIONS_HASH_STRING>"),
+                    before_enum_additions: hash("<RENAMED_HASH_STRING>"),
+                    before_renames_and_enum_additions: hash("<HASH_STRING>"),
+                }
+            ),
+            Some(SchemaMigrations {
+                renames: true,
+                enum_additions: true
+            }),
+            "should run both migrations since the schema before both changes matches the db"
+        );
+
+        assert_eq!(
+            current_state.get_schema_migrations(
+                &persisted_db,
+                &PreviousSchemaHashes {
+                    before_renames: hash("<HASH_STRING>"),
+                    ..Default::default()
+                }
+            ),
+            Some(SchemaMigrations {
+                renames: true,
+                enum_additions: false
+            }),
+            "should only run rename migrations since the schema before renames matches the db"
+        );
+
+        assert_eq!(
+            current_state.get_schema_migrations(
+                &persisted_db,
+                &PreviousSchemaHashes {
+                    before_renames: hash("<ENUM_ADDITIONS_HASH_STRING>"),
+                    before_enum_additions: hash("<RENAMED_HASH_STRING>"),
+                    before_renames_and_enum_additions: hash("<CHANGED_HASH_STRING>"),
+                }
+            ),
+            None,
+            "should set up the db since the schema has other changes"
+        );
+    }
+
     #[test]
     fn should_not_run_db_migrations() {
         let persisted_db: PersistedState = serde_json::from_value(json!({
@@ -17,12 +17,33 @@ impl HandlerPathsTemplate {
     pub fn from_contract(
         contract: &system_config::Contract,
         project_paths: &ParsedProjectPaths,
+    ) -> anyhow::Result<Self> {
+        Self::from_handler_path(&contract.handler_path, project_paths)
+    }
+
+    pub fn from_block_handler(
+        block_handler: &system_config::BlockHandler,
+        project_paths: &ParsedProjectPaths,
+    ) -> anyhow::Result<Self> {
+        Self::from_handler_path(&block_handler.handler_path, project_paths)
+    }
+
+    pub fn from_transaction_handler(
+        transaction_handler: &system_config::TransactionHandler,
+        project_paths: &ParsedProjectPaths,
+    ) -> anyhow::Result<Self> {
+        Self::from_handler_path(&transaction_handler.handler_path, project_paths)
+    }
+
+    fn from_handler_path(
+        handler_path: &str,
+        project_paths: &ParsedProjectPaths,
     ) -> anyhow::Result<Self> {
         let config_directory = project_paths
             .config
             .parent()
             .ok_or_else(|| anyhow!("Unexpected config file should have a parent directory"))?;
-        let handler_path_relative = PathBuf::from(&contract.handler_path);
+        let handler_path_relative = PathBuf::from(handler_path);
         let handler_path_joined = config_directory.join(handler_path_relative);
         let absolute_path = path_utils::normalize_path(handler_path_joined);
 
@@ -79,7 +100,8 @@ mod tests {
         let config =
             SystemConfig::parse_from_project_files(&project_paths).expect("Failed parsing config");
 
-        let expected_schema_path = test_dir_path_buf.join(PathBuf::from("schemas/schema.graphql"));
+        let expected_schema_paths =
+            vec![test_dir_path_buf.join(PathBuf::from("schemas/schema.graphql"))];
 
         let expected_handler_paths =
             vec![test_dir_path_buf.join(PathBuf::from("configs/src/EventHandler.js"))];
@@ -87,9 +109,9 @@ mod tests {
         let expected_abi_paths = vec![test_dir_path_buf.join(PathBuf::from("abis/Contract1.json"))];
 
         assert_eq!(
-            expected_schema_path,
+            expected_schema_paths,
             config
-                .get_path_to_schema()
+                .get_paths_to_schema()
                 .expect("failed to get schema path")
         );
         assert_eq!(
@@ -354,8 +354,12 @@ pub enum RescriptTypeIdent {
     Bool,
     Unknown,
     Timestamp,
+    //Hex string stored as BYTEA in the db
+    DbBytes,
     //Enums defined in the user's schema
     SchemaEnum(CapitalizedOptions),
+    //Custom scalars defined in the user's schema
+    SchemaScalar(CapitalizedOptions),
     Array(Box<RescriptTypeIdent>),
     Option(Box<RescriptTypeIdent>),
     //Note: tuple is technically an expression not an identifier
@@ -393,6 +397,7 @@ impl RescriptTypeIdent {
             Self::ID => "id".to_string(),
             Self::Bool => "bool".to_string(),
             Self::Timestamp => "Js.Date.t".to_string(),
+            Self::DbBytes => "string".to_string(),
             Self::Array(inner_type) => {
                 format!("array<{}>", inner_type.to_string())
             }
@@ -410,6 +415,9 @@ impl RescriptTypeIdent {
             Self::SchemaEnum(enum_name) => {
                 format!("Enums.{}.t", &enum_name.capitalized)
             }
+            Self::SchemaScalar(scalar_name) => {
+                format!("Scalars.{}.t", &scalar_name.capitalized)
+            }
             // Lowercase generic params because of the issue https://github.com/rescript-lang/rescript-compiler/issues/6759
             Self::GenericParam(name) => format!("'{}", name.to_lowercase()),
             Self::TypeApplication {
@@ -445,6 +453,7 @@ impl RescriptTypeIdent {
             Self::ID => "S.string".to_string(),
             Self::Bool => "S.bool".to_string(),
             Self::Timestamp => "Utils.Schema.dbDate".to_string(),
+            Self::DbBytes => "Utils.Schema.dbBytes".to_string(),
             Self::Array(inner_type) => {
                 format!("S.array({})", inner_type.to_rescript_schema(mode))
             }
@@ -469,6 +478,9 @@ impl RescriptTypeIdent {
             Self::SchemaEnum(enum_name) => {
                 format!("Enums.{}.enum.schema", &enum_name.capitalized)
             }
+            Self::SchemaScalar(scalar_name) => {
+                format!("Scalars.{}.schema", &scalar_name.capitalized)
+            }
             // TODO: ensure these are defined
             Self::GenericParam(name) => {
                 format!("_{name}Schema")
@@ -500,7 +512,9 @@ impl RescriptTypeIdent {
             | Self::ID
             | Self::Bool
             | Self::Timestamp
+            | Self::DbBytes
             | Self::SchemaEnum(_)
+            | Self::SchemaScalar(_)
             | Self::GenericParam(_) => vec![],
             Self::TypeApplication {
                 name, type_params, ..
@@ -532,11 +546,15 @@ impl RescriptTypeIdent {
             Self::ID => "\"my_id\"".to_string(),
             Self::Bool => "false".to_string(),
             Self::Timestamp => "Js.Date.fromFloat(0.)".to_string(),
+            Self::DbBytes => "\"0x\"".to_string(),
             Self::Array(_) => "[]".to_string(),
             Self::Option(_) => "None".to_string(),
             Self::SchemaEnum(enum_name) => {
                 format!("Enums.{}.default", &enum_name.capitalized)
             }
+            Self::SchemaScalar(scalar_name) => {
+                format!("Scalars.{}.default", &scalar_name.capitalized)
+            }
             Self::Tuple(inner_types) => {
                 let inner_types_str = inner_types
                     .iter()
@@ -598,11 +616,15 @@ impl RescriptTypeIdent {
             Self::ID => "\"my_id\"".to_string(),
             Self::Bool => "false".to_string(),
             Self::Timestamp => "new Date(0)".to_string(),
+            Self::DbBytes => "\"0x\"".to_string(),
             Self::Array(_) => "[]".to_string(),
             Self::Option(_) => "null".to_string(),
             Self::SchemaEnum(enum_name) => {
                 format!("{}Default", &enum_name.uncapitalized)
             }
+            Self::SchemaScalar(scalar_name) => {
+                format!("{}Default", &scalar_name.uncapitalized)
+            }
             Self::Tuple(inner_types) => {
                 let inner_types_str = inner_types
                     .iter()
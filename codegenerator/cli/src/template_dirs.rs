@@ -261,6 +261,11 @@ impl<'a> TemplateDirs<'a> {
         })
     }
 
+    ///Gets dir at templates/dynamic/docs
+    pub fn get_docs_dynamic_dir(&self) -> Result<RelativeDir<'a>> {
+        self.get_dynamic_dir("docs")
+    }
+
     ///Gets template from templates/static/{init_template}
     fn get_init_template_static_dirs(&self, template: String) -> Result<RelativeDir<'a>> {
         let template_dir = self
@@ -399,6 +404,12 @@ mod test {
             .expect("codegen dynamic");
     }
 
+    #[test]
+    fn docs_templates_exist() {
+        let template_dirs = TemplateDirs::new();
+        template_dirs.get_docs_dynamic_dir().expect("docs dynamic");
+    }
+
     #[test]
     fn all_init_templates_exist() {
         let template_dirs = TemplateDirs::new();
@@ -67,6 +67,46 @@ impl From<String> for CaseOptions {
     }
 }
 
+///Number of single character insertions, deletions or substitutions needed
+///to turn one string into the other
+pub fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b_chars = b.chars().collect::<Vec<_>>();
+    let mut previous_row = (0..=b_chars.len()).collect::<Vec<_>>();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current_row = vec![i + 1];
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution_cost = if a_char == *b_char { 0 } else { 1 };
+            current_row.push(
+                (previous_row[j] + substitution_cost)
+                    .min(previous_row[j + 1] + 1)
+                    .min(current_row[j] + 1),
+            );
+        }
+        previous_row = current_row;
+    }
+
+    previous_row[b_chars.len()]
+}
+
+///Finds the candidate closest to a name that doesn't exist, to suggest it in
+///errors. Candidates that differ too much from the name are not returned.
+pub fn find_closest_match<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            let distance = levenshtein_distance(&name.to_lowercase(), &candidate.to_lowercase());
+            (distance, candidate)
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,6 +144,28 @@ mod tests {
         assert_eq!(capitalization_options.original, "camelCase");
     }
 
+    #[test]
+    fn levenshtein_distance_works() {
+        assert_eq!(levenshtein_distance("token", "token"), 0);
+        assert_eq!(levenshtein_distance("token", "tokens"), 1);
+        assert_eq!(levenshtein_distance("owner", "owmer"), 1);
+        assert_eq!(levenshtein_distance("", "pool"), 4);
+    }
+
+    #[test]
+    fn closest_match_is_found() {
+        let candidates = vec!["token", "owner", "amount"];
+        assert_eq!(
+            find_closest_match("tokn", candidates.clone()),
+            Some("token")
+        );
+        assert_eq!(
+            find_closest_match("Owner", candidates.clone()),
+            Some("owner")
+        );
+        assert_eq!(find_closest_match("pool", candidates), None);
+    }
+
     #[test]
     fn casing_works() {
         let case_options = CaseOptions::new("TransactionIndex");
@@ -1,10 +1,39 @@
 open Belt
 
+type eventBlockRange = {
+  eventName: string,
+  startBlock?: int,
+  endBlock?: int,
+}
+
 type contract = {
   name: string,
   abi: Ethers.abi,
   addresses: array<Address.t>,
   events: array<module(Types.Event)>,
+  // Limits the blocks where the events of the contract are indexed
+  startBlock?: int,
+  endBlock?: int,
+  // Overrides the block range of the contract for specific events
+  eventBlockRanges?: array<eventBlockRange>,
+}
+
+type blockHandler = {
+  handler: module(Types.BlockHandler),
+  interval: int,
+  startBlock: int,
+  endBlock: option<int>,
+}
+
+type transactionStatus = Success | Reverted
+
+type transactionHandler = {
+  handler: module(Types.TransactionHandler),
+  // Empty arrays don't filter by the field
+  from: array<Address.t>,
+  to: array<Address.t>,
+  functionSelectors: array<string>,
+  status: option<transactionStatus>,
 }
 
 type syncConfig = {
@@ -31,6 +60,9 @@ type chainConfig = {
   confirmedBlockThreshold: int,
   chain: ChainMap.Chain.t,
   contracts: array<contract>,
+  blockHandlers: array<blockHandler>,
+  transactionHandlers: array<transactionHandler>,
+  calls: array<module(Types.Call)>,
   source: Source.t,
 }
 
@@ -133,6 +165,15 @@ let shouldSaveHistory = (config, ~isInReorgThreshold) =>
   | _ => false
   }
 
+// Immutable entities never change after creation, so outside of the reorg threshold
+// their history rows would only duplicate the entity table.
+let shouldSaveEntityHistory = (config, ~table: Table.table, ~isInReorgThreshold) =>
+  switch config.historyConfig {
+  | {rollbackFlag: RollbackOnReorg} if isInReorgThreshold => true
+  | {historyFlag: FullHistory} => !table.isImmutable
+  | _ => false
+  }
+
 let shouldPruneHistory = (config, ~isInReorgThreshold) =>
   switch config.historyConfig {
   | {rollbackFlag: RollbackOnReorg, historyFlag: MinHistory} if isInReorgThreshold => true
@@ -44,6 +44,16 @@ module Group = {
       | None => wildcard
       }
     }
+
+  // Anonymous events are matched by their shape only,
+  // so only route logs of the registered contract addresses
+  let getByContractAddress = (group: t<'a>, ~contractAddress, ~contractAddressMapping) =>
+    switch contractAddressMapping->ContractAddressingMap.getContractNameFromAddress(
+      ~contractAddress,
+    ) {
+    | Some(contractName) => group.byContractName->Utils.Dict.dangerouslyGetNonOption(contractName)
+    | None => None
+    }
 }
 
 type t<'a> = dict<Group.t<'a>>
@@ -89,6 +99,50 @@ let getEvmEventId = (~sighash, ~topicCount) => {
   sighash ++ "_" ++ topicCount->Belt.Int.toString
 }
 
+let getEvmAnonymousEventId = (~topicCount, ~dataShape) => {
+  "anonymous_" ++ topicCount->Belt.Int.toString ++ "_" ++ dataShape
+}
+
+/**
+Finds the event by topic0 first, and falls back to anonymous events
+with the same number of topics and either the exact data size or dynamic data
+*/
+let getEvmEvent = (
+  router: t<'a>,
+  ~topics: array<EvmTypes.Hex.t>,
+  ~data: string,
+  ~contractAddress,
+  ~contractAddressMapping,
+) => {
+  let topicCount = topics->Js.Array2.length
+  let byTopic0 = switch topics->Belt.Array.get(0) {
+  | Some(topic0) =>
+    router->get(
+      ~tag=getEvmEventId(~sighash=topic0->EvmTypes.Hex.toString, ~topicCount),
+      ~contractAddress,
+      ~contractAddressMapping,
+    )
+  | None => None
+  }
+  switch byTopic0 {
+  | Some(_) => byTopic0
+  | None =>
+    let getAnonymous = dataShape =>
+      switch router->Utils.Dict.dangerouslyGetNonOption(
+        getEvmAnonymousEventId(~topicCount, ~dataShape),
+      ) {
+      | Some(group) => group->Group.getByContractAddress(~contractAddress, ~contractAddressMapping)
+      | None => None
+      }
+    // Data is a 0x prefixed hex string, 2 characters per byte
+    let dataSize = (data->Js.String2.length - 2) / 2
+    switch getAnonymous(dataSize->Belt.Int.toString) {
+    | Some(_) as event => event
+    | None => getAnonymous("dynamic")
+    }
+  }
+}
+
 let fromEvmEventModsOrThrow = (eventMods: array<module(Types.Event)>, ~chain): t<
   module(Types.InternalEvent),
 > => {
@@ -96,13 +150,19 @@ let fromEvmEventModsOrThrow = (eventMods: array<module(Types.Event)>, ~chain): t
   eventMods->Belt.Array.forEach(eventMod => {
     let eventMod = eventMod->(Utils.magic: module(Types.Event) => module(Types.InternalEvent))
     let module(Event) = eventMod
+    let isWildcard = (Event.handlerRegister->Types.HandlerTypes.Register.getEventOptions).isWildcard
+    if isWildcard && Event.anonymousInputs->Belt.Option.isSome {
+      Js.Exn.raiseError(
+        `Anonymous events can't be indexed as wildcard, since they can only be identified by the contract address: ${Event.name} for contract ${Event.contractName} on chain ${chain->ChainMap.Chain.toString}`,
+      )
+    }
     router->addOrThrow(
       Event.id,
       eventMod,
       ~contractName=Event.contractName,
       ~eventName=Event.name,
       ~chain,
-      ~isWildcard=(Event.handlerRegister->Types.HandlerTypes.Register.getEventOptions).isWildcard,
+      ~isWildcard,
     )
   })
   router
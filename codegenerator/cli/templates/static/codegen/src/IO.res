@@ -141,12 +141,131 @@ let executeDbFunctionsEntity = (
   promises->Promise.all->Promise.thenResolve(_ => ())
 }
 
-let executeBatch = async (sql, ~inMemoryStore: InMemoryStore.t, ~isInReorgThreshold, ~config) => {
-  let entityDbExecutionComposer =
-    config->Config.shouldSaveHistory(~isInReorgThreshold)
-      ? executeSetEntityWithHistory
-      : executeDbFunctionsEntity
+//The timestamps of the earliest and the latest source rows an aggregation needs to be recomputed for
+type rollupRange = {fromTimestamp: Js.Date.t, toTimestamp: Js.Date.t}
+
+let mergeRollupRanges = (a: option<rollupRange>, b: option<rollupRange>) =>
+  switch (a, b) {
+  | (Some(a), Some(b)) =>
+    Some({
+      fromTimestamp: a.fromTimestamp->Js.Date.getTime < b.fromTimestamp->Js.Date.getTime
+        ? a.fromTimestamp
+        : b.fromTimestamp,
+      toTimestamp: a.toTimestamp->Js.Date.getTime > b.toTimestamp->Js.Date.getTime
+        ? a.toTimestamp
+        : b.toTimestamp,
+    })
+  | (Some(_) as a, None) => a
+  | (None, b) => b
+  }
+
+//Gets the range of the timestamps of the source timeseries rows set in the batch
+let getTimeseriesRollupRange = (
+  ~inMemoryStore: InMemoryStore.t,
+  ~entityMod: module(Entities.InternalEntity),
+) => {
+  inMemoryStore.entities
+  ->InMemoryStore.EntityTables.get(entityMod)
+  ->InMemoryTable.Entity.rows
+  ->Array.reduce(None, (range, row) =>
+    switch row {
+    | Updated({latest: {entityUpdateAction: Set(entity)}}) =>
+      let timestamp = (
+        entity->(Utils.magic: Entities.internalEntity => {"timestamp": Js.Date.t})
+      )["timestamp"]
+      mergeRollupRanges(range, Some({fromTimestamp: timestamp, toTimestamp: timestamp}))
+    | _ => range
+    }
+  )
+}
 
+//Gets the timestamp ranges the aggregations need to be recomputed for, by aggregation table name.
+//A rollback deletes source rows which are only in the DB, and rows set after it can reuse
+//their ids, so when rolling back the timestamps of all the source rows in the batch are
+//also read from the DB. Needs to run in the transaction before the source rows are written.
+let getAggregationRollupRanges = async (sql, ~inMemoryStore: InMemoryStore.t) => {
+  let rollupRanges = Js.Dict.empty()
+  for i in 0 to Entities.allEntities->Array.length - 1 {
+    let module(EntityMod) = Entities.allEntities->Array.getUnsafe(i)
+    switch EntityMod.table.aggregation {
+    | Some(aggregation) =>
+      let sourceEntityMod = Entities.allEntities->Js.Array2.find(entityMod => {
+        let module(SourceEntityMod) = entityMod
+        SourceEntityMod.table.tableName === aggregation.source
+      })
+      switch sourceEntityMod {
+      | Some(entityMod) =>
+        let setRollupRange = getTimeseriesRollupRange(~inMemoryStore, ~entityMod)
+        let sourceIds =
+          inMemoryStore.entities
+          ->InMemoryStore.EntityTables.get(entityMod)
+          ->InMemoryTable.Entity.rows
+          ->Array.keepMap(row =>
+            switch row {
+            | Updated({latest: {entityId}}) => Some(entityId)
+            | InitialReadFromDb(_) => None
+            }
+          )
+        let dbRollupRange = if (
+          inMemoryStore->InMemoryStore.isRollingBack && sourceIds->Array.length > 0
+        ) {
+          let rows: array<{
+            "fromTimestamp": Js.Nullable.t<Js.Date.t>,
+            "toTimestamp": Js.Nullable.t<Js.Date.t>,
+          }> = await sql->Postgres.preparedUnsafe(
+            EntityMod.table->Table.makeAggregationSourceTimestampRangeQuery(~aggregation),
+            [sourceIds]->Utils.magic,
+          )
+          switch rows->Array.get(0) {
+          | Some(row) =>
+            switch (
+              row["fromTimestamp"]->Js.Nullable.toOption,
+              row["toTimestamp"]->Js.Nullable.toOption,
+            ) {
+            | (Some(fromTimestamp), Some(toTimestamp)) => Some({fromTimestamp, toTimestamp})
+            | _ => None
+            }
+          | None => None
+          }
+        } else {
+          None
+        }
+        switch mergeRollupRanges(setRollupRange, dbRollupRange) {
+        | Some(rollupRange) => rollupRanges->Js.Dict.set(EntityMod.table.tableName, rollupRange)
+        | None => ()
+        }
+      | None =>
+        Js.Exn.raiseError(
+          `Unexpected, source ${aggregation.source} of aggregation ${EntityMod.table.tableName} not found`,
+        )
+      }
+    | None => ()
+    }
+  }
+  rollupRanges
+}
+
+//Recomputes the aggregation rows affected by the timeseries rows written in the batch.
+//Needs to run after the source entities are written in the same transaction
+let executeAggregationRollups = async (sql, ~rollupRanges: Js.Dict.t<rollupRange>) => {
+  for i in 0 to Entities.allEntities->Array.length - 1 {
+    let module(EntityMod) = Entities.allEntities->Array.getUnsafe(i)
+    switch (EntityMod.table.aggregation, rollupRanges->Js.Dict.get(EntityMod.table.tableName)) {
+    | (Some(aggregation), Some({fromTimestamp, toTimestamp})) =>
+      let queries = EntityMod.table->Table.makeAggregationRollupQueries(~aggregation)
+      for j in 0 to queries->Array.length - 1 {
+        let _ =
+          await sql->Postgres.preparedUnsafe(
+            queries->Array.getUnsafe(j),
+            (fromTimestamp, toTimestamp)->Utils.magic,
+          )
+      }
+    | _ => ()
+    }
+  }
+}
+
+let executeBatch = async (sql, ~inMemoryStore: InMemoryStore.t, ~isInReorgThreshold, ~config) => {
   let setEventSyncState = executeSet(
     _,
     ~dbFunction=DbFunctions.EventSyncState.batchSet,
@@ -160,6 +279,11 @@ let executeBatch = async (sql, ~inMemoryStore: InMemoryStore.t, ~isInReorgThresh
   )
 
   let setEntities = Entities.allEntities->Belt.Array.map(entityMod => {
+    let module(EntityMod) = entityMod
+    let entityDbExecutionComposer =
+      config->Config.shouldSaveEntityHistory(~table=EntityMod.table, ~isInReorgThreshold)
+        ? executeSetEntityWithHistory
+        : executeDbFunctionsEntity
     entityDbExecutionComposer(_, ~entityMod, ~inMemoryStore)
   })
 
@@ -183,12 +307,28 @@ let executeBatch = async (sql, ~inMemoryStore: InMemoryStore.t, ~isInReorgThresh
   }
 
   let res = await sql->Postgres.beginSql(sql => {
-    Belt.Array.concatMany([
-      //Rollback tables need to happen first in the traction
-      rollbackTables,
-      [setEventSyncState, setRawEvents],
-      setEntities,
-    ])->Belt.Array.map(dbFunc => sql->dbFunc)
+    //Read before the writes, since rows deleted by a rollback are only in the DB until then
+    let rollupRanges = sql->getAggregationRollupRanges(~inMemoryStore)
+
+    let writes = rollupRanges->Promise.then(_ =>
+      Belt.Array.concatMany([
+        //Rollback tables need to happen first in the traction
+        rollbackTables,
+        [setEventSyncState, setRawEvents],
+        setEntities,
+      ])
+      ->Belt.Array.map(dbFunc => sql->dbFunc)
+      ->Promise.all
+    )
+
+    //Aggregations are rolled up from the written timeseries rows,
+    //so they can only run once all the writes are done
+    let rollups =
+      writes->Promise.then(_ =>
+        rollupRanges->Promise.then(rollupRanges => sql->executeAggregationRollups(~rollupRanges))
+      )
+
+    [writes->Promise.thenResolve(_ => ()), rollups]
   })
 
   res
@@ -249,10 +389,7 @@ module RollBack = {
           switch historyRow.entityData {
           | Set(entity) =>
             entityTable->InMemoryTable.Entity.set(
-              Set(entity)->Types.mkEntityUpdate(
-                ~eventIdentifier,
-                ~entityId=entity->Entities.getEntityId,
-              ),
+              Set(entity)->Types.mkEntityUpdate(~eventIdentifier, ~entityId=entity->Entity.getKey),
               ~shouldSaveHistory=false,
             )
           | Delete({id}) =>
@@ -14,7 +14,7 @@ module EntityTables = {
     let init = Js.Dict.empty()
     entities->Belt.Array.forEach(entity => {
       let module(Entity) = entity
-      init->Js.Dict.set((Entity.name :> string), InMemoryTable.Entity.make())
+      init->Js.Dict.set((Entity.name :> string), InMemoryTable.Entity.make(~getKey=Entity.getKey))
     })
     init
   }
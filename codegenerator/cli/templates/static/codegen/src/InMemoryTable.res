@@ -33,6 +33,7 @@ module Entity = {
   type t<'entity> = {
     table: t<Types.id, entityWithIndices<'entity>>,
     fieldNameIndices: indexFieldNameToIndices,
+    getKey: 'entity => Types.id,
   }
 
   let makeIndicesSerializedToValue = (
@@ -44,9 +45,10 @@ module Entity = {
     empty
   }
 
-  let make = (): t<'entity> => {
+  let make = (~getKey): t<'entity> => {
     table: make(~hash=str => str),
     fieldNameIndices: make(~hash=TableIndices.Index.getFieldName),
+    getKey,
   }
 
   exception UndefinedKey(string)
@@ -83,10 +85,10 @@ module Entity = {
         ->Array.forEach(((index, relatedEntityIds)) => {
           if index->TableIndices.Index.evaluate(~fieldName, ~fieldValue) {
             //Add entity id to indices and add index to entity indicies
-            relatedEntityIds->Utils.Set.add(Entities.getEntityIdUnsafe(entity))->ignore
+            relatedEntityIds->Utils.Set.add(self.getKey(entity))->ignore
             entityIndices->Utils.Set.add(index)->ignore
           } else {
-            relatedEntityIds->Utils.Set.delete(Entities.getEntityIdUnsafe(entity))->ignore
+            relatedEntityIds->Utils.Set.delete(self.getKey(entity))->ignore
           }
         })
       | _ =>
@@ -242,7 +244,7 @@ module Entity = {
           ->Js.Dict.unsafeGet(fieldName)
         if index->TableIndices.Index.evaluate(~fieldName, ~fieldValue) {
           let _ = row.entityIndices->Utils.Set.add(index)
-          let _ = relatedEntityIds->Utils.Set.add(entity->Entities.getEntityIdUnsafe)
+          let _ = relatedEntityIds->Utils.Set.add(entity->inMemTable.getKey)
         }
       | None => ()
       }
@@ -291,7 +293,7 @@ module Entity = {
     ->Array.keepMap(rowToEntity)
   }
 
-  let clone = ({table, fieldNameIndices}: t<'entity>) => {
+  let clone = ({table, fieldNameIndices, getKey}: t<'entity>) => {
     table: table->clone,
     fieldNameIndices: {
       ...fieldNameIndices,
@@ -300,5 +302,6 @@ module Entity = {
       ->Array.map(((k, v)) => (k, v->clone))
       ->Js.Dict.fromArray,
     },
+    getKey,
   }
 }
@@ -107,6 +107,7 @@ let executeLoadEntitiesById = async (
   ~inMemoryStore,
 ) => {
   let {entityMod, logger} = batchQueue
+  let module(Entity) = entityMod
 
   try {
     let inMemTable = inMemoryStore->InMemoryStore.getInMemTable(~entityMod)
@@ -118,7 +119,7 @@ let executeLoadEntitiesById = async (
     let entitiesMap = Js.Dict.empty()
     for idx in 0 to entities->Array.length - 1 {
       let entity = entities->Js.Array2.unsafe_get(idx)
-      entitiesMap->Js.Dict.set(entity->Entities.getEntityId, entity)
+      entitiesMap->Js.Dict.set(entity->Entity.getKey, entity)
     }
     idsToLoad->Array.forEach(entityId => {
       //Set the entity in the in memory store
@@ -149,6 +150,7 @@ let executeLoadEntitiesByIndex = async (
   ~inMemoryStore,
 ) => {
   let {entityMod, logger} = batchQueue
+  let module(Entity) = entityMod
 
   try {
     let inMemTable = inMemoryStore->InMemoryStore.getInMemTable(~entityMod)
@@ -183,7 +185,7 @@ let executeLoadEntitiesByIndex = async (
         //Set the entity in the in memory store
         inMemTable->InMemoryTable.Entity.initValue(
           ~allowOverWriteEntity=false,
-          ~key=Entities.getEntityId(entity),
+          ~key=Entity.getKey(entity),
           ~entity=Some(entity),
         )
       })
@@ -52,3 +52,11 @@ module Fs = {
     external readFile: (~filepath: Path.t, ~encoding: encoding) => promise<string> = "readFile"
   }
 }
+
+module Crypto = {
+  type hash
+  @module("crypto") external createHash: string => hash = "createHash"
+  @send external update: (hash, string) => hash = "update"
+  type encoding = | @as("hex") Hex
+  @send external digest: (hash, encoding) => string = "digest"
+}
@@ -47,6 +47,8 @@ SELECT * FROM unnest(${arrayFieldTypes
       switch (isRawEvents, primaryKeyFieldNames) {
       | (true, _)
       | (_, []) => ``
+      // Immutable rows are never overwritten, so inserting an existing one fails
+      | (false, _) if table.isImmutable => ``
       | (false, primaryKeyFieldNames) =>
         `ON CONFLICT(${primaryKeyFieldNames
           ->Js.Array2.map(quote)
@@ -262,6 +264,7 @@ module EntityHistory = {
   external getRollbackDiffInternal: (
     Postgres.sql,
     ~entityName: Enums.EntityType.t,
+    ~primaryKeyFieldNames: array<string>,
     ~getFirstChangeSerial: Postgres.sql => dynamicSqlQuery,
   ) => //Returns an array of entity history rows
   promise<Js.Json.t> = "getRollbackDiff"
@@ -282,6 +285,7 @@ module EntityHistory = {
   external pruneStaleEntityHistoryInternal: (
     Postgres.sql,
     ~entityName: Enums.EntityType.t,
+    ~primaryKeyFieldNames: array<string>,
     ~safeChainIdAndBlockNumberArray: array<chainIdAndBlockNumber>,
     // shouldDeepClean is a boolean that determines whether to delete stale history
     // items of entities that are in the reorg threshold (expensive to calculate)
@@ -292,13 +296,17 @@ module EntityHistory = {
   let rollbacksGroup = "Rollbacks"
 
   let pruneStaleEntityHistory = async (
+    type entity,
     sql,
-    ~entityName,
+    ~entityMod: module(Entities.Entity with type t = entity),
     ~safeChainIdAndBlockNumberArray,
     ~shouldDeepClean,
   ) => {
+    let module(Entity) = entityMod
+    let entityName = Entity.name
     try await sql->pruneStaleEntityHistoryInternal(
       ~entityName,
+      ~primaryKeyFieldNames=Entity.table->Table.getPrimaryKeyFieldNames,
       ~safeChainIdAndBlockNumberArray,
       ~shouldDeepClean,
     ) catch {
@@ -437,6 +445,7 @@ module EntityHistory = {
       sql,
       ~getFirstChangeSerial=args->Args.makeGetFirstChangeSerial(~entityName=Entity.name),
       ~entityName=Entity.name,
+      ~primaryKeyFieldNames=Entity.table->Table.getPrimaryKeyFieldNames,
     ) {
     | exception exn =>
       exn->ErrorHandling.mkLogAndRaise(
@@ -30,6 +30,26 @@ let makeReadEntities = (~table: Table.table, ~rowsSchema: S.t<array<'entityRow>>
   }
 }
 
+type postgresError = {code: Js.Nullable.t<string>, detail: Js.Nullable.t<string>}
+
+//Returns the key columns and values of the row violating a unique constraint,
+//Postgres describes them in the error detail as: Key (id)=(<id>) already exists.
+let getUniqueViolationKey = exn =>
+  switch exn {
+  | Js.Exn.Error(jsExn) =>
+    let {code, detail} = jsExn->(Utils.magic: Js.Exn.t => postgresError)
+    switch (code->Js.Nullable.toOption, detail->Js.Nullable.toOption) {
+    //23505 is the Postgres error code of a unique constraint violation
+    | (Some("23505"), Some(detail)) =>
+      switch detail->Js.String2.match_(%re(`/^Key \((.*)\)=\((.*)\) already exists\.$/`)) {
+      | Some([_, Some(columns), Some(values)]) => Some((columns, values))
+      | _ => None
+      }
+    | _ => None
+    }
+  | _ => None
+  }
+
 let makeBatchSet = (~table: Table.table, ~schema: S.t<'entity>) => {
   let query = DbFunctions.makeTableBatchSet(table, schema)
   async (sql: Postgres.sql, entities: array<'entity>, ~logger=?) => {
@@ -40,10 +60,14 @@ let makeBatchSet = (~table: Table.table, ~schema: S.t<'entity>) => {
         ~msg=`Failed during batch serialization of entity ${table.tableName}`,
       )
     | exception exn =>
-      exn->ErrorHandling.mkLogAndRaise(
-        ~logger?,
-        ~msg=`Failed during batch set of entity ${table.tableName}`,
-      )
+      let msg = switch (table.isImmutable, exn->getUniqueViolationKey) {
+      //The in memory store only catches updates of immutable entities it has loaded,
+      //the ones already in the database are caught by the insert failing
+      | (true, Some((columns, values))) =>
+        `Failed during batch set of entity ${table.tableName}. The entity ${table.tableName} with ${columns} ${values} is immutable and already exists in the database, so it can't be set again`
+      | _ => `Failed during batch set of entity ${table.tableName}`
+      }
+      exn->ErrorHandling.mkLogAndRaise(~logger?, ~msg)
     | res => res
     }
   }
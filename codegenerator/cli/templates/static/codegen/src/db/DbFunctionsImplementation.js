@@ -24,6 +24,27 @@ const commaSeparateDynamicMapQuery = (sql, dynQueryConstructors) =>
       }`
   )}`;
 
+// Comma separated primary key columns, optionally qualified by a table alias
+const keyColumns = (sql, primaryKeyFieldNames, alias) =>
+  commaSeparateDynamicMapQuery(
+    sql,
+    primaryKeyFieldNames.map((fieldName) => (sql) =>
+      alias ? sql`${sql(alias)}.${sql(fieldName)}` : sql(fieldName)
+    )
+  );
+
+// Matches the primary key columns of two table aliases
+const keysMatch = (sql, primaryKeyFieldNames, aliasA, aliasB) =>
+  Utils.$$Array.interleave(
+    primaryKeyFieldNames.map(
+      (fieldName) =>
+        sql`${sql(aliasA)}.${sql(fieldName)} = ${sql(aliasB)}.${sql(
+          fieldName
+        )}`
+    ),
+    sql` AND `
+  );
+
 module.exports.batchDeleteItemsInTable = (table, sql, pkArray) => {
   const primaryKeyFieldNames = TableModule.getPrimaryKeyFieldNames(table);
 
@@ -34,8 +55,13 @@ module.exports.batchDeleteItemsInTable = (table, sql, pkArray) => {
       WHERE ${sql(primaryKeyFieldNames[0])} IN ${sql(pkArray)};
       `;
   } else {
-    //TODO, if needed create a delete query for multiple field matches
-    //May be best to make pkArray an array of objects with fieldName -> value
+    return sql`
+      DELETE
+      FROM ${sql(publicSchema)}.${sql(table.tableName)}
+      WHERE (${keyColumns(sql, primaryKeyFieldNames)}) IN ${sql(
+      pkArray.map(TableModule.parseCompositeKey)
+    )};
+      `;
   }
 };
 
@@ -49,8 +75,13 @@ module.exports.batchReadItemsInTable = (table, sql, pkArray) => {
       WHERE ${sql(primaryKeyFieldNames[0])} IN ${sql(pkArray)};
       `;
   } else {
-    //TODO, if needed create a select query for multiple field matches
-    //May be best to make pkArray an array of objects with fieldName -> value
+    return sql`
+      SELECT *
+      FROM ${sql(publicSchema)}.${sql(table.tableName)}
+      WHERE (${keyColumns(sql, primaryKeyFieldNames)}) IN ${sql(
+      pkArray.map(TableModule.parseCompositeKey)
+    )};
+      `;
   }
 };
 
@@ -153,6 +184,12 @@ module.exports.makeBatchSetEntityValues = (table) => {
   );
 
   return chunkBatchQuery((sql, rowDataArray) => {
+    // Immutable rows are never overwritten, so inserting an existing one fails
+    if (table.isImmutable) {
+      return sql`
+INSERT INTO ${sql(publicSchema)}.${sql(table.tableName)}
+${sql(rowDataArray, ...fieldNames)};`;
+    }
     return sql`
 INSERT INTO ${sql(publicSchema)}.${sql(table.tableName)}
 ${sql(rowDataArray, ...fieldNames)}
@@ -358,6 +395,7 @@ module.exports.deleteRolledBackEntityHistory = (
 module.exports.pruneStaleEntityHistory = (
   sql,
   entityName,
+  primaryKeyFieldNames,
   safeChainIdAndBlockNumberArray,
   // shouldDeepCleanHistory is a boolean that determines whether to delete stale history
   // items of entities that are in the reorg threshold (expensive to calculate)
@@ -382,13 +420,13 @@ module.exports.pruneStaleEntityHistory = (
   ),
   items_in_reorg_threshold AS (
     SELECT DISTINCT
-      ON (id) *
+      ON (${keyColumns(sql, primaryKeyFieldNames)}) *
     FROM
       ${sql(publicSchema)}.${sql(tableName)}
     WHERE
       serial >= (SELECT first_change_serial FROM first_change)
     ORDER BY
-      id,
+      ${keyColumns(sql, primaryKeyFieldNames)},
       serial ASC -- Select the row with the lowest serial per id
   )${
     shouldDeepClean
@@ -396,14 +434,14 @@ module.exports.pruneStaleEntityHistory = (
   -- Select all the previous history items for each id in the reorg threshold
   ,previous_items AS (
     SELECT
-      prev.id,
+      ${keyColumns(sql, primaryKeyFieldNames, "prev")},
       prev.serial
     FROM
       ${sql(publicSchema)}.${sql(tableName)} prev
     INNER JOIN
       items_in_reorg_threshold r
     ON
-      r.id = prev.id
+      ${keysMatch(sql, primaryKeyFieldNames, "r", "prev")}
       AND
       r.previous_entity_history_chain_id = prev.entity_history_chain_id
       AND
@@ -417,19 +455,32 @@ module.exports.pruneStaleEntityHistory = (
     ${sql(publicSchema)}.${sql(tableName)} eh
   WHERE
     -- Delete all entity history of entities that are not in the reorg threshold
-    eh.id NOT IN (SELECT id FROM items_in_reorg_threshold)
+    (${keyColumns(sql, primaryKeyFieldNames, "eh")}) NOT IN (SELECT ${keyColumns(
+      sql,
+      primaryKeyFieldNames
+    )} FROM items_in_reorg_threshold)
   ${
     shouldDeepClean
       ? sql`
     -- Delete all rows where id matches a row in previous_items but has a lower serial
     OR 
-    eh.serial < (SELECT serial FROM previous_items WHERE previous_items.id = eh.id)`
+    eh.serial < (SELECT serial FROM previous_items WHERE ${keysMatch(
+      sql,
+      primaryKeyFieldNames,
+      "previous_items",
+      "eh"
+    )})`
       : sql``
   }
 `;
 };
 
-module.exports.getRollbackDiff = (sql, entityName, getFirstChangeSerial) => sql`
+module.exports.getRollbackDiff = (
+  sql,
+  entityName,
+  primaryKeyFieldNames,
+  getFirstChangeSerial
+) => sql`
   WITH
     first_change AS (
       -- Step 1: Find the "first change" serial originating from the reorg chain above the safe block number 
@@ -439,7 +490,7 @@ module.exports.getRollbackDiff = (sql, entityName, getFirstChangeSerial) => sql`
     rollback_ids AS (
       -- Step 2: Get all unique entity ids of rows that require rollbacks where the row's serial is above the first change serial
       SELECT DISTINCT
-        ON (id) after.*
+        ON (${keyColumns(sql, primaryKeyFieldNames)}) after.*
       FROM
         ${sql(publicSchema)}.${sql(makeHistoryTableName(entityName))} after
       WHERE
@@ -450,7 +501,7 @@ module.exports.getRollbackDiff = (sql, entityName, getFirstChangeSerial) => sql`
             first_change
         )
       ORDER BY
-        after.id,
+        ${keyColumns(sql, primaryKeyFieldNames, "after")},
         after.serial ASC -- Select the row with the lowest serial per id
     )
   -- Step 3: For each relevant id, join to the row on the "previous_entity_history" fields
@@ -459,7 +510,15 @@ module.exports.getRollbackDiff = (sql, entityName, getFirstChangeSerial) => sql`
     before.*,
     -- In the case where no previous row exists, coalesce the needed values since this new entity
     -- will need to be deleted
-    COALESCE(before.id, after.id) AS id,
+    ${commaSeparateDynamicMapQuery(
+      sql,
+      primaryKeyFieldNames.map(
+        (fieldName) => (sql) =>
+          sql`COALESCE(before.${sql(fieldName)}, after.${sql(
+            fieldName
+          )}) AS ${sql(fieldName)}`
+      )
+    )},
     COALESCE(before.action, 'DELETE') AS action,
     -- Deleting at 0 values will work fine for future rollbacks
     COALESCE(before.entity_history_block_number, 0) AS entity_history_block_number,
@@ -469,7 +528,12 @@ module.exports.getRollbackDiff = (sql, entityName, getFirstChangeSerial) => sql`
   FROM
     -- Use a RIGHT JOIN, to ensure that nulls get returned if there is no "before" row
     ${sql(publicSchema)}.${sql(makeHistoryTableName(entityName))} before
-    RIGHT JOIN rollback_ids after ON before.id = after.id
+    RIGHT JOIN rollback_ids after ON ${keysMatch(
+      sql,
+      primaryKeyFieldNames,
+      "before",
+      "after"
+    )}
     AND before.entity_history_block_timestamp = after.previous_entity_history_block_timestamp
     AND before.entity_history_chain_id = after.previous_entity_history_chain_id
     AND before.entity_history_block_number = after.previous_entity_history_block_number
@@ -1,6 +1,27 @@
 let sql = Db.sql
 let unsafe = Postgres.unsafe
 
+let makeFullTextSearchColumn = ({name, fields, language}: Table.fullTextSearch) => {
+  //The regconfig cast makes to_tsvector immutable, which generated columns require
+  let document =
+    fields->Js.Array2.map(field => `coalesce("${field}", '')`)->Js.Array2.joinWith(` || ' ' || `)
+  `"${name}" tsvector GENERATED ALWAYS AS (to_tsvector('${language}'::regconfig, ${document})) STORED`
+}
+
+//Search function tracked in Hasura, returning the matching rows ordered by rank
+let makeFullTextSearchFunctionQuery = (
+  ~tableName,
+  {name, language}: Table.fullTextSearch,
+) => {
+  let query = `websearch_to_tsquery('${language}'::regconfig, search)`
+  `CREATE OR REPLACE FUNCTION "${Env.Db.publicSchema}"."${name}"(search text)
+  RETURNS SETOF "${Env.Db.publicSchema}"."${tableName}" AS $$
+    SELECT * FROM "${Env.Db.publicSchema}"."${tableName}"
+    WHERE "${name}" @@ ${query}
+    ORDER BY ts_rank("${name}", ${query}) DESC;
+  $$ LANGUAGE sql STABLE;`
+}
+
 let creatTableIfNotExists = (sql, table) => {
   open Belt
   let fieldsMapped =
@@ -17,6 +38,7 @@ let creatTableIfNotExists = (sql, table) => {
           }}`
       }
     })
+    ->Array.concat(table.fullTextSearches->Array.map(makeFullTextSearchColumn))
     ->Js.Array2.joinWith(", ")
 
   let primaryKeyFieldNames = table->Table.getPrimaryKeyFieldNames
@@ -33,26 +55,154 @@ let creatTableIfNotExists = (sql, table) => {
   sql->unsafe(query)
 }
 
-let makeCreateIndexQuery = (~tableName, ~indexFields) => {
-  let indexName = tableName ++ "_" ++ indexFields->Js.Array2.joinWith("_")
-  let index = indexFields->Belt.Array.map(idx => `"${idx}"`)->Js.Array2.joinWith(", ")
-  `CREATE INDEX IF NOT EXISTS "${indexName}" ON "${Env.Db.publicSchema}"."${tableName}"(${index}); `
+//Table and column comments are shown by Hasura as descriptions in the GraphQL schema
+let makeCommentQuery = (table: Table.table) => {
+  let escape = comment => `'${comment->Js.String2.replaceByRe(%re("/'/g"), "''")}'`
+  let tableName = `"${Env.Db.publicSchema}"."${table.tableName}"`
+  let tableComment = switch table.description {
+  | Some(description) => [`COMMENT ON TABLE ${tableName} IS ${escape(description)};`]
+  | None => []
+  }
+  let columnComments = table->Table.getFields->Belt.Array.keepMap(field =>
+    field.description->Belt.Option.map(description =>
+      `COMMENT ON COLUMN ${tableName}."${field->Table.getDbFieldName}" IS ${escape(description)};`
+    )
+  )
+  Belt.Array.concat(tableComment, columnComments)->Js.Array2.joinWith("\n")
+}
+
+//Renames the tables and columns marked with @renamedFrom, together with their history tables.
+//Every statement checks the current state of the db, so it's safe to run it more than once.
+let makeRenameQueries = (table: Table.table) => {
+  let schemaName = Env.Db.publicSchema
+  let historyTableName = tableName => tableName ++ "_history"
+  let renameTable = (~oldName, ~newName) =>
+    `ALTER TABLE IF EXISTS "${schemaName}"."${oldName}" RENAME TO "${newName}";`
+  let renameColumn = (~tableName, ~oldName, ~newName) => `DO $$ BEGIN
+      IF EXISTS(SELECT 1 FROM information_schema.columns WHERE table_schema = '${schemaName}' AND table_name = '${tableName}' AND column_name = '${oldName}') THEN
+        ALTER TABLE "${schemaName}"."${tableName}" RENAME COLUMN "${oldName}" TO "${newName}";
+      END IF;
+    END $$;`
+  //Indices are prefixed with the table name, so they are renamed along with the table
+  let renameIndices = (~oldName, ~newName) => {
+    let suffixStart = (oldName->Js.String2.length + 1)->Belt.Int.toString
+    `DO $$ DECLARE idx record; BEGIN
+      FOR idx IN SELECT indexname FROM pg_indexes WHERE schemaname = '${schemaName}' AND tablename = '${newName}' AND left(indexname, ${suffixStart}) = '${oldName}_' LOOP
+        EXECUTE format('ALTER INDEX %I.%I RENAME TO %I', '${schemaName}', idx.indexname, '${newName}' || substr(idx.indexname, ${suffixStart}));
+      END LOOP;
+    END $$;`
+  }
+
+  let tableRenames = switch table.renamedFrom {
+  | Some(renamedFrom) => [
+      renameTable(~oldName=renamedFrom, ~newName=table.tableName),
+      renameTable(~oldName=renamedFrom->historyTableName, ~newName=table.tableName->historyTableName),
+      renameIndices(~oldName=renamedFrom, ~newName=table.tableName),
+      renameIndices(
+        ~oldName=renamedFrom->historyTableName,
+        ~newName=table.tableName->historyTableName,
+      ),
+      //The insert function is recreated with the new history table name
+      `DROP FUNCTION IF EXISTS "insert_${renamedFrom->historyTableName}";`,
+    ]
+  | None => []
+  }
+
+  let columnRenames = table->Table.getFields->Belt.Array.map(field =>
+    switch field->Table.getRenamedFromDbFieldName {
+    | Some(oldName) =>
+      let newName = field->Table.getDbFieldName
+      [
+        renameColumn(~tableName=table.tableName, ~oldName, ~newName),
+        renameColumn(~tableName=table.tableName->historyTableName, ~oldName, ~newName),
+      ]
+    | None => []
+    }
+  )
+
+  Belt.Array.concat(tableRenames, columnRenames->Belt.Array.concatMany)->Js.Array2.joinWith("\n")
+}
+
+let getShortHash = value =>
+  NodeJsLocal.Crypto.createHash("md5")
+  ->NodeJsLocal.Crypto.update(value)
+  ->NodeJsLocal.Crypto.digest(Hex)
+  ->Js.String2.slice(~from=0, ~to_=8)
+
+//Postgres truncates longer identifiers, so indices with a common prefix would clash
+let maxIdentifierLength = 63
+
+//Cuts a long name to fit, ending it with the hash of the full name to keep it unique
+let shortenIdentifier = name =>
+  if name->Js.String2.length <= maxIdentifierLength {
+    name
+  } else {
+    let hash = name->getShortHash
+    name->Js.String2.slice(~from=0, ~to_=maxIdentifierLength - hash->Js.String2.length - 1) ++
+    "_" ++
+    hash
+  }
+
+let makeCreateIndexQuery = (
+  ~tableName,
+  ~indexFields,
+  ~method: option<Table.indexMethod>=?,
+  ~order: option<Table.indexOrder>=?,
+  ~where=?,
+) => {
+  //Options are part of the name, so indices on the same fields don't clash
+  let indexName =
+    tableName ++
+    "_" ++
+    indexFields->Js.Array2.joinWith("_") ++
+    switch method {
+    | Some(method) if method !== Btree => "_" ++ (method :> string)
+    | _ => ""
+    } ++
+    switch order {
+    | Some(Desc) => "_desc"
+    | _ => ""
+    } ++
+    switch where {
+    //Partial indices on the same fields need a different name per predicate
+    | Some(where) => "_partial_" ++ where->getShortHash
+    | None => ""
+    }
+  let orderSuffix = switch order {
+  | Some(order) => " " ++ (order :> string)
+  | None => ""
+  }
+  let index =
+    indexFields->Belt.Array.map(idx => `"${idx}"${orderSuffix}`)->Js.Array2.joinWith(", ")
+  let using = switch method {
+  | Some(method) => ` USING ${(method :> string)}`
+  | None => ""
+  }
+  let whereClause = switch where {
+  | Some(where) => ` WHERE ${where}`
+  | None => ""
+  }
+  `CREATE INDEX IF NOT EXISTS "${indexName->shortenIdentifier}" ON "${Env.Db.publicSchema}"."${tableName}"${using}(${index})${whereClause}; `
 }
 
 let createTableIndices = (sql, table: Table.table) => {
   open Belt
   let tableName = table.tableName
   let createIndex = indexField => makeCreateIndexQuery(~tableName, ~indexFields=[indexField])
-  let createCompositeIndex = indexFields => {
-    makeCreateIndexQuery(~tableName, ~indexFields)
+  let createCompositeIndex = ({fieldNames, method, order, where}: Table.compositeIndex) => {
+    makeCreateIndexQuery(~tableName, ~indexFields=fieldNames, ~method?, ~order?, ~where?)
   }
 
+  let createFullTextSearchIndex = ({name}: Table.fullTextSearch) =>
+    makeCreateIndexQuery(~tableName, ~indexFields=[name], ~method=Gin)
+
   let singleIndices = table->Table.getSingleIndices
   let compositeIndices = table->Table.getCompositeIndices
 
   let query =
     singleIndices->Array.map(createIndex)->Js.Array2.joinWith("\n") ++
-      compositeIndices->Array.map(createCompositeIndex)->Js.Array2.joinWith("\n")
+    compositeIndices->Array.map(createCompositeIndex)->Js.Array2.joinWith("\n") ++
+    table.fullTextSearches->Array.map(createFullTextSearchIndex)->Js.Array2.joinWith("\n")
 
   sql->unsafe(query)
 }
@@ -80,6 +230,14 @@ let createEnumIfNotExists = (sql, enum: Enum.enum<_>) => {
   sql->unsafe(query)
 }
 
+//Values are added one statement at a time, since ADD VALUE can't run in a
+//transaction block together with other statements. IF NOT EXISTS appends only
+//the values after the ones already in the type.
+let makeAddEnumValueQueries = (enum: Enum.enum<_>) => {
+  let {variants, name} = enum
+  variants->Belt.Array.map(v => `ALTER TYPE ${name} ADD VALUE IF NOT EXISTS '${v->Utils.magic}';`)
+}
+
 let deleteAllTables: unit => promise<unit> = async () => {
   Logging.trace("Dropping all tables")
   let query = `
@@ -136,6 +294,13 @@ let runUpMigrations = async (~shouldExit) => {
     await createTableIndices(Db.sql, table)->handleFailure(
       ~msg=`EE800: Error creating ${table.tableName} indices`,
     )
+    switch makeCommentQuery(table) {
+    | "" => ()
+    | commentQuery =>
+      await Db.sql
+      ->unsafe(commentQuery)
+      ->handleFailure(~msg=`EE800: Error adding comments to ${table.tableName} table`)
+    }
   })
 
   await Db.allEntityHistory->awaitEach(async entityHistory => {
@@ -144,6 +309,17 @@ let runUpMigrations = async (~shouldExit) => {
     ->handleFailure(~msg=`EE800: Error creating ${entityHistory.table.tableName} insert function`)
   })
 
+  //Create the search functions of all full text searches
+  await Db.allEntityTables->awaitEach(async table => {
+    await table.fullTextSearches->awaitEach(fullTextSearch => {
+      sql
+      ->unsafe(makeFullTextSearchFunctionQuery(~tableName=table.tableName, fullTextSearch))
+      ->handleFailure(
+        ~msg=`EE800: Error creating the "${fullTextSearch.name}" full text search function of entity "${table.tableName}"`,
+      )
+    })
+  })
+
   //Create all derivedFromField indices (must be done after all tables are created)
   await Db.allEntityTables
   ->awaitEach(async table => {
@@ -181,6 +357,96 @@ let runDownMigrations = async (~shouldExit) => {
   exitCode.contents
 }
 
+//Applies the @renamedFrom hints in place, keeping the indexed data.
+//Used instead of setupDb when the renames are the only change to the schema.
+let runRenameMigrations = async (~shouldExit) => {
+  let exitCode = ref(Success)
+  let logger = Logging.createChild(~params={"context": "Running DB Rename Migrations"})
+
+  let handleFailure = async (res, ~msg) =>
+    switch await res {
+    | exception exn =>
+      exitCode := Failure
+      exn->ErrorHandling.make(~msg, ~logger)->ErrorHandling.log
+    | _ => ()
+    }
+
+  await Db.allEntityTables->awaitEach(async table => {
+    switch makeRenameQueries(table) {
+    | "" => ()
+    | renameQuery =>
+      await sql
+      ->unsafe(renameQuery)
+      ->handleFailure(~msg=`EE800: Error renaming ${table.tableName} table and its columns`)
+    }
+  })
+
+  //Functions reference the tables and columns by name, so they are all recreated
+  await Db.allEntityHistory->awaitEach(async entityHistory => {
+    await sql
+    ->Postgres.unsafe(entityHistory.createInsertFnQuery)
+    ->handleFailure(~msg=`EE800: Error creating ${entityHistory.table.tableName} insert function`)
+  })
+
+  await Db.allEntityTables->awaitEach(async table => {
+    await table.fullTextSearches->awaitEach(fullTextSearch => {
+      sql
+      ->unsafe(makeFullTextSearchFunctionQuery(~tableName=table.tableName, fullTextSearch))
+      ->handleFailure(
+        ~msg=`EE800: Error creating the "${fullTextSearch.name}" full text search function of entity "${table.tableName}"`,
+      )
+    })
+  })
+
+  //Renamed tables are untracked under their old names before everything is tracked again
+  await Db.allEntityTables->awaitEach(async table =>
+    switch table.renamedFrom {
+    | Some(renamedFrom) =>
+      await TrackTables.untrackTable(~tableName=renamedFrom)
+      await TrackTables.untrackTable(~tableName=renamedFrom ++ "_history")
+    | None => ()
+    }
+  )
+
+  await TrackTables.trackAllTables()->Promise.catch(err => {
+    Logging.errorWithExn(err, `EE803: Error tracking tables`)->Promise.resolve
+  })
+
+  if shouldExit {
+    process->exit(exitCode.contents)
+  }
+  exitCode.contents
+}
+
+//Adds the values appended to the schema enums to the existing enum types.
+//Used instead of setupDb when the appended values are the only change to the schema.
+let runEnumMigrations = async (~shouldExit) => {
+  let exitCode = ref(Success)
+  let logger = Logging.createChild(~params={"context": "Running DB Enum Migrations"})
+
+  let handleFailure = async (res, ~msg) =>
+    switch await res {
+    | exception exn =>
+      exitCode := Failure
+      exn->ErrorHandling.make(~msg, ~logger)->ErrorHandling.log
+    | _ => ()
+    }
+
+  await Enums.allEnums->awaitEach(async enum => {
+    let module(EnumMod) = enum
+    await makeAddEnumValueQueries(EnumMod.enum)->awaitEach(addValueQuery =>
+      sql
+      ->unsafe(addValueQuery)
+      ->handleFailure(~msg=`EE800: Error adding values to ${EnumMod.enum.name} enum`)
+    )
+  })
+
+  if shouldExit {
+    process->exit(exitCode.contents)
+  }
+  exitCode.contents
+}
+
 let setupDb = async () => {
   Logging.info("Provisioning Database")
   // TODO: we should make a hash of the schema file (that gets stored in the DB) and either drop the tables and create new ones or keep this migration.
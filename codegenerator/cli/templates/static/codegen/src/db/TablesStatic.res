@@ -231,4 +231,6 @@ module DynamicContractRegistry = {
   )
 
   let entityHistory = table->EntityHistory.fromTable(~schema)
+
+  let getKey = (entity: t) => entity.id
 }
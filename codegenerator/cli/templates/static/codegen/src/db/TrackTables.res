@@ -27,7 +27,8 @@ let validateHasuraResponse = (~statusCode: int, ~responseJson: Js.Json.t): Belt.
     | decoded =>
       switch decoded.code {
       | "already-exists"
-      | "already-tracked" =>
+      | "already-tracked"
+      | "already-untracked" =>
         Ok(AlreadyDone)
       | _ =>
         //If the code is not known return it as an error
@@ -125,6 +126,104 @@ let trackTable = async (~tableName: string) => {
   }
 }
 
+let untrackTable = async (~tableName: string) => {
+  let body = {
+    "type": "pg_untrack_table",
+    "args": {
+      "source": "public",
+      "table": {
+        "schema": Env.Db.publicSchema,
+        "name": tableName,
+      },
+      "cascade": true,
+    },
+  }
+
+  Logging.trace({
+    "msg": `Untracking table ${tableName} in schema ${Env.Db.publicSchema}`,
+    "body": body,
+  })
+
+  let response = await fetch(
+    Env.Hasura.graphqlEndpoint,
+    {
+      method: #POST,
+      body: body->Js.Json.stringifyAny->Belt.Option.getExn->Body.string,
+      headers: Headers.fromObject(headers),
+    },
+  )
+
+  let responseJson = await response->Response.json
+  let statusCode = response->Response.status
+
+  switch validateHasuraResponse(~statusCode, ~responseJson) {
+  | Error(_) =>
+    Logging.error({
+      "msg": `EE810: There was an issue untracking the ${tableName} table in hasura - indexing may still work - but you may have issues querying the data in hasura.`,
+      "tableName": tableName,
+      "requestStatusCode": statusCode,
+      "requestResponseJson": responseJson,
+    })
+  | Ok(case) =>
+    let msg = switch case {
+    | QuerySucceeded => "Table Untracked"
+    | AlreadyDone => "Table Already Untracked"
+    }
+    Logging.trace({
+      "msg": msg,
+      "tableName": tableName,
+      "requestStatusCode": statusCode,
+      "requestResponseJson": responseJson,
+    })
+  }
+}
+
+let trackFunction = async (~functionName: string) => {
+  let body = {
+    "type": "pg_track_function",
+    "args": {
+      "source": "default",
+      "function": {
+        "schema": Env.Db.publicSchema,
+        "name": functionName,
+      },
+    },
+  }
+
+  let response = await fetch(
+    Env.Hasura.graphqlEndpoint,
+    {
+      method: #POST,
+      body: body->Js.Json.stringifyAny->Belt.Option.getExn->Body.string,
+      headers: Headers.fromObject(headers),
+    },
+  )
+
+  let responseJson = await response->Response.json
+  let statusCode = response->Response.status
+
+  switch validateHasuraResponse(~statusCode, ~responseJson) {
+  | Error(_) =>
+    Logging.error({
+      "msg": `EE809: There was an issue tracking the ${functionName} search function in hasura - indexing may still work - but you may have issues querying the data in hasura.`,
+      "functionName": functionName,
+      "requestStatusCode": statusCode,
+      "requestResponseJson": responseJson,
+    })
+  | Ok(case) =>
+    let msg = switch case {
+    | QuerySucceeded => "Function Tracked"
+    | AlreadyDone => "Function Already Tracked"
+    }
+    Logging.trace({
+      "msg": msg,
+      "functionName": functionName,
+      "requestStatusCode": statusCode,
+      "requestResponseJson": responseJson,
+    })
+  }
+}
+
 let createSelectPermissions = async (~tableName: string) => {
   let body = {
     "type": "pg_create_select_permission",
@@ -254,6 +353,11 @@ let trackAllTables = async () => {
       )
     })
 
+    //Track the search functions of full text searches
+    await table.fullTextSearches->Utils.Array.awaitEach(async ({name}) => {
+      await trackFunction(~functionName=name)
+    })
+
     //Set object relationships
     await table
     ->Table.getLinkedEntityFields
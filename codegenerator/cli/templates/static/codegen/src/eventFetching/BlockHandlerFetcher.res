@@ -0,0 +1,136 @@
+open Belt
+
+let contractName = "BlockHandlers"
+
+let zeroAddress = "0x0000000000000000000000000000000000000000"->Address.unsafeFromString
+
+/**
+Returns the block numbers in the range where the block handler should be called
+*/
+let getBlockNumbers = (
+  {interval, startBlock, endBlock}: Config.blockHandler,
+  ~fromBlock,
+  ~toBlock,
+) => {
+  let toBlock = switch endBlock {
+  | Some(endBlock) => Pervasives.min(endBlock, toBlock)
+  | None => toBlock
+  }
+  let fromBlock = Pervasives.max(fromBlock, startBlock)
+  let blockNumbers = []
+  let blockNumber = ref(fromBlock + mod(interval - mod(fromBlock - startBlock, interval), interval))
+  while blockNumber.contents <= toBlock {
+    blockNumbers->Array.push(blockNumber.contents)
+    blockNumber := blockNumber.contents + interval
+  }
+  blockNumbers
+}
+
+let fetchBlockRange = (
+  query: FetchState.query,
+  ~blockHandlers: array<Config.blockHandler>,
+  ~source: Source.t,
+  ~currentBlockHeight,
+  ~chain,
+  ~logger,
+) => {
+  let logIndexRange = BlockRangeFetcher.blockHandlerItemsLogIndexRange
+  let registeredBlockHandlers =
+    blockHandlers
+    ->Array.mapWithIndex((index, blockHandler) => {
+      let module(BlockHandler) = blockHandler.handler
+      BlockHandler.handlerRegister
+      ->Types.HandlerTypes.BlockRegister.getHandler
+      ->Option.map(handler => (blockHandler, handler, logIndexRange.firstLogIndex + index))
+    })
+    ->Array.keepMap(v => v)
+
+  query->BlockRangeFetcher.fetchBlockRange(
+    ~handlersName="block handlers",
+    ~selectItems=async (~toBlock, ~logger as _) => {
+      let fromBlock = query.fromBlock
+      let blockNumbersByHandler = registeredBlockHandlers->Array.map(((blockHandler, _, _)) =>
+        blockHandler->getBlockNumbers(~fromBlock, ~toBlock)
+      )
+
+      Ok({
+        BlockRangeFetcher.blockNumbers: blockNumbersByHandler->Array.concatMany,
+        makeItems: (~getBlockOrThrow) => {
+          if blockHandlers->Array.length > logIndexRange->BlockRangeFetcher.getRangeSize {
+            Js.Exn.raiseError(
+              `A chain can have up to ${logIndexRange
+                ->BlockRangeFetcher.getRangeSize
+                ->Int.toString} block handlers, since their items are ordered by the log indices reserved for them`,
+            )
+          }
+          let chainId = chain->ChainMap.Chain.toChainId
+          let parsedQueueItems = []
+
+          registeredBlockHandlers->Array.forEachWithIndex((
+            index,
+            (blockHandler, handler, logIndex),
+          ) => {
+            let module(BlockHandler) = blockHandler.handler
+
+            blockNumbersByHandler
+            ->Js.Array2.unsafe_get(index)
+            ->Array.forEach(blockNumber => {
+              let {blockHash, blockTimestamp} = getBlockOrThrow(blockNumber)
+              let block: Internal.blockHandlerBlock = {
+                number: blockNumber,
+                timestamp: blockTimestamp,
+                hash: blockHash,
+              }
+
+              parsedQueueItems->Array.push(
+                (
+                  {
+                    eventName: BlockHandler.name,
+                    contractName,
+                    loader: None,
+                    handler: Some(
+                      (args: Internal.handlerArgs) =>
+                        handler(
+                          ({block, chainId, context: args.context}: Internal.blockHandlerArgs),
+                        ),
+                    ),
+                    contractRegister: None,
+                    paramsRawEventSchema: BlockRangeFetcher.paramsRawEventSchema,
+                    timestamp: blockTimestamp,
+                    chain,
+                    blockNumber,
+                    logIndex,
+                    event: (
+                      {
+                        params: (),
+                        chainId,
+                        srcAddress: zeroAddress,
+                        logIndex,
+                        transaction: Js.Obj.empty(),
+                        block,
+                      }: Internal.genericEvent<unit, Internal.blockHandlerBlock, _>
+                    )->Internal.fromGenericEvent,
+                  }: Internal.eventItem
+                ),
+              )
+            })
+          })
+
+          parsedQueueItems
+          ->Js.Array2.sortInPlaceWith((a, b) =>
+            switch a.blockNumber - b.blockNumber {
+            | 0 => a.logIndex - b.logIndex
+            | diff => diff
+            }
+          )
+          ->ignore
+          parsedQueueItems
+        },
+      })
+    },
+    ~source,
+    ~currentBlockHeight,
+    ~chain,
+    ~logger,
+  )
+}
@@ -0,0 +1,187 @@
+open Belt
+
+// The block data for the whole query range is requested at once,
+// and sources don't allow ranges larger than 1000 blocks
+let maxBlocksPerQuery = 1000
+
+let paramsRawEventSchema =
+  S.literal(%raw(`null`))
+  ->S.to(_ => ())
+  ->(Utils.magic: S.t<unit> => S.t<Internal.eventParams>)
+
+type logIndexRange = {firstLogIndex: int, lastLogIndex: int}
+
+// Handler items are ordered after the logs of their block by giving them log indices
+// from the top half of the 16 bit range the log index is packed into for the event id.
+// The transaction items come first, in the order of the transactions, then the call items,
+// in the order the calls were executed, and then the block handler items,
+// in the order the handlers are defined in the config.
+let transactionItemsLogIndexRange = {firstLogIndex: 32768, lastLogIndex: 40959}
+let callItemsLogIndexRange = {firstLogIndex: 40960, lastLogIndex: 61439}
+let blockHandlerItemsLogIndexRange = {firstLogIndex: 61440, lastLogIndex: 65535}
+
+// The logs of chains with handler partitions need to be below the reserved range
+let firstReservedLogIndex = transactionItemsLogIndexRange.firstLogIndex
+
+let getRangeSize = ({firstLogIndex, lastLogIndex}: logIndexRange) =>
+  lastLogIndex - firstLogIndex + 1
+
+/**
+Gives the items of each block consecutive log indices from the range.
+Raises when a block has more items than the range fits, since items with the same
+block number and log index would be processed in an undefined order.
+*/
+let makeLogIndexAllocator = (range: logIndexRange, ~itemsName) => {
+  let {firstLogIndex, lastLogIndex} = range
+  let lastBlockNumber = ref(-1)
+  let nextLogIndex = ref(firstLogIndex)
+  blockNumber => {
+    if lastBlockNumber.contents !== blockNumber {
+      lastBlockNumber := blockNumber
+      nextLogIndex := firstLogIndex
+    }
+    let logIndex = nextLogIndex.contents
+    if logIndex > lastLogIndex {
+      Js.Exn.raiseError(
+        `Block ${blockNumber->Int.toString} has more than ${range
+          ->getRangeSize
+          ->Int.toString} ${itemsName}, which is the most that can be ordered in a block`,
+      )
+    }
+    nextLogIndex := logIndex + 1
+    logIndex
+  }
+}
+
+/**
+Checks that the logs fetched by the event partitions are below the log indices
+reserved for the handler items, so they can't be ordered the same
+*/
+let checkLogIndices = (response: Source.blockRangeFetchResponse, ~logger) =>
+  switch response.parsedQueueItems->Js.Array2.find(item =>
+    item.logIndex >= firstReservedLogIndex
+  ) {
+  | None => Ok(response)
+  | Some(item) =>
+    Failure(
+      `The log ${item.logIndex->Int.toString} of block ${item.blockNumber->Int.toString} has an index reserved for the items of the block, transaction and call handlers. Chains with these handlers support up to ${firstReservedLogIndex->Int.toString} logs per block`,
+    )
+    ->ErrorHandling.make(~logger, ~msg="Failed to order the fetched logs")
+    ->Error
+  }
+
+type selectedItems = {
+  // The blocks the items need the hash and timestamp of
+  blockNumbers: array<int>,
+  makeItems: (~getBlockOrThrow: int => ReorgDetection.blockDataWithTimestamp) => array<
+    Internal.eventItem,
+  >,
+}
+
+/**
+Fetches a query of a partition which doesn't fetch events,
+but produces items for the blocks of the query range.
+The fetchers of the handlers only select the items, the block data and the reorg guard
+of the range are fetched here.
+*/
+let fetchBlockRange = async (
+  query: FetchState.query,
+  ~handlersName,
+  ~selectItems: (~toBlock: int, ~logger: Pino.t) => promise<result<selectedItems, exn>>,
+  ~source: Source.t,
+  ~currentBlockHeight,
+  ~chain,
+  ~logger,
+): result<Source.blockRangeFetchResponse, ErrorHandling.t> => {
+  let startFetchingBatchTimeRef = Hrtime.makeTimer()
+  let fromBlock = query.fromBlock
+  let logger = Logging.createChildFrom(
+    ~logger,
+    ~params={
+      "chainId": chain->ChainMap.Chain.toChainId,
+      "logType": `${handlersName} query`,
+      "partitionId": query.partitionId,
+      "fromBlock": fromBlock,
+    },
+  )
+
+  let toBlock = Pervasives.min(
+    fromBlock + maxBlocksPerQuery - 1,
+    switch query.target {
+    | Head => currentBlockHeight
+    | EndBlock({toBlock})
+    | Merge({toBlock}) =>
+      Pervasives.min(toBlock, currentBlockHeight)
+    },
+  )
+
+  switch await selectItems(~toBlock, ~logger) {
+  | Error(exn) => exn->ErrorHandling.make(~logger, ~msg=`Failed to fetch ${handlersName} data`)->Error
+  | Ok({blockNumbers, makeItems}) =>
+    let blockNumbersToFetch = Utils.Set.fromEntries(blockNumbers)
+    blockNumbersToFetch->Utils.Set.add(toBlock)->ignore
+    if fromBlock > 0 {
+      blockNumbersToFetch->Utils.Set.add(fromBlock - 1)->ignore
+    }
+
+    switch await source.getBlockHashes(
+      ~blockNumbers=blockNumbersToFetch->Utils.Set.toArray,
+      ~logger,
+    ) {
+    | Error(exn) =>
+      exn->ErrorHandling.make(~logger, ~msg=`Failed to fetch blocks for ${handlersName}`)->Error
+    | Ok(blocks) =>
+      let blocksByNumber = Js.Dict.empty()
+      blocks->Array.forEach(block =>
+        blocksByNumber->Js.Dict.set(block.blockNumber->Int.toString, block)
+      )
+      let getBlockOrThrow = blockNumber =>
+        switch blocksByNumber->Js.Dict.get(blockNumber->Int.toString) {
+        | Some(block) => block
+        | None =>
+          Js.Exn.raiseError(
+            `Block ${blockNumber->Int.toString} wasn't returned by the source ${source.name}`,
+          )
+        }
+
+      try {
+        let parsedQueueItems = makeItems(~getBlockOrThrow)
+        let latestFetchedBlock = getBlockOrThrow(toBlock)
+
+        let reorgGuard: ReorgDetection.reorgGuard = {
+          firstBlockParentNumberAndHash: fromBlock > 0
+            ? Some(getBlockOrThrow(fromBlock - 1)->ReorgDetection.generalizeBlockDataWithTimestamp)
+            : None,
+          lastBlockScannedData: latestFetchedBlock->ReorgDetection.generalizeBlockDataWithTimestamp,
+        }
+
+        let totalTimeElapsed =
+          startFetchingBatchTimeRef->Hrtime.timeSince->Hrtime.toMillis->Hrtime.intFromMillis
+
+        logger->Logging.childTrace({
+          "msg": `Fetched items for ${handlersName}`,
+          "toBlock": toBlock,
+          "numItems": parsedQueueItems->Array.length,
+        })
+
+        Ok(
+          (
+            {
+              currentBlockHeight,
+              reorgGuard,
+              parsedQueueItems,
+              fromBlockQueried: fromBlock,
+              latestFetchedBlockNumber: toBlock,
+              latestFetchedBlockTimestamp: latestFetchedBlock.blockTimestamp,
+              stats: {
+                totalTimeElapsed: totalTimeElapsed,
+              },
+            }: Source.blockRangeFetchResponse
+          ),
+        )
+      } catch {
+      | exn => exn->ErrorHandling.make(~logger, ~msg=`Failed to process items for ${handlersName}`)->Error
+      }
+    }
+  }
+}
@@ -0,0 +1,169 @@
+open Belt
+
+/**
+Decodes the call data into an object keyed by the ABI param names,
+the same way Viem decodes event params
+*/
+let decodeParamsOrThrow = (params: array<Viem.abiParameter>, data: string) => {
+  let values = Viem.decodeAbiParameters(params, data->EvmTypes.Hex.fromStringUnsafe)
+  let decoded = Js.Dict.empty()
+  params->Js.Array2.forEachi((param, index) =>
+    decoded->Js.Dict.set(param.name, values->Js.Array2.unsafe_get(index))
+  )
+  decoded
+}
+
+let compareTraceAddress = (a: array<int>, b: array<int>) => {
+  let rec loop = index =>
+    switch (a->Array.get(index), b->Array.get(index)) {
+    | (Some(a), Some(b)) if a === b => loop(index + 1)
+    | (Some(a), Some(b)) => a - b
+    | (None, Some(_)) => -1
+    | (Some(_), None) => 1
+    | (None, None) => 0
+    }
+  loop(0)
+}
+
+let fetchBlockRange = (
+  query: FetchState.query,
+  ~calls: array<module(Types.Call)>,
+  ~source: Source.t,
+  ~currentBlockHeight,
+  ~chain,
+  ~logger,
+) => {
+  let registeredCallsByKey = Js.Dict.empty()
+  let functionSelectors = Utils.Set.make()
+  calls->Array.forEach(call => {
+    let module(Call) = call
+    switch Call.handlerRegister->Types.HandlerTypes.CallRegister.getHandler {
+    | Some(handler)
+      if query.contractAddressMapping
+      ->ContractAddressingMap.getAddresses(Call.contractName)
+      ->Option.isSome =>
+      registeredCallsByKey->Js.Dict.set(
+        `${Call.contractName}_${Call.functionSelector}`,
+        (call, handler),
+      )
+      functionSelectors->Utils.Set.add(Call.functionSelector)->ignore
+    | _ => ()
+    }
+  })
+
+  query->BlockRangeFetcher.fetchBlockRange(
+    ~handlersName="call handlers",
+    ~selectItems=async (~toBlock, ~logger) =>
+      switch await source.getCallTraces(
+        ~fromBlock=query.fromBlock,
+        ~toBlock,
+        ~addresses=query.contractAddressMapping->ContractAddressingMap.getAllAddresses,
+        ~functionSelectors=functionSelectors->Utils.Set.toArray,
+        ~logger,
+      ) {
+      | Error(exn) => Error(exn)
+      | Ok(traces) =>
+        let traces =
+          traces
+          ->Array.copy
+          ->Js.Array2.sortInPlaceWith((a, b) =>
+            switch (a.blockNumber - b.blockNumber, a.transactionPosition - b.transactionPosition) {
+            | (0, 0) => compareTraceAddress(a.traceAddress, b.traceAddress)
+            | (0, diff)
+            | (diff, _) => diff
+            }
+          )
+
+        Ok({
+          BlockRangeFetcher.blockNumbers: traces->Array.map(trace => trace.blockNumber),
+          makeItems: (~getBlockOrThrow) => {
+            let chainId = chain->ChainMap.Chain.toChainId
+            let parsedQueueItems = []
+            let getLogIndex =
+              BlockRangeFetcher.callItemsLogIndexRange->BlockRangeFetcher.makeLogIndexAllocator(
+                ~itemsName="call handler items",
+              )
+
+            traces->Array.forEach(trace => {
+              let registeredCall = switch query.contractAddressMapping->ContractAddressingMap.getContractNameFromAddress(
+                ~contractAddress=trace.to,
+              ) {
+              | Some(contractName) =>
+                registeredCallsByKey->Js.Dict.get(
+                  `${contractName}_${trace.input->Js.String2.slice(~from=0, ~to_=10)}`,
+                )
+              | None => None
+              }
+
+              switch registeredCall {
+              | None => ()
+              | Some((call, handler)) =>
+                let module(Call) = call
+                let itemLogIndex = getLogIndex(trace.blockNumber)
+
+                let {blockHash, blockTimestamp} = getBlockOrThrow(trace.blockNumber)
+                let block: Internal.blockHandlerBlock = {
+                  number: trace.blockNumber,
+                  timestamp: blockTimestamp,
+                  hash: blockHash,
+                }
+                let callData: Internal.call = {
+                  inputs: decodeParamsOrThrow(
+                    Call.inputsAbi,
+                    "0x" ++ trace.input->Js.String2.sliceToEnd(~from=10),
+                  )->(Utils.magic: dict<unknown> => Internal.callInputs),
+                  outputs: decodeParamsOrThrow(Call.outputsAbi, trace.output)->(
+                    Utils.magic: dict<unknown> => Internal.callOutputs
+                  ),
+                  chainId,
+                  srcAddress: trace.to,
+                  from: trace.from,
+                  transactionHash: trace.transactionHash,
+                  traceAddress: trace.traceAddress,
+                  block,
+                }
+
+                parsedQueueItems->Array.push(
+                  (
+                    {
+                      eventName: Call.name,
+                      contractName: Call.contractName,
+                      loader: None,
+                      handler: Some(
+                        (args: Internal.handlerArgs) =>
+                          handler(
+                            ({call: callData, context: args.context}: Internal.callHandlerArgs),
+                          ),
+                      ),
+                      contractRegister: None,
+                      paramsRawEventSchema: BlockRangeFetcher.paramsRawEventSchema,
+                      timestamp: blockTimestamp,
+                      chain,
+                      blockNumber: trace.blockNumber,
+                      logIndex: itemLogIndex,
+                      event: (
+                        {
+                          params: (),
+                          chainId,
+                          srcAddress: trace.to,
+                          logIndex: itemLogIndex,
+                          transaction: Js.Obj.empty(),
+                          block,
+                        }: Internal.genericEvent<unit, Internal.blockHandlerBlock, _>
+                      )->Internal.fromGenericEvent,
+                    }: Internal.eventItem
+                  ),
+                )
+              }
+            })
+
+            parsedQueueItems
+          },
+        })
+      },
+    ~source,
+    ~currentBlockHeight,
+    ~chain,
+    ~logger,
+  )
+}
@@ -29,6 +29,13 @@ type t = {
   //in the future, have a per chain state and allow individual chains to start indexing as
   //soon as the pre registration is done
   dynamicContractPreRegistration: option<addressToDynContractLookup>,
+  //The contracts with registered call handlers,
+  //so the traces of their dynamic contracts are fetched as well
+  callHandlerContractNames: array<string>,
+  //The block ranges of the events by contract and event name.
+  //Partitions stop at the latest end block of their events,
+  //but the events of different block ranges share them
+  eventBlockRanges: dict<(option<int>, option<int>)>,
 }
 
 //CONSTRUCTION
@@ -60,6 +67,8 @@ let make = (
   // Aggregate events we want to fetch
   let staticContracts = Js.Dict.empty()
   let eventConfigs: array<FetchState.eventConfig> = []
+  // The block ranges of the events by contract and event name
+  let eventBlockRanges = Js.Dict.empty()
 
   chainConfig.contracts->Array.forEach(contract => {
     let contractName = contract.name
@@ -100,18 +109,99 @@ let make = (
         isRegistered
       }
 
+      // The block range of the event overrides the one of the contract
+      let (eventStartBlock, eventEndBlock) = switch contract.eventBlockRanges
+      ->Option.getWithDefault([])
+      ->Js.Array2.find(r => r.eventName === Event.name) {
+      | Some(eventBlockRange) => (
+          eventBlockRange.startBlock->Option.orElse(contract.startBlock),
+          eventBlockRange.endBlock->Option.orElse(contract.endBlock),
+        )
+      | None => (contract.startBlock, contract.endBlock)
+      }
+
       if shouldBeIncluded {
         eventConfigs->Array.push({
           contractName,
           eventId: Event.id,
           isWildcard,
+          startBlock: ?eventStartBlock,
+          endBlock: ?eventEndBlock,
         })
+        if eventStartBlock->Option.isSome || eventEndBlock->Option.isSome {
+          eventBlockRanges->Js.Dict.set(
+            `${contractName}.${Event.name}`,
+            (eventStartBlock, eventEndBlock),
+          )
+        }
       }
     })
 
     staticContracts->Js.Dict.set(contractName, contract.addresses)
   })
 
+  // Block handlers don't register contracts,
+  // so they are skipped on preRegistration phase
+  let registeredBlockHandlers = chainConfig.blockHandlers->Array.keep(({handler}) => {
+    let module(BlockHandler) = handler
+    let isRegistered =
+      BlockHandler.handlerRegister->Types.HandlerTypes.BlockRegister.getHandler->Option.isSome
+    if !isRegistered {
+      logger->Logging.childInfo(
+        `The block handler "${BlockHandler.name}" is not going to be called, because it doesn't have a registered handler.`,
+      )
+    }
+    isRegistered
+  })
+  let hasBlockHandlers =
+    !isPreRegisteringDynamicContracts && !(registeredBlockHandlers->Utils.Array.isEmpty)
+  // The block handlers partition stops after the latest end block of the handlers
+  let blockHandlersEndBlock = registeredBlockHandlers->Array.reduce(Some(0), (
+    endBlock,
+    blockHandler: Config.blockHandler,
+  ) =>
+    switch (endBlock, blockHandler.endBlock) {
+    | (Some(endBlock), Some(handlerEndBlock)) => Some(Pervasives.max(endBlock, handlerEndBlock))
+    | _ => None
+    }
+  )
+
+  // Transaction handlers don't register contracts either
+  let registeredTransactionHandlers = chainConfig.transactionHandlers->Array.keep(({handler}) => {
+    let module(TransactionHandler) = handler
+    let isRegistered =
+      TransactionHandler.handlerRegister
+      ->Types.HandlerTypes.TransactionRegister.getHandler
+      ->Option.isSome
+    if !isRegistered {
+      logger->Logging.childInfo(
+        `The transaction handler "${TransactionHandler.name}" is not going to be called, because it doesn't have a registered handler.`,
+      )
+    }
+    isRegistered
+  })
+  let hasTransactionHandlers =
+    !isPreRegisteringDynamicContracts && !(registeredTransactionHandlers->Utils.Array.isEmpty)
+
+  // Call handlers don't register contracts either
+  let callHandlerContractNames = Utils.Set.make()
+  if !isPreRegisteringDynamicContracts {
+    chainConfig.calls->Array.forEach(call => {
+      let module(Call) = call
+      let isRegistered =
+        Call.handlerRegister->Types.HandlerTypes.CallRegister.getHandler->Option.isSome
+      if isRegistered {
+        callHandlerContractNames->Utils.Set.add(Call.contractName)->ignore
+      } else {
+        logger->Logging.childInfo(
+          `The call handler "${Call.contractName}.${Call.name}" is not going to be called, because it doesn't have a registered handler.`,
+        )
+      }
+    })
+  }
+
+  let callHandlerContractNames = callHandlerContractNames->Utils.Set.toArray
+
   let fetchState = FetchState.make(
     ~maxAddrInPartition,
     ~staticContracts,
@@ -119,6 +209,10 @@ let make = (
     ~startBlock,
     ~endBlock,
     ~eventConfigs,
+    ~hasBlockHandlers,
+    ~blockHandlersEndBlock?,
+    ~hasTransactionHandlers,
+    ~callHandlerContractNames,
   )
 
   {
@@ -139,6 +233,8 @@ let make = (
     numBatchesFetched,
     processingFilters,
     dynamicContractPreRegistration,
+    callHandlerContractNames,
+    eventBlockRanges,
   }
 }
 
@@ -353,6 +449,24 @@ let cleanUpProcessingFilters = (
   }
 }
 
+/**
+Keeps the items within the block range of their event
+*/
+let applyEventBlockRanges = (
+  items: array<Internal.eventItem>,
+  ~eventBlockRanges: dict<(option<int>, option<int>)>,
+) =>
+  items->Array.keep(item =>
+    switch eventBlockRanges->Utils.Dict.dangerouslyGetNonOption(
+      `${item.contractName}.${item.eventName}`,
+    ) {
+    | Some((eventStartBlock, eventEndBlock)) =>
+      eventStartBlock->Option.mapWithDefault(true, startBlock => item.blockNumber >= startBlock) &&
+        eventEndBlock->Option.mapWithDefault(true, endBlock => item.blockNumber <= endBlock)
+    | None => true
+    }
+  )
+
 /**
 Updates of fetchState and cleans up event filters. Should be used whenever updating fetchState
 to ensure processingFilters are always valid.
@@ -370,6 +484,11 @@ let setQueryResponse = (
   | None => fetchedEvents
   | Some(processingFilters) => fetchedEvents->applyProcessingFilters(~processingFilters)
   }
+  let newItems = if self.eventBlockRanges->Js.Dict.keys->Utils.Array.isEmpty {
+    newItems
+  } else {
+    newItems->applyEventBlockRanges(~eventBlockRanges=self.eventBlockRanges)
+  }
 
   self.fetchState
   ->FetchState.setQueryResponse(
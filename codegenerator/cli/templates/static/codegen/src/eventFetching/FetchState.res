@@ -17,6 +17,9 @@ type eventConfig = {
   contractName: string,
   eventId: string,
   isWildcard: bool,
+  // The block range of the event from the config
+  startBlock?: int,
+  endBlock?: int,
 }
 
 type selection = {
@@ -26,6 +29,18 @@ type selection = {
 
 type status = {mutable fetchingStateId: option<int>}
 
+// The handler partitions don't fetch events, but produce items for the
+// blocks matching the chain block handlers, the traced calls of the contracts
+// with call handlers and the transactions matching the chain transaction handlers
+let blockHandlersPartitionId = "blockHandlers"
+let callHandlersPartitionId = "callHandlers"
+let transactionHandlersPartitionId = "transactionHandlers"
+
+// Dynamic contracts with call handlers are traced in separate partitions
+// starting from their registration block, the same way as their events
+let isCallHandlersPartitionId = partitionId =>
+  partitionId->Js.String2.startsWith(callHandlersPartitionId)
+
 /**
 A state that holds a queue of events and data regarding what to fetch next
 for specific contract events with a given contract address.
@@ -43,6 +58,9 @@ type partition = {
   dynamicContracts: array<TablesStatic.DynamicContractRegistry.t>,
   //Events ordered from latest to earliest
   fetchedEventQueue: array<Internal.eventItem>,
+  //For the handler partitions, which don't have
+  //event configs to get the end block from
+  endBlock?: int,
 }
 
 type t = {
@@ -87,6 +105,66 @@ let checkIsInSelection = (~selection, ~contractName, ~eventId, ~isWildcard) => {
     })
 }
 
+/*
+The latest end block of the events fetched by the partition.
+None when some of the events don't have an end block
+*/
+let getPartitionEndBlock = ({selection, contractAddressMapping} as p: partition) =>
+  switch p.endBlock {
+  | Some(_) as endBlock => endBlock
+  | None =>
+    let endBlock = ref(None)
+    let hasEventWithoutEndBlock = ref(false)
+    selection.eventConfigs->Array.forEach(ec => {
+      if (
+        selection.isWildcard ||
+          contractAddressMapping->ContractAddressingMap.getAddresses(ec.contractName)->Option.isSome
+      ) {
+        switch ec.endBlock {
+        | Some(eventEndBlock) =>
+          endBlock :=
+            Some(
+              Pervasives.max(eventEndBlock, endBlock.contents->Option.getWithDefault(eventEndBlock)),
+            )
+        | None => hasEventWithoutEndBlock := true
+        }
+      }
+    })
+    hasEventWithoutEndBlock.contents ? None : endBlock.contents
+  }
+
+/*
+The partition has nothing more to fetch,
+since every event of its contracts has ended
+*/
+let isPartitionEnded = (p: partition) =>
+  switch p->getPartitionEndBlock {
+  | Some(endBlock) => p.latestFetchedBlock.blockNumber >= endBlock
+  | None => false
+  }
+
+/*
+Ended partitions don't hold back the fully fetched block of the rest.
+When every partition has ended, it's the latest block fetched by them
+*/
+let computeLatestFullyFetchedBlock = (partitions: array<partition>) => {
+  let latestFullyFetchedBlock = ref(None)
+  let latestEndedBlock = ref((partitions->Js.Array2.unsafe_get(0)).latestFetchedBlock)
+  partitions->Array.forEach(p => {
+    if p->isPartitionEnded {
+      if p.latestFetchedBlock.blockNumber > latestEndedBlock.contents.blockNumber {
+        latestEndedBlock := p.latestFetchedBlock
+      }
+    } else {
+      switch latestFullyFetchedBlock.contents {
+      | Some({blockNumber}) if blockNumber <= p.latestFetchedBlock.blockNumber => ()
+      | _ => latestFullyFetchedBlock := Some(p.latestFetchedBlock)
+      }
+    }
+  })
+  latestFullyFetchedBlock.contents->Option.getWithDefault(latestEndedBlock.contents)
+}
+
 /*
 Comapritor for two events from the same chain. No need for chain id or timestamp
 */
@@ -247,10 +325,7 @@ let updateInternal = (
   ~firstEventBlockNumber=fetchState.firstEventBlockNumber,
   ~currentBlockHeight=?,
 ): t => {
-  let firstPartition = partitions->Js.Array2.unsafe_get(0)
-
   let queueSize = ref(0)
-  let latestFullyFetchedBlock = ref(firstPartition.latestFetchedBlock)
 
   for idx in 0 to partitions->Array.length - 1 {
     let p = partitions->Js.Array2.unsafe_get(idx)
@@ -258,10 +333,6 @@ let updateInternal = (
     let partitionQueueSize = p.fetchedEventQueue->Array.length
 
     queueSize := queueSize.contents + partitionQueueSize
-
-    if latestFullyFetchedBlock.contents.blockNumber > p.latestFetchedBlock.blockNumber {
-      latestFullyFetchedBlock := p.latestFetchedBlock
-    }
   }
 
   if (
@@ -274,7 +345,7 @@ let updateInternal = (
     )
   }
 
-  let latestFullyFetchedBlock = latestFullyFetchedBlock.contents
+  let latestFullyFetchedBlock = partitions->computeLatestFullyFetchedBlock
 
   let isFetchingAtHead = switch currentBlockHeight {
   | None => fetchState.isFetchingAtHead
@@ -282,6 +353,9 @@ let updateInternal = (
     // Sync isFetchingAtHead when currentBlockHeight is provided
     if latestFullyFetchedBlock.blockNumber >= currentBlockHeight {
       true
+    } else if partitions->Js.Array2.every(isPartitionEnded) {
+      // Nothing is left to fetch until the head
+      true
     } else if (
       // For dc registration reset the state only when dcs are not in the sync range
       fetchState.isFetchingAtHead &&
@@ -338,6 +412,7 @@ let registerDynamicContracts = (
   fetchState: t,
   dynamicContracts: array<TablesStatic.DynamicContractRegistry.t>,
   ~currentBlockHeight,
+  ~callHandlerContractNames=[],
 ) => {
   let dcsByStartBlock = Js.Dict.empty()
   dynamicContracts->Array.forEach(dc => {
@@ -378,10 +453,41 @@ let registerDynamicContracts = (
       )
     })
 
+  let nextPartitionIndex = fetchState.nextPartitionIndex + newPartitions->Array.length
+
+  let callHandlerPartitions =
+    dcsByStartBlock
+    ->Js.Dict.entries
+    ->Array.keepMap(((startBlockKey, dcs)) =>
+      switch dcs->Array.keep(dc =>
+        callHandlerContractNames->Js.Array2.includes((dc.contractType :> string))
+      ) {
+      | [] => None
+      | dcs => Some((startBlockKey, dcs))
+      }
+    )
+    ->Array.mapWithIndex((index, (startBlockKey, dcs)) => {
+      let p = makeDcPartition(
+        ~partitionIndex=nextPartitionIndex + index,
+        ~dynamicContracts=dcs,
+        ~latestFetchedBlock={
+          blockNumber: Pervasives.max(startBlockKey->Int.fromString->Option.getExn - 1, 0),
+          blockTimestamp: 0,
+        },
+        ~selection={
+          isWildcard: true,
+          eventConfigs: [],
+        },
+      )
+      {...p, id: `${callHandlersPartitionId}_${p.id}`}
+    })
+
   fetchState->updateInternal(
-    ~partitions=fetchState.partitions->Js.Array2.concat(newPartitions),
+    ~partitions=fetchState.partitions
+    ->Js.Array2.concat(newPartitions)
+    ->Js.Array2.concat(callHandlerPartitions),
     ~currentBlockHeight,
-    ~nextPartitionIndex=fetchState.nextPartitionIndex + newPartitions->Array.length,
+    ~nextPartitionIndex=nextPartitionIndex + callHandlerPartitions->Array.length,
   )
 }
 
@@ -482,8 +588,12 @@ let makePartitionQuery = (p: partition, ~endBlock, ~mergeTarget) => {
   | 0 => 0
   | latestFetchedBlockNumber => latestFetchedBlockNumber + 1
   }
+  let endBlock = Utils.Math.minOptInt(endBlock, p->getPartitionEndBlock)
   switch (endBlock, mergeTarget) {
   | (Some(endBlock), _) if fromBlock > endBlock => None
+  // Don't fetch past the end block to catch up with the merge target
+  | (Some(endBlock), Some(mergeTarget)) if mergeTarget.latestFetchedBlock.blockNumber > endBlock =>
+    Some(EndBlock({toBlock: endBlock}))
   | (_, Some(mergeTarget)) =>
     Some(
       Merge({
@@ -561,57 +671,64 @@ let getNextQuery = (
       }
     }
 
+    let hasPartitionsToFetch = ref(false)
+
     for idx in 0 to partitions->Js.Array2.length - 1 {
       let p = partitions->Js.Array2.unsafe_get(idx)
 
-      let isFetching = checkIsFetchingPartition(p)
-      let isReachedTheHead = p.latestFetchedBlock.blockNumber >= currentBlockHeight
+      // Ended partitions neither fetch nor get merged
+      if !(p->isPartitionEnded) {
+        hasPartitionsToFetch := true
 
-      if isFetching || !isReachedTheHead {
-        // Even if there are some partitions waiting for the new block
-        // We still want to wait for all partitions reaching the head
-        // because they might update currentBlockHeight in their response
-        // Also, there are cases when some partitions fetching at 50% of the chain
-        // and we don't want to poll the head for a few small partitions
-        shouldWaitForNewBlock := false
-      }
+        let isFetching = checkIsFetchingPartition(p)
+        let isReachedTheHead = p.latestFetchedBlock.blockNumber >= currentBlockHeight
 
-      if p->isFullPartition(~maxAddrInPartition) {
-        fullPartitions->Array.push(p)
-      } else {
-        mergingPartitions->Array.push(p)
+        if isFetching || !isReachedTheHead {
+          // Even if there are some partitions waiting for the new block
+          // We still want to wait for all partitions reaching the head
+          // because they might update currentBlockHeight in their response
+          // Also, there are cases when some partitions fetching at 50% of the chain
+          // and we don't want to poll the head for a few small partitions
+          shouldWaitForNewBlock := false
+        }
 
-        mostBehindMergingPartition :=
-          switch mostBehindMergingPartition.contents {
-          | Some(mostBehindMergingPartition) =>
-            if (
-              // The = check is important here. We don't want to have a target
-              // with the same latestFetchedBlock. They should be merged in separate queries
-              mostBehindMergingPartition.latestFetchedBlock.blockNumber ===
+        if p->isFullPartition(~maxAddrInPartition) {
+          fullPartitions->Array.push(p)
+        } else {
+          mergingPartitions->Array.push(p)
+
+          mostBehindMergingPartition :=
+            switch mostBehindMergingPartition.contents {
+            | Some(mostBehindMergingPartition) =>
+              if (
+                // The = check is important here. We don't want to have a target
+                // with the same latestFetchedBlock. They should be merged in separate queries
+                mostBehindMergingPartition.latestFetchedBlock.blockNumber ===
+                  p.latestFetchedBlock.blockNumber
+              ) {
+                mostBehindMergingPartition
+              } else if (
+                mostBehindMergingPartition.latestFetchedBlock.blockNumber <
                 p.latestFetchedBlock.blockNumber
-            ) {
-              mostBehindMergingPartition
-            } else if (
-              mostBehindMergingPartition.latestFetchedBlock.blockNumber <
-              p.latestFetchedBlock.blockNumber
-            ) {
-              mergingPartitionTarget :=
-                switch mergingPartitionTarget.contents {
-                | Some(mergingPartitionTarget)
-                  if mergingPartitionTarget.latestFetchedBlock.blockNumber <
-                  p.latestFetchedBlock.blockNumber => mergingPartitionTarget
-                | _ => p
-                }->Some
-              mostBehindMergingPartition
-            } else {
-              mergingPartitionTarget := Some(mostBehindMergingPartition)
-              p
-            }
-          | None => p
-          }->Some
+              ) {
+                mergingPartitionTarget :=
+                  switch mergingPartitionTarget.contents {
+                  | Some(mergingPartitionTarget)
+                    if mergingPartitionTarget.latestFetchedBlock.blockNumber <
+                    p.latestFetchedBlock.blockNumber => mergingPartitionTarget
+                  | _ => p
+                  }->Some
+                mostBehindMergingPartition
+              } else {
+                mergingPartitionTarget := Some(mostBehindMergingPartition)
+                p
+              }
+            | None => p
+            }->Some
 
-        if isFetching {
-          areMergingPartitionsFetching := true
+          if isFetching {
+            areMergingPartitionsFetching := true
+          }
         }
       }
     }
@@ -664,7 +781,7 @@ let getNextQuery = (
     }
 
     if queries->Utils.Array.isEmpty {
-      if shouldWaitForNewBlock.contents {
+      if shouldWaitForNewBlock.contents && hasPartitionsToFetch.contents {
         WaitingForNewBlock
       } else {
         NothingToQuery
@@ -762,13 +879,17 @@ Gets the earliest queueItem from thgetNodeEarliestEventWithUpdatedQueue.
 Finds the earliest queue item across all partitions and then returns that
 queue item with an update fetch state.
 */
-let getEarliestEvent = ({partitions}: t) => {
-  let item = ref(partitions->Js.Array2.unsafe_get(0)->getEarliestEventInPartition)
-  for idx in 1 to partitions->Array.length - 1 {
+let getEarliestEvent = ({partitions, latestFullyFetchedBlock}: t) => {
+  let item = ref(NoItem({latestFetchedBlock: latestFullyFetchedBlock}))
+  for idx in 0 to partitions->Array.length - 1 {
     let p = partitions->Js.Array2.unsafe_get(idx)
-    let pItem = p->getEarliestEventInPartition
-    if pItem->qItemLt(item.contents) {
-      item := pItem
+    switch p->getEarliestEventInPartition {
+    // Ended partitions won't get new items
+    | NoItem(_) if p->isPartitionEnded => ()
+    | pItem =>
+      if pItem->qItemLt(item.contents) {
+        item := pItem
+      }
     }
   }
   item.contents
@@ -784,23 +905,47 @@ let make = (
   ~staticContracts: dict<array<Address.t>>,
   ~dynamicContracts: array<TablesStatic.DynamicContractRegistry.t>,
   ~maxAddrInPartition,
+  ~hasBlockHandlers=false,
+  ~blockHandlersEndBlock=?,
+  ~hasTransactionHandlers=false,
+  ~callHandlerContractNames=[],
 ): t => {
-  let latestFetchedBlock = {
+  let makeLatestFetchedBlock = startBlock => {
     blockTimestamp: 0,
     // Here's a bug that startBlock: 1 won't work
     blockNumber: Pervasives.max(startBlock - 1, 0),
   }
+  let latestFetchedBlock = makeLatestFetchedBlock(startBlock)
+
+  // Events with a later start block in the config don't need
+  // to be fetched from the chain start block
+  let getEventStartBlock = (ec: eventConfig) =>
+    switch ec.startBlock {
+    | Some(eventStartBlock) => Pervasives.max(eventStartBlock, startBlock)
+    | None => startBlock
+    }
 
   let wildcardEventConfigs = []
   let normalEventConfigs = []
-  let contractNamesWithNormalEvents = Utils.Set.make()
+  let wildcardStartBlock = ref(None)
+  // The earliest start block of the normal events by contract name
+  let contractStartBlocks = Js.Dict.empty()
 
   eventConfigs->Array.forEach(ec => {
+    let eventStartBlock = ec->getEventStartBlock
     if ec.isWildcard {
       wildcardEventConfigs->Array.push(ec)
+      wildcardStartBlock :=
+        Utils.Math.minOptInt(wildcardStartBlock.contents, Some(eventStartBlock))
     } else {
       normalEventConfigs->Array.push(ec)
-      contractNamesWithNormalEvents->Utils.Set.add(ec.contractName)->ignore
+      contractStartBlocks->Js.Dict.set(
+        ec.contractName,
+        switch contractStartBlocks->Utils.Dict.dangerouslyGetNonOption(ec.contractName) {
+        | Some(contractStartBlock) => Pervasives.min(contractStartBlock, eventStartBlock)
+        | None => eventStartBlock
+        },
+      )
     }
   })
 
@@ -812,7 +957,9 @@ let make = (
       status: {
         fetchingStateId: None,
       },
-      latestFetchedBlock,
+      latestFetchedBlock: makeLatestFetchedBlock(
+        wildcardStartBlock.contents->Option.getWithDefault(startBlock),
+      ),
       selection: {
         isWildcard: true,
         eventConfigs: wildcardEventConfigs,
@@ -831,13 +978,14 @@ let make = (
   switch normalEventConfigs {
   | [] => ()
   | _ => {
-      let makePendingNormalPartition = () => {
+      let makePendingNormalPartition = (~startBlock) => {
         {
-          id: partitions->Array.length->Int.toString,
+          // The id is set when the partition is pushed
+          id: "",
           status: {
             fetchingStateId: None,
           },
-          latestFetchedBlock,
+          latestFetchedBlock: makeLatestFetchedBlock(startBlock),
           selection: normalSelection,
           contractAddressMapping: ContractAddressingMap.make(),
           dynamicContracts: [],
@@ -845,49 +993,147 @@ let make = (
         }
       }
 
-      let pendingNormalPartition = ref(makePendingNormalPartition())
+      let pushPartition = (p: partition) =>
+        partitions->Array.push({...p, id: partitions->Array.length->Int.toString})
+
+      // Contracts starting from the same block share partitions,
+      // the same way as dynamic contracts registered at the same block
+      let pendingNormalPartitions = Js.Dict.empty()
 
       let registerAddress = (contractName, address, ~dc=?) => {
-        let pendingPartition = pendingNormalPartition.contents
-        pendingPartition.contractAddressMapping->ContractAddressingMap.addAddress(
-          ~name=contractName,
-          ~address,
-        )
-        switch dc {
-        | Some(dc) => pendingPartition.dynamicContracts->Array.push(dc)
+        switch contractStartBlocks->Utils.Dict.dangerouslyGetNonOption(contractName) {
+        | Some(contractStartBlock) => {
+            let key = contractStartBlock->Int.toString
+            let pendingPartition = switch pendingNormalPartitions->Utils.Dict.dangerouslyGetNonOption(
+              key,
+            ) {
+            | Some(pendingPartition) => pendingPartition
+            | None => {
+                let pendingPartition = makePendingNormalPartition(~startBlock=contractStartBlock)
+                pendingNormalPartitions->Js.Dict.set(key, pendingPartition)
+                pendingPartition
+              }
+            }
+            pendingPartition.contractAddressMapping->ContractAddressingMap.addAddress(
+              ~name=contractName,
+              ~address,
+            )
+            switch dc {
+            | Some(dc) => pendingPartition.dynamicContracts->Array.push(dc)
+            | None => ()
+            }
+            if (
+              pendingPartition.contractAddressMapping->ContractAddressingMap.addressCount ===
+                maxAddrInPartition
+            ) {
+              pushPartition(pendingPartition)
+              pendingNormalPartitions->Js.Dict.set(
+                key,
+                makePendingNormalPartition(~startBlock=contractStartBlock),
+              )
+            }
+          }
+        // The contract doesn't have normal events
         | None => ()
         }
-        if (
-          pendingPartition.contractAddressMapping->ContractAddressingMap.addressCount ===
-            maxAddrInPartition
-        ) {
-          partitions->Array.push(pendingPartition)
-          pendingNormalPartition := makePendingNormalPartition()
-        }
       }
 
       staticContracts
       ->Js.Dict.entries
       ->Array.forEach(((contractName, addresses)) => {
-        if contractNamesWithNormalEvents->Utils.Set.has(contractName) {
-          addresses->Array.forEach(a => {
-            registerAddress(contractName, a)
-          })
-        }
+        addresses->Array.forEach(a => {
+          registerAddress(contractName, a)
+        })
       })
 
       dynamicContracts->Array.forEach(dc => {
-        let contractName = (dc.contractType :> string)
-        if contractNamesWithNormalEvents->Utils.Set.has(contractName) {
-          registerAddress(contractName, dc.contractAddress, ~dc)
+        registerAddress((dc.contractType :> string), dc.contractAddress, ~dc)
+      })
+
+      // Will be in the ASC order by Js spec
+      pendingNormalPartitions
+      ->Js.Dict.values
+      ->Array.forEach(pendingPartition => {
+        if pendingPartition.contractAddressMapping->ContractAddressingMap.addressCount > 0 {
+          pushPartition(pendingPartition)
         }
       })
+    }
+  }
 
-      if (
-        pendingNormalPartition.contents.contractAddressMapping->ContractAddressingMap.addressCount > 0
-      ) {
-        partitions->Array.push(pendingNormalPartition.contents)
+  if hasBlockHandlers {
+    partitions->Array.push({
+      id: blockHandlersPartitionId,
+      status: {
+        fetchingStateId: None,
+      },
+      latestFetchedBlock,
+      selection: {
+        isWildcard: true,
+        eventConfigs: [],
+      },
+      contractAddressMapping: ContractAddressingMap.make(),
+      dynamicContracts: [],
+      fetchedEventQueue: [],
+      endBlock: ?blockHandlersEndBlock,
+    })
+  }
+
+  if hasTransactionHandlers {
+    partitions->Array.push({
+      id: transactionHandlersPartitionId,
+      status: {
+        fetchingStateId: None,
+      },
+      latestFetchedBlock,
+      selection: {
+        isWildcard: true,
+        eventConfigs: [],
+      },
+      contractAddressMapping: ContractAddressingMap.make(),
+      dynamicContracts: [],
+      fetchedEventQueue: [],
+    })
+  }
+
+  switch callHandlerContractNames {
+  | [] => ()
+  | _ =>
+    // The addresses registered later are traced by the partitions
+    // created in registerDynamicContracts
+    let contractAddressMapping = ContractAddressingMap.make()
+    callHandlerContractNames->Array.forEach(contractName => {
+      staticContracts
+      ->Utils.Dict.dangerouslyGetNonOption(contractName)
+      ->Option.getWithDefault([])
+      ->Array.forEach(address =>
+        contractAddressMapping->ContractAddressingMap.addAddress(~name=contractName, ~address)
+      )
+    })
+    dynamicContracts->Array.forEach(dc => {
+      let contractName = (dc.contractType :> string)
+      if callHandlerContractNames->Js.Array2.includes(contractName) {
+        contractAddressMapping->ContractAddressingMap.addAddress(
+          ~name=contractName,
+          ~address=dc.contractAddress,
+        )
       }
+    })
+    if contractAddressMapping->ContractAddressingMap.addressCount > 0 {
+      partitions->Array.push({
+        id: callHandlersPartitionId,
+        status: {
+          fetchingStateId: None,
+        },
+        latestFetchedBlock,
+        selection: {
+          isWildcard: true,
+          eventConfigs: [],
+        },
+        contractAddressMapping,
+        dynamicContracts: [],
+        fetchedEventQueue: [],
+      })
     }
   }
 
@@ -911,7 +1157,8 @@ let make = (
     isFetchingAtHead: false,
     maxAddrInPartition,
     endBlock,
-    latestFullyFetchedBlock: latestFetchedBlock,
+    // Partitions of events with a later start block are ahead of the chain start block
+    latestFullyFetchedBlock: partitions->computeLatestFullyFetchedBlock,
     queueSize: 0,
     firstEventBlockNumber: None,
     normalSelection,
@@ -993,7 +1240,28 @@ Rolls back partitions to the given valid block
 */
 let rollbackPartition = (p: partition, ~firstChangeEvent: blockNumberAndLogIndex) => {
   switch p {
-  | {selection: {isWildcard: true}} =>
+  | {id}
+    if id === blockHandlersPartitionId ||
+    id === callHandlersPartitionId ||
+    id === transactionHandlersPartitionId =>
+    let shouldRollbackFetched = p.latestFetchedBlock.blockNumber >= firstChangeEvent.blockNumber
+    Some({
+      ...p,
+      status: {
+        fetchingStateId: None,
+      },
+      fetchedEventQueue: shouldRollbackFetched
+        ? p.fetchedEventQueue->pruneQueueFromFirstChangeEvent(~firstChangeEvent)
+        : p.fetchedEventQueue,
+      latestFetchedBlock: shouldRollbackFetched
+        ? {
+            blockNumber: Pervasives.max(firstChangeEvent.blockNumber - 1, 0),
+            blockTimestamp: 0,
+          }
+        : p.latestFetchedBlock,
+    })
+  // The call handler partitions of dynamic contracts are rolled back as normal partitions
+  | {selection: {isWildcard: true}, dynamicContracts: []} =>
     Some({
       ...p,
       status: {
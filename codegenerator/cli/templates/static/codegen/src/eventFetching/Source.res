@@ -22,6 +22,47 @@ type blockRangeFetchResponse = {
   stats: blockRangeFetchStats,
 }
 
+/**
+A successful contract function call taken from the block traces
+*/
+type callTrace = {
+  blockNumber: int,
+  transactionHash: string,
+  transactionPosition: int,
+  traceAddress: array<int>,
+  from: Address.t,
+  to: Address.t,
+  input: string,
+  output: string,
+}
+
+/**
+Filters for the transactions to fetch.
+Empty arrays match any value of the field
+*/
+type transactionSelection = {
+  from: array<Address.t>,
+  to: array<Address.t>,
+  functionSelectors: array<string>,
+  // 1 for successful and 0 for reverted transactions
+  status: option<int>,
+}
+
+/**
+A transaction together with the data of the block it's included in
+*/
+type blockTransaction = {
+  blockNumber: int,
+  blockTimestamp: int,
+  blockHash: string,
+  transactionIndex: int,
+  from: Address.t,
+  to: option<Address.t>,
+  input: string,
+  status: option<int>,
+  transaction: Internal.eventTransaction,
+}
+
 type t = {
   name: string,
   chain: ChainMap.Chain.t,
@@ -31,6 +72,21 @@ type t = {
     ~blockNumbers: array<int>,
     ~logger: Pino.t,
   ) => promise<result<array<ReorgDetection.blockDataWithTimestamp>, exn>>,
+  getCallTraces: (
+    ~fromBlock: int,
+    ~toBlock: int,
+    ~addresses: array<Address.t>,
+    ~functionSelectors: array<string>,
+    ~logger: Pino.t,
+  ) => promise<result<array<callTrace>, exn>>,
+  getTransactions: (
+    ~fromBlock: int,
+    ~toBlock: int,
+    ~selections: array<transactionSelection>,
+    ~fieldNames: array<string>,
+    ~nonOptionalFieldNames: array<string>,
+    ~logger: Pino.t,
+  ) => promise<result<array<blockTransaction>, exn>>,
   getHeightOrThrow: unit => promise<int>,
   fetchBlockRange: (
     ~fromBlock: int,
@@ -0,0 +1,181 @@
+open Belt
+
+let contractName = "TransactionHandlers"
+
+// The fields required to match the transactions with the handlers
+let requiredFieldNames = ["BlockNumber", "TransactionIndex", "From", "To", "Input", "Status"]
+
+let statusToInt = (status: Config.transactionStatus) =>
+  switch status {
+  | Success => 1
+  | Reverted => 0
+  }
+
+let toSelection = (transactionHandler: Config.transactionHandler): Source.transactionSelection => {
+  from: transactionHandler.from,
+  to: transactionHandler.to,
+  functionSelectors: transactionHandler.functionSelectors,
+  status: transactionHandler.status->Option.map(statusToInt),
+}
+
+/**
+Checks the transaction against the handler filters the same way the source does,
+since a single query is made for all the handlers.
+Addresses are compared case-insensitively, since the configured addresses are checksummed
+and the source returns them lowercased.
+*/
+let isMatching = (transaction: Source.blockTransaction, ~selection: Source.transactionSelection) => {
+  let toLowerCase = address => address->Address.toString->Js.String2.toLowerCase
+  let matchesAddress = (addresses, address) =>
+    switch addresses {
+    | [] => true
+    | _ =>
+      let address = address->toLowerCase
+      addresses->Js.Array2.some(a => a->toLowerCase === address)
+    }
+
+  matchesAddress(selection.from, transaction.from) &&
+  switch transaction.to {
+  | Some(address) => matchesAddress(selection.to, address)
+  | None => selection.to->Utils.Array.isEmpty
+  } &&
+  switch selection.functionSelectors {
+  | [] => true
+  | functionSelectors =>
+    functionSelectors->Js.Array2.includes(
+      transaction.input->Js.String2.slice(~from=0, ~to_=10)->Js.String2.toLowerCase,
+    )
+  } &&
+  switch selection.status {
+  | None => true
+  | Some(status) => transaction.status === Some(status)
+  }
+}
+
+let fetchBlockRange = (
+  query: FetchState.query,
+  ~transactionHandlers: array<Config.transactionHandler>,
+  ~source: Source.t,
+  ~currentBlockHeight,
+  ~chain,
+  ~logger,
+) => {
+  let fieldNames = Utils.Set.fromEntries(requiredFieldNames)
+  let nonOptionalFieldNames = Utils.Set.make()
+  let registeredTransactionHandlers = transactionHandlers->Array.keepMap(transactionHandler => {
+    let module(TransactionHandler) = transactionHandler.handler
+    TransactionHandler.handlerRegister
+    ->Types.HandlerTypes.TransactionRegister.getHandler
+    ->Option.map(handler => {
+      fieldNames->Utils.Set.addMany(
+        TransactionHandler.transactionSchema->Utils.Schema.getCapitalizedFieldNames,
+      )
+      nonOptionalFieldNames->Utils.Set.addMany(
+        TransactionHandler.transactionSchema->Utils.Schema.getNonOptionalFieldNames,
+      )
+      (transactionHandler, handler, transactionHandler->toSelection)
+    })
+  })
+
+  query->BlockRangeFetcher.fetchBlockRange(
+    ~handlersName="transaction handlers",
+    ~selectItems=async (~toBlock, ~logger) =>
+      switch await source.getTransactions(
+        ~fromBlock=query.fromBlock,
+        ~toBlock,
+        ~selections=registeredTransactionHandlers->Array.map(((_, _, selection)) => selection),
+        ~fieldNames=fieldNames->Utils.Set.toArray,
+        ~nonOptionalFieldNames=nonOptionalFieldNames->Utils.Set.toArray,
+        ~logger,
+      ) {
+      | Error(exn) => Error(exn)
+      | Ok(transactions) =>
+        let transactions =
+          transactions
+          ->Array.copy
+          ->Js.Array2.sortInPlaceWith((a, b) =>
+            switch a.blockNumber - b.blockNumber {
+            | 0 => a.transactionIndex - b.transactionIndex
+            | diff => diff
+            }
+          )
+
+        Ok({
+          // The transactions already have the block data
+          BlockRangeFetcher.blockNumbers: [],
+          makeItems: (~getBlockOrThrow as _) => {
+            let chainId = chain->ChainMap.Chain.toChainId
+            let parsedQueueItems = []
+            let getLogIndex =
+              BlockRangeFetcher.transactionItemsLogIndexRange->BlockRangeFetcher.makeLogIndexAllocator(
+                ~itemsName="transaction handler items",
+              )
+
+            transactions->Array.forEach(transaction => {
+              registeredTransactionHandlers->Array.forEach(((
+                transactionHandler,
+                handler,
+                selection,
+              )) => {
+                if transaction->isMatching(~selection) {
+                  let module(TransactionHandler) = transactionHandler.handler
+                  let itemLogIndex = getLogIndex(transaction.blockNumber)
+
+                  let block: Internal.blockHandlerBlock = {
+                    number: transaction.blockNumber,
+                    timestamp: transaction.blockTimestamp,
+                    hash: transaction.blockHash,
+                  }
+
+                  parsedQueueItems->Array.push(
+                    (
+                      {
+                        eventName: TransactionHandler.name,
+                        contractName,
+                        loader: None,
+                        handler: Some(
+                          (args: Internal.handlerArgs) =>
+                            handler(
+                              (
+                                {
+                                  transaction: transaction.transaction,
+                                  block,
+                                  chainId,
+                                  context: args.context,
+                                }: Internal.transactionHandlerArgs
+                              ),
+                            ),
+                        ),
+                        contractRegister: None,
+                        paramsRawEventSchema: BlockRangeFetcher.paramsRawEventSchema,
+                        timestamp: transaction.blockTimestamp,
+                        chain,
+                        blockNumber: transaction.blockNumber,
+                        logIndex: itemLogIndex,
+                        event: (
+                          {
+                            params: (),
+                            chainId,
+                            srcAddress: transaction.to->Option.getWithDefault(transaction.from),
+                            logIndex: itemLogIndex,
+                            transaction: transaction.transaction,
+                            block,
+                          }: Internal.genericEvent<unit, Internal.blockHandlerBlock, _>
+                        )->Internal.fromGenericEvent,
+                      }: Internal.eventItem
+                    ),
+                  )
+                }
+              })
+            })
+
+            parsedQueueItems
+          },
+        })
+      },
+    ~source,
+    ~currentBlockHeight,
+    ~chain,
+    ~logger,
+  )
+}
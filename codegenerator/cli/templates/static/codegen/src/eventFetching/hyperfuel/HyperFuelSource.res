@@ -513,10 +513,29 @@ let make = ({chain, contracts, endpointUrl}: options): t => {
   let getBlockHashes = (~blockNumbers as _, ~logger as _) =>
     Js.Exn.raiseError("HyperFuel does not support getting block hashes")
 
+  let getCallTraces = (
+    ~fromBlock as _,
+    ~toBlock as _,
+    ~addresses as _,
+    ~functionSelectors as _,
+    ~logger as _,
+  ) => Js.Exn.raiseError("HyperFuel does not support call traces")
+
+  let getTransactions = (
+    ~fromBlock as _,
+    ~toBlock as _,
+    ~selections as _,
+    ~fieldNames as _,
+    ~nonOptionalFieldNames as _,
+    ~logger as _,
+  ) => Js.Exn.raiseError("HyperFuel does not support transaction handlers")
+
   {
     name,
     chain,
     getBlockHashes,
+    getCallTraces,
+    getTransactions,
     pollingInterval: 100,
     getHeightOrThrow: () => HyperFuel.heightRoute->Rest.fetch(endpointUrl, ()),
     fetchBlockRange,
@@ -345,6 +345,286 @@ module BlockData = {
   }
 }
 
+module CallTraces = {
+  let makeRequestBody = (
+    ~fromBlock,
+    ~toBlock,
+    ~addresses,
+    ~functionSelectors,
+  ): HyperSyncJsonApi.QueryTypes.postQueryBody => {
+    fromBlock,
+    toBlockExclusive: toBlock + 1,
+    traces: [
+      {
+        to: addresses,
+        callType: ["call"],
+        sighash: functionSelectors,
+      },
+    ],
+    fieldSelection: {
+      trace: [
+        BlockNumber,
+        TransactionHash,
+        TransactionPosition,
+        TraceAddress,
+        From,
+        To,
+        Input,
+        Output,
+        Error,
+      ],
+    },
+  }
+
+  let convertResponse = (res: HyperSyncJsonApi.ResponseTypes.queryResponse): queryResponse<
+    array<Source.callTrace>,
+  > => {
+    res.data
+    ->Array.flatMap(item => item.traces->Option.getWithDefault([]))
+    ->Array.keepMap(trace => {
+      switch trace {
+      // Reverted calls don't change the state, so there is nothing to index
+      | {error: Some(_)} => None
+      | {
+          blockNumber,
+          transactionHash: Some(transactionHash),
+          transactionPosition: Some(transactionPosition),
+          traceAddress: Some(traceAddress),
+          from: Some(from),
+          to: Some(toAddress),
+          input: Some(input),
+          output: Some(output),
+        } =>
+        Some(
+          Ok(
+            (
+              {
+                blockNumber,
+                transactionHash,
+                transactionPosition,
+                traceAddress,
+                from: from->Address.Evm.fromStringOrThrow,
+                to: toAddress->Address.Evm.fromStringOrThrow,
+                input,
+                output,
+              }: Source.callTrace
+            ),
+          ),
+        )
+      | _ =>
+        let missingParams =
+          [
+            trace.blockNumber->Utils.Option.mapNone("trace.blockNumber"),
+            trace.transactionHash
+            ->Utils.Option.flatten
+            ->Utils.Option.mapNone("trace.transactionHash"),
+            trace.transactionPosition
+            ->Utils.Option.flatten
+            ->Utils.Option.mapNone("trace.transactionPosition"),
+            trace.traceAddress->Utils.Option.flatten->Utils.Option.mapNone("trace.traceAddress"),
+            trace.from->Utils.Option.flatten->Utils.Option.mapNone("trace.from"),
+            trace.to->Utils.Option.flatten->Utils.Option.mapNone("trace.to"),
+            trace.input->Utils.Option.flatten->Utils.Option.mapNone("trace.input"),
+            trace.output->Utils.Option.flatten->Utils.Option.mapNone("trace.output"),
+          ]->Array.keepMap(p => p)
+
+        Some(
+          Error(
+            UnexpectedMissingParams({
+              queryName: "query call traces HyperSync",
+              missingParams,
+            }),
+          ),
+        )
+      }
+    })
+    ->Utils.Array.transposeResults
+  }
+
+  let rec queryCallTraces = async (
+    ~serverUrl,
+    ~fromBlock,
+    ~toBlock,
+    ~addresses,
+    ~functionSelectors,
+    ~logger,
+  ): queryResponse<array<Source.callTrace>> => {
+    let body = makeRequestBody(~fromBlock, ~toBlock, ~addresses, ~functionSelectors)
+
+    let logger = Logging.createChildFrom(
+      ~logger,
+      ~params={
+        "logType": "HyperSync call traces query",
+        "fromBlock": fromBlock,
+        "toBlock": toBlock,
+      },
+    )
+
+    let res = await Time.retryAsyncWithExponentialBackOff(
+      () => HyperSyncJsonApi.queryRoute->Rest.fetch(serverUrl, body),
+      ~logger,
+    )
+
+    switch res->convertResponse {
+    | Error(_) as err => err
+    | Ok(traces) if res.nextBlock <= toBlock => {
+        // The HyperSync instance might be slightly behind the requested block,
+        // so give it time to catch up instead of polling in a loop
+        if res.nextBlock <= fromBlock {
+          await Time.resolvePromiseAfterDelay(~delayMilliseconds=100)
+        }
+        let restRes = await queryCallTraces(
+          ~serverUrl,
+          ~fromBlock=res.nextBlock,
+          ~toBlock,
+          ~addresses,
+          ~functionSelectors,
+          ~logger,
+        )
+        restRes->Result.map(rest => traces->Array.concat(rest))
+      }
+    | Ok(_) as ok => ok
+    }
+  }
+}
+
+module Transactions = {
+  let makeRequestBody = (
+    ~fromBlock,
+    ~toBlock,
+    ~selections: array<Source.transactionSelection>,
+    ~fieldNames,
+  ): HyperSyncClient.QueryTypes.query => {
+    fromBlock,
+    toBlockExclusive: toBlock + 1,
+    transactions: selections->Array.map((
+      selection
+    ): HyperSyncClient.QueryTypes.transactionSelection => {
+      from: selection.from,
+      to_: selection.to,
+      sighash: selection.functionSelectors,
+      status: ?selection.status,
+    }),
+    fieldSelection: {
+      block: [Number, Hash, Timestamp],
+      transaction: fieldNames->(
+        Utils.magic: array<string> => array<HyperSyncClient.QueryTypes.transactionField>
+      ),
+    },
+  }
+
+  let convertResponse = (
+    res: HyperSyncClient.ResponseTypes.queryResponse,
+    ~nonOptionalFieldNames,
+  ): queryResponse<array<Source.blockTransaction>> => {
+    try {
+      let blocksByNumber = Js.Dict.empty()
+      res.data.blocks
+      ->Option.getWithDefault([])
+      ->Array.forEach(block =>
+        switch block {
+        | {number, timestamp, hash} =>
+          blocksByNumber->Js.Dict.set(number->Int.toString, (timestamp, hash))
+        | _ =>
+          UnexpectedMissingParamsExn({
+            queryName: "query transactions HyperSync",
+            missingParams: [
+              block.number->Utils.Option.mapNone("block.number"),
+              block.timestamp->Utils.Option.mapNone("block.timestamp"),
+              block.hash->Utils.Option.mapNone("block.hash"),
+            ]->Array.keepMap(p => p),
+          })->raise
+        }
+      )
+
+      res.data.transactions
+      ->Option.getWithDefault([])
+      ->Array.map((transaction): Source.blockTransaction => {
+        let missingParams = []
+        missingParams->LogsQuery.addMissingParams(
+          ["blockNumber", "transactionIndex", "from", "input"]->Array.concat(nonOptionalFieldNames),
+          transaction,
+          ~prefix="transaction",
+        )
+        if missingParams->Array.length > 0 {
+          UnexpectedMissingParamsExn({
+            queryName: "query transactions HyperSync",
+            missingParams,
+          })->raise
+        }
+
+        let blockNumber = transaction.blockNumber->Option.getUnsafe
+        switch blocksByNumber->Js.Dict.get(blockNumber->Int.toString) {
+        | Some((blockTimestamp, blockHash)) => {
+            blockNumber,
+            blockTimestamp,
+            blockHash,
+            transactionIndex: transaction.transactionIndex->Option.getUnsafe,
+            from: transaction.from->Option.getUnsafe->Address.unsafeFromString,
+            to: transaction.to->Option.map(Address.unsafeFromString),
+            input: transaction.input->Option.getUnsafe,
+            status: transaction.status,
+            transaction: transaction->Utils.magic,
+          }
+        | None =>
+          UnexpectedMissingParamsExn({
+            queryName: "query transactions HyperSync",
+            missingParams: [`block ${blockNumber->Int.toString}`],
+          })->raise
+        }
+      })
+      ->Ok
+    } catch {
+    | UnexpectedMissingParamsExn(err) => Error(UnexpectedMissingParams(err))
+    }
+  }
+
+  let rec queryTransactions = async (
+    ~client: HyperSyncClient.t,
+    ~fromBlock,
+    ~toBlock,
+    ~selections,
+    ~fieldNames,
+    ~nonOptionalFieldNames,
+    ~logger,
+  ): queryResponse<array<Source.blockTransaction>> => {
+    let query = makeRequestBody(~fromBlock, ~toBlock, ~selections, ~fieldNames)
+
+    let logger = Logging.createChildFrom(
+      ~logger,
+      ~params={
+        "logType": "HyperSync transactions query",
+        "fromBlock": fromBlock,
+        "toBlock": toBlock,
+      },
+    )
+
+    let res = await Time.retryAsyncWithExponentialBackOff(() => client.get(~query), ~logger)
+
+    switch res->convertResponse(~nonOptionalFieldNames) {
+    | Error(_) as err => err
+    | Ok(transactions) if res.nextBlock <= toBlock => {
+        // The HyperSync instance might be slightly behind the requested block,
+        // so give it time to catch up instead of polling in a loop
+        if res.nextBlock <= fromBlock {
+          await Time.resolvePromiseAfterDelay(~delayMilliseconds=100)
+        }
+        let restRes = await queryTransactions(
+          ~client,
+          ~fromBlock=res.nextBlock,
+          ~toBlock,
+          ~selections,
+          ~fieldNames,
+          ~nonOptionalFieldNames,
+          ~logger,
+        )
+        restRes->Result.map(rest => transactions->Array.concat(rest))
+      }
+    | Ok(_) as ok => ok
+    }
+  }
+}
+
 let queryLogsPage = LogsQuery.queryLogsPage
 let queryBlockData = (~serverUrl, ~blockNumber, ~logger) =>
   BlockData.queryBlockData(
@@ -354,3 +634,5 @@ let queryBlockData = (~serverUrl, ~blockNumber, ~logger) =>
     ~logger,
   )->Promise.thenResolve(res => res->Result.map(res => res->Array.get(0)))
 let queryBlockDataMulti = BlockData.queryBlockDataMulti
+let queryCallTraces = CallTraces.queryCallTraces
+let queryTransactions = Transactions.queryTransactions
@@ -382,6 +382,42 @@ let make = (
           exn->ErrorHandling.mkLogAndRaise(~msg, ~logger)
         }
       }
+
+      let isAnonymousEvent = (eventMod: module(Types.InternalEvent)) => {
+        let module(Event) = eventMod
+        Event.anonymousInputs->Option.isSome
+      }
+
+      let parseAnonymousEvent = (item: HyperSync.logsQueryPageItem, ~eventMod) => {
+        let module(Event: Types.InternalEvent) = eventMod
+        let {block, log} = item
+        switch Event.anonymousInputs {
+        | Some(inputs) =>
+          switch Viem.parseAnonymousLogOrThrow(
+            ~eventName=Event.name,
+            ~inputs,
+            ~topics=log.topics,
+            ~data=log.data,
+          ) {
+          // Anonymous events are matched by the log shape only,
+          // so a log failing to decode is a different event and is skipped
+          | exception _ =>
+            logger->Logging.childTrace({
+              "msg": `Skipping the log, since it doesn't decode as the anonymous event ${Event.name}`,
+              "contractName": Event.contractName,
+              "chainId": chain->ChainMap.Chain.toChainId,
+              "blockNumber": block->Types.Block.getNumber,
+              "logIndex": log.logIndex,
+            })
+          | decodedEvent =>
+            parsedQueueItems
+            ->Js.Array2.push(makeEventBatchQueueItem(item, ~params=decodedEvent.args, ~eventMod))
+            ->ignore
+          }
+        | None => ()
+        }
+      }
+
       if shouldUseHypersyncClientDecoder {
         //Currently there are still issues with decoder for some cases so
         //this can only be activated with a flag
@@ -398,19 +434,20 @@ let make = (
         pageUnsafe.items->Belt.Array.forEachWithIndex((index, item) => {
           let {block, log} = item
           let chainId = chain->ChainMap.Chain.toChainId
-          let topic0 = log.topics->Js.Array2.unsafe_get(0)
           let maybeEventMod =
-            eventRouter->EventRouter.get(
-              ~tag=EventRouter.getEvmEventId(
-                ~sighash=topic0->EvmTypes.Hex.toString,
-                ~topicCount=log.topics->Array.length,
-              ),
+            eventRouter->EventRouter.getEvmEvent(
+              ~topics=log.topics,
+              ~data=log.data,
               ~contractAddressMapping,
               ~contractAddress=log.address,
             )
           let maybeDecodedEvent = parsedEvents->Js.Array2.unsafe_get(index)
 
           switch (maybeEventMod, maybeDecodedEvent) {
+          | (Some(eventMod), _) if isAnonymousEvent(eventMod) =>
+            //The hypersync client decoder matches events by topic0,
+            //so anonymous events are always decoded with viem
+            parseAnonymousEvent(item, ~eventMod)
           | (Some(eventMod), Value(decoded)) =>
             let module(Event) = eventMod
             parsedQueueItems
@@ -439,16 +476,14 @@ let make = (
         pageUnsafe.items->Array.forEach(item => {
           let {block, log} = item
           let chainId = chain->ChainMap.Chain.toChainId
-          let topic0 = log.topics->Js.Array2.unsafe_get(0)
 
-          switch eventRouter->EventRouter.get(
-            ~tag=EventRouter.getEvmEventId(
-              ~sighash=topic0->EvmTypes.Hex.toString,
-              ~topicCount=log.topics->Array.length,
-            ),
+          switch eventRouter->EventRouter.getEvmEvent(
+            ~topics=log.topics,
+            ~data=log.data,
             ~contractAddressMapping,
             ~contractAddress=log.address,
           ) {
+          | Some(eventMod) if isAnonymousEvent(eventMod) => parseAnonymousEvent(item, ~eventMod)
           | Some(eventMod) =>
             let module(Event) = eventMod
 
@@ -518,10 +553,40 @@ let make = (
       ~logger,
     )->Promise.thenResolve(HyperSync.mapExn)
 
+  let getCallTraces = (~fromBlock, ~toBlock, ~addresses, ~functionSelectors, ~logger) =>
+    HyperSync.queryCallTraces(
+      ~serverUrl=endpointUrl,
+      ~fromBlock,
+      ~toBlock,
+      ~addresses,
+      ~functionSelectors,
+      ~logger,
+    )->Promise.thenResolve(HyperSync.mapExn)
+
+  let getTransactions = (
+    ~fromBlock,
+    ~toBlock,
+    ~selections,
+    ~fieldNames,
+    ~nonOptionalFieldNames,
+    ~logger,
+  ) =>
+    HyperSync.queryTransactions(
+      ~client,
+      ~fromBlock,
+      ~toBlock,
+      ~selections,
+      ~fieldNames,
+      ~nonOptionalFieldNames,
+      ~logger,
+    )->Promise.thenResolve(HyperSync.mapExn)
+
   {
     name,
     chain,
     getBlockHashes,
+    getCallTraces,
+    getTransactions,
     pollingInterval: 100,
     getHeightOrThrow: () => HyperSyncJsonApi.heightRoute->Rest.fetch(endpointUrl, ()),
     fetchBlockRange,
@@ -40,6 +40,7 @@ let getSelectionConfig = (selection: FetchState.selection, ~contracts: array<Con
   // Our integration test with hardcat would fail
   // if we don't strip trailing empty topics
   let topics = switch topicSelection {
+  | {topic0: [], topic1: [], topic2: [], topic3: []} => []
   | {topic0, topic1: [], topic2: [], topic3: []} => [topic0]
   | {topic0, topic1, topic2: [], topic3: []} => [topic0, topic1]
   | {topic0, topic1, topic2, topic3: []} => [topic0, topic1, topic2]
@@ -249,12 +250,9 @@ let make = ({syncConfig, provider, chain, contracts, eventRouter}: options): t =
       let parsedQueueItems =
         await logs
         ->Belt.Array.keepMap(log => {
-          let topic0 = log.topics->Js.Array2.unsafe_get(0)
-          switch eventRouter->EventRouter.get(
-            ~tag=EventRouter.getEvmEventId(
-              ~sighash=topic0->EvmTypes.Hex.toString,
-              ~topicCount=log.topics->Array.length,
-            ),
+          switch eventRouter->EventRouter.getEvmEvent(
+            ~topics=log.topics,
+            ~data=log.data,
             ~contractAddressMapping,
             ~contractAddress=log.address,
           ) {
@@ -272,7 +270,39 @@ let make = ({syncConfig, provider, chain, contracts, eventRouter}: options): t =
                 }
               },
             )
-            Some(
+            // Anonymous events are matched by the log shape only,
+            // so a log failing to decode is a different event and is skipped
+            let decodedEvent = switch Event.anonymousInputs {
+            | Some(inputs) =>
+              switch Viem.parseAnonymousLogOrThrow(
+                ~eventName=Event.name,
+                ~inputs,
+                ~topics=log.topics,
+                ~data=log.data,
+              ) {
+              | exception _ =>
+                logger->Logging.childTrace({
+                  "msg": `Skipping the log, since it doesn't decode as the anonymous event ${Event.name}`,
+                  "contractName": Event.contractName,
+                })
+                None
+              | decodedEvent => Some(decodedEvent)
+              }
+            | None =>
+              switch contractNameAbiMapping->Viem.parseLogOrThrow(
+                ~contractName=Event.contractName,
+                ~topics=log.topics,
+                ~data=log.data,
+              ) {
+              | exception exn =>
+                exn->ErrorHandling.mkLogAndRaise(
+                  ~msg="Failed to parse event with viem, please double-check your ABI.",
+                  ~logger,
+                )
+              | decodedEvent => Some(decodedEvent)
+              }
+            }
+            decodedEvent->Option.map(decodedEvent =>
               (
                 async () => {
                   let (block, transaction) = try await Promise.all2((
@@ -288,18 +318,6 @@ let make = ({syncConfig, provider, chain, contracts, eventRouter}: options): t =
                     )
                   }
 
-                  let decodedEvent = try contractNameAbiMapping->Viem.parseLogOrThrow(
-                    ~contractName=Event.contractName,
-                    ~topics=log.topics,
-                    ~data=log.data,
-                  ) catch {
-                  | exn =>
-                    exn->ErrorHandling.mkLogAndRaise(
-                      ~msg="Failed to parse event with viem, please double-check your ABI.",
-                      ~logger,
-                    )
-                  }
-
                   (
                     {
                       eventName: Event.name,
@@ -323,7 +341,7 @@ let make = ({syncConfig, provider, chain, contracts, eventRouter}: options): t =
                     }: Internal.eventItem
                   )
                 }
-              )(),
+              )()
             )
           }
         })
@@ -377,11 +395,78 @@ let make = ({syncConfig, provider, chain, contracts, eventRouter}: options): t =
     ->Promise.catch(exn => exn->Error->Promise.resolve)
   }
 
+  let getCallTraces = async (
+    ~fromBlock,
+    ~toBlock,
+    ~addresses,
+    ~functionSelectors,
+    ~logger as _currentlyUnusedLogger,
+  ) => {
+    try {
+      let traces =
+        (
+          await provider->Ethers.JsonRpcProvider.send(
+            ~method="trace_filter",
+            ~params=[
+              (
+                {fromBlock, toBlock, toAddress: addresses}: Rpc.TraceFilter.param
+              )->S.reverseConvertToJsonOrThrow(Rpc.TraceFilter.paramsSchema),
+            ],
+          )
+        )->S.parseOrThrow(S.array(Rpc.TraceFilter.traceSchema))
+
+      traces
+      ->Array.keepMap((trace): option<Source.callTrace> =>
+        switch trace {
+        | {
+            type_: "call",
+            error: None,
+            action: {
+              callType: Some("call"),
+              from: Some(from),
+              to: Some(toAddress),
+              input: Some(input),
+            },
+            result: Some(Some({output: Some(output)})),
+            transactionHash: Some(transactionHash),
+            transactionPosition: Some(transactionPosition),
+          } if functionSelectors->Js.Array2.includes(input->Js.String2.slice(~from=0, ~to_=10)) =>
+          Some({
+            blockNumber: trace.blockNumber,
+            transactionHash,
+            transactionPosition,
+            traceAddress: trace.traceAddress,
+            from: from->Address.Evm.fromAddressOrThrow,
+            to: toAddress->Address.Evm.fromAddressOrThrow,
+            input,
+            output,
+          })
+        | _ => None
+        }
+      )
+      ->Ok
+    } catch {
+    | exn => Error(exn)
+    }
+  }
+
+  // The config validation only allows transaction handlers on HyperSync networks
+  let getTransactions = (
+    ~fromBlock as _,
+    ~toBlock as _,
+    ~selections as _,
+    ~fieldNames as _,
+    ~nonOptionalFieldNames as _,
+    ~logger as _,
+  ) => Js.Exn.raiseError("RPC sources don't support transaction handlers")
+
   {
     name,
     chain,
     pollingInterval: 1000,
     getBlockHashes,
+    getCallTraces,
+    getTransactions,
     getHeightOrThrow: () => provider->Ethers.JsonRpcProvider.getBlockNumber,
     fetchBlockRange,
   }
@@ -343,6 +343,10 @@ let handlePartitionQueryResponse = (
       ~numAddresses=query.contractAddressMapping->ContractAddressingMap.addressCount,
       ~queryName=switch query {
       | {target: Merge(_)} => `Merge Query`
+      | {partitionId} if partitionId === FetchState.blockHandlersPartitionId => `Block Handlers Query`
+      | {partitionId} if partitionId->FetchState.isCallHandlersPartitionId => `Call Handlers Query`
+      | {partitionId} if partitionId === FetchState.transactionHandlersPartitionId =>
+        `Transaction Handlers Query`
       | {selection: {isWildcard: true}} => `Wildcard Query`
       | {selection: {isWildcard: false}} => `Normal Query`
       },
@@ -520,6 +524,7 @@ let actionReducer = (state: t, action: action) => {
             cf.fetchState->FetchState.registerDynamicContracts(
               dcs,
               ~currentBlockHeight=cf.currentBlockHeight,
+              ~callHandlerContractNames=cf.callHandlerContractNames,
             )
 
           {
@@ -774,7 +779,16 @@ let checkAndFetchForChain = (
 ) => async chain => {
   let chainFetcher = state.chainManager.chainFetchers->ChainMap.get(chain)
   if !isRollingBack(state) {
-    let {chainConfig: {source}, logger, currentBlockHeight, fetchState} = chainFetcher
+    let {
+      chainConfig: {source, blockHandlers, transactionHandlers, calls},
+      logger,
+      currentBlockHeight,
+      fetchState,
+    } = chainFetcher
+    let hasHandlerPartitions =
+      !(blockHandlers->Utils.Array.isEmpty) ||
+      !(transactionHandlers->Utils.Array.isEmpty) ||
+      !(calls->Utils.Array.isEmpty)
 
     await chainFetcher.sourceManager->SourceManager.fetchNext(
       ~fetchState,
@@ -784,7 +798,38 @@ let checkAndFetchForChain = (
         dispatchAction(FinishWaitingForNewBlock({chain, currentBlockHeight})),
       ~currentBlockHeight,
       ~executeQuery=async query => {
-        switch await query->executeQuery(~logger, ~source, ~currentBlockHeight, ~chain) {
+        let response = if query.partitionId === FetchState.blockHandlersPartitionId {
+          await query->BlockHandlerFetcher.fetchBlockRange(
+            ~blockHandlers,
+            ~source,
+            ~currentBlockHeight,
+            ~chain,
+            ~logger,
+          )
+        } else if query.partitionId === FetchState.transactionHandlersPartitionId {
+          await query->TransactionHandlerFetcher.fetchBlockRange(
+            ~transactionHandlers,
+            ~source,
+            ~currentBlockHeight,
+            ~chain,
+            ~logger,
+          )
+        } else if query.partitionId->FetchState.isCallHandlersPartitionId {
+          await query->CallHandlerFetcher.fetchBlockRange(
+            ~calls,
+            ~source,
+            ~currentBlockHeight,
+            ~chain,
+            ~logger,
+          )
+        } else {
+          switch await query->executeQuery(~logger, ~source, ~currentBlockHeight, ~chain) {
+          | Ok(response) if hasHandlerPartitions =>
+            response->BlockRangeFetcher.checkLogIndices(~logger)
+          | response => response
+          }
+        }
+        switch response {
         | Ok(response) => dispatchAction(PartitionQueryResponse({chain, response, query}))
         | Error(e) => dispatchAction(ErrorExit(e))
         }
@@ -865,9 +910,8 @@ let injectedTaskReducer = (
         }
         let timeRef = Hrtime.makeTimer()
         let _ = await Promise.all(Entities.allEntities->Belt.Array.map(entityMod => {
-          let module(Entity) = entityMod
           Db.sql->DbFunctions.EntityHistory.pruneStaleEntityHistory(
-            ~entityName=Entity.name,
+            ~entityMod,
             ~safeChainIdAndBlockNumberArray,
             ~shouldDeepClean,
           )
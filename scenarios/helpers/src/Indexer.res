@@ -16,6 +16,24 @@ module type S = {
         let getHandler: t => option<Internal.handler>
         let getContractRegister: t => option<Internal.contractRegister>
       }
+
+      module BlockRegister: {
+        type t
+
+        let getHandler: t => option<Internal.blockHandler>
+      }
+
+      module TransactionRegister: {
+        type t
+
+        let getHandler: t => option<Internal.transactionHandler>
+      }
+
+      module CallRegister: {
+        type t
+
+        let getHandler: t => option<Internal.callHandler>
+      }
     }
 
     module SingleOrMultiple: {
@@ -49,6 +67,7 @@ module type S = {
       let transactionSchema: RescriptSchema.S.schema<transaction>
 
       let convertHyperSyncEventArgs: HyperSyncClient.Decoder.decodedEvent => eventArgs
+      let anonymousInputs: option<array<Viem.abiParameter>>
       let handlerRegister: HandlerTypes.Register.t
 
       type eventFilter
@@ -58,6 +77,33 @@ module type S = {
       with type eventArgs = Internal.eventParams
       and type transaction = Internal.eventTransaction
       and type block = Internal.eventBlock
+
+    module type BlockHandler = {
+      let name: string
+      let handlerRegister: HandlerTypes.BlockRegister.t
+    }
+
+    module type TransactionHandler = {
+      let name: string
+
+      type transaction
+
+      let transactionSchema: RescriptSchema.S.schema<transaction>
+      let handlerRegister: HandlerTypes.TransactionRegister.t
+    }
+
+    module type Call = {
+      let name: string
+      let contractName: string
+      let functionSelector: string
+
+      type inputs
+      type outputs
+
+      let inputsAbi: array<Viem.abiParameter>
+      let outputsAbi: array<Viem.abiParameter>
+      let handlerRegister: HandlerTypes.CallRegister.t
+    }
   }
 
   module ContractAddressingMap: {
@@ -110,6 +156,36 @@ module type S = {
       stats: blockRangeFetchStats,
     }
 
+    type callTrace = {
+      blockNumber: int,
+      transactionHash: string,
+      transactionPosition: int,
+      traceAddress: array<int>,
+      from: Address.t,
+      to: Address.t,
+      input: string,
+      output: string,
+    }
+
+    type transactionSelection = {
+      from: array<Address.t>,
+      to: array<Address.t>,
+      functionSelectors: array<string>,
+      status: option<int>,
+    }
+
+    type blockTransaction = {
+      blockNumber: int,
+      blockTimestamp: int,
+      blockHash: string,
+      transactionIndex: int,
+      from: Address.t,
+      to: option<Address.t>,
+      input: string,
+      status: option<int>,
+      transaction: Internal.eventTransaction,
+    }
+
     type t = {
       name: string,
       chain: ChainMap.Chain.t,
@@ -119,6 +195,21 @@ module type S = {
         ~blockNumbers: array<int>,
         ~logger: Pino.t,
       ) => promise<result<array<ReorgDetection.blockDataWithTimestamp>, exn>>,
+      getCallTraces: (
+        ~fromBlock: int,
+        ~toBlock: int,
+        ~addresses: array<Address.t>,
+        ~functionSelectors: array<string>,
+        ~logger: Pino.t,
+      ) => promise<result<array<callTrace>, exn>>,
+      getTransactions: (
+        ~fromBlock: int,
+        ~toBlock: int,
+        ~selections: array<transactionSelection>,
+        ~fieldNames: array<string>,
+        ~nonOptionalFieldNames: array<string>,
+        ~logger: Pino.t,
+      ) => promise<result<array<blockTransaction>, exn>>,
       getHeightOrThrow: unit => promise<int>,
       fetchBlockRange: (
         ~fromBlock: int,
@@ -140,6 +231,23 @@ module type S = {
       events: array<module(Types.Event)>,
     }
 
+    type blockHandler = {
+      handler: module(Types.BlockHandler),
+      interval: int,
+      startBlock: int,
+      endBlock: option<int>,
+    }
+
+    type transactionStatus = Success | Reverted
+
+    type transactionHandler = {
+      handler: module(Types.TransactionHandler),
+      from: array<Address.t>,
+      to: array<Address.t>,
+      functionSelectors: array<string>,
+      status: option<transactionStatus>,
+    }
+
     type syncSource = HyperSync | HyperFuel | Rpc
 
     type chainConfig = {
@@ -149,6 +257,9 @@ module type S = {
       confirmedBlockThreshold: int,
       chain: ChainMap.Chain.t,
       contracts: array<contract>,
+      blockHandlers: array<blockHandler>,
+      transactionHandlers: array<transactionHandler>,
+      calls: array<module(Types.Call)>,
       source: Source.t,
     }
   }
@@ -0,0 +1,68 @@
+open RescriptMocha
+
+describe("BlockRangeFetcher.makeLogIndexAllocator", () => {
+  let range: BlockRangeFetcher.logIndexRange = {firstLogIndex: 100, lastLogIndex: 101}
+
+  it("Gives consecutive log indices starting over for every block", () => {
+    let getLogIndex = range->BlockRangeFetcher.makeLogIndexAllocator(~itemsName="test items")
+
+    Assert.deepEqual(
+      [getLogIndex(1), getLogIndex(1), getLogIndex(2), getLogIndex(3), getLogIndex(3)],
+      [100, 101, 100, 100, 101],
+    )
+  })
+
+  it("Fails when a block has more items than the range fits", () => {
+    let getLogIndex = range->BlockRangeFetcher.makeLogIndexAllocator(~itemsName="test items")
+
+    getLogIndex(1)->ignore
+    getLogIndex(1)->ignore
+    Assert.throws(
+      () => getLogIndex(1),
+      ~error={
+        "message": "Block 1 has more than 2 test items, which is the most that can be ordered in a block",
+      },
+    )
+  })
+
+  it("Keeps the ranges of the handler items apart and within the packed log index", () => {
+    open BlockRangeFetcher
+    Assert.ok(transactionItemsLogIndexRange.lastLogIndex < callItemsLogIndexRange.firstLogIndex)
+    Assert.ok(callItemsLogIndexRange.lastLogIndex < blockHandlerItemsLogIndexRange.firstLogIndex)
+    Assert.ok(blockHandlerItemsLogIndexRange.lastLogIndex <= 65535)
+  })
+})
+
+describe("BlockRangeFetcher.checkLogIndices", () => {
+  let mockResponse = (~logIndices): Source.blockRangeFetchResponse =>
+    {
+      "parsedQueueItems": logIndices->Belt.Array.map(logIndex =>
+        {"blockNumber": 10, "logIndex": logIndex}
+      ),
+    }->Utils.magic
+
+  it("Passes the logs below the reserved log indices", () => {
+    let response = mockResponse(~logIndices=[0, BlockRangeFetcher.firstReservedLogIndex - 1])
+
+    switch response->BlockRangeFetcher.checkLogIndices(~logger=Logging.logger) {
+    | Ok(checkedResponse) => Assert.ok(checkedResponse === response)
+    | Error(_) => Assert.fail("Should pass the logs below the reserved log indices")
+    }
+  })
+
+  it("Fails with the block of a log using a reserved log index", () => {
+    let response = mockResponse(~logIndices=[0, BlockRangeFetcher.firstReservedLogIndex])
+
+    switch response->BlockRangeFetcher.checkLogIndices(~logger=Logging.logger) {
+    | Ok(_) => Assert.fail("Should fail on the log with a reserved log index")
+    | Error({msg, exn}) =>
+      Assert.deepEqual(msg, Some("Failed to order the fetched logs"))
+      Assert.deepEqual(
+        exn,
+        Failure(
+          "The log 32768 of block 10 has an index reserved for the items of the block, transaction and call handlers. Chains with these handlers support up to 32768 logs per block",
+        ),
+      )
+    }
+  })
+})
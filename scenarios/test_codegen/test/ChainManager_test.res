@@ -159,6 +159,8 @@ let populateChainQueuesWithRandomEvents = (~runTime=1000, ~maxBlockTime=15, ())
       currentBlockHeight: 0,
       processingFilters: None,
       dynamicContractPreRegistration: None,
+      callHandlerContractNames: [],
+      eventBlockRanges: Js.Dict.empty(),
     }
 
     mockChainFetcher
@@ -0,0 +1,31 @@
+open RescriptMocha
+
+describe("DbFunctionsEntities.getUniqueViolationKey", () => {
+  let throwPostgresError: {"code": string, "detail": string} => exn = %raw(`(fields) => {
+    throw Object.assign(new Error("Postgres error"), fields)
+  }`)
+
+  let makeExn = fields =>
+    try {
+      throwPostgresError(fields)
+    } catch {
+    | exn => exn
+    }
+
+  it("Returns the key of the row violating the unique constraint", () => {
+    Assert.deepEqual(
+      makeExn({"code": "23505", "detail": "Key (id)=(0x123) already exists."})
+      ->DbFunctionsEntities.getUniqueViolationKey,
+      Some(("id", "0x123")),
+    )
+  })
+
+  it("Ignores other errors", () => {
+    Assert.deepEqual(
+      makeExn({"code": "23503", "detail": "Key (id)=(0x123) is not present in table \"Token\"."})
+      ->DbFunctionsEntities.getUniqueViolationKey,
+      None,
+    )
+    Assert.deepEqual(Not_found->DbFunctionsEntities.getUniqueViolationKey, None)
+  })
+})
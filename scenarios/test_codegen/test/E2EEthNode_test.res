@@ -33,6 +33,9 @@ describe("E2E Integration Test", () => {
         endBlock: None,
         chain,
         contracts,
+        blockHandlers: [],
+        transactionHandlers: [],
+        calls: [],
         source: RpcSource.make({
           chain,
           contracts,
@@ -33,3 +33,22 @@ describe("nullable encodes and decodes successfully", () => {
     Assert.deepEqual(mock1raw->S.parseJsonStringOrThrow(testRecordSchema), mock1)
   })
 })
+
+describe("dbBytes encodes and decodes successfully", () => {
+  it("encodes 0x prefixed hex as the postgres hex format", () => {
+    Assert.deepEqual(
+      "0xdeadbeef"->S.reverseConvertOrThrow(Utils.Schema.dbBytes),
+      "\\xdeadbeef"->Utils.magic,
+    )
+  })
+  it("decodes the postgres hex format as 0x prefixed hex", () => {
+    Assert.deepEqual("\\xdeadbeef"->S.parseOrThrow(Utils.Schema.dbBytes), "0xdeadbeef")
+  })
+  it("fails to encode hex without the 0x prefix", () => {
+    Assert.throws(() => "deadbeef"->S.reverseConvertOrThrow(Utils.Schema.dbBytes))
+  })
+  it("fails to decode strings without the postgres hex prefix", () => {
+    Assert.throws(() => "0xdeadbeef"->S.parseOrThrow(Utils.Schema.dbBytes))
+    Assert.throws(() => "deadbeef"->S.parseOrThrow(Utils.Schema.dbBytes))
+  })
+})
@@ -0,0 +1,53 @@
+open RescriptMocha
+
+describe("Factory contracts from config.yaml", () => {
+  let nftAddress = "0x1230000000000000000000000000000000000000"->Address.Evm.fromStringOrThrow
+
+  let makeSimpleNftCreatedEvent = (~chainId): Internal.event => {
+    let event: Types.eventLog<Types.NftFactory.SimpleNftCreated.eventArgs> = {
+      params: {
+        name: "Nft",
+        symbol: "NFT",
+        maxSupply: 100->BigInt.fromInt,
+        contractAddress: nftAddress,
+      },
+      chainId,
+      srcAddress: "0xa2F6E6029638cCb484A2ccb6414499aD3e825CaC"->Address.Evm.fromStringOrThrow,
+      logIndex: 1,
+      transaction: MockEvents.tx1,
+      block: MockEvents.block1,
+    }
+    event->Internal.fromGenericEvent
+  }
+
+  //Runs the contract register of the factory event and returns the registered contracts
+  let registerContracts = (~chainId) => {
+    let _ = RegisterHandlers.registerAllHandlers()
+    let contractRegister =
+      Types.NftFactory.SimpleNftCreated.handlerRegister
+      ->Types.HandlerTypes.Register.getContractRegister
+      ->Belt.Option.getExn
+
+    let registeredContracts = []
+    contractRegister({
+      event: makeSimpleNftCreatedEvent(~chainId),
+      context: ContextEnv.getContractRegisterContext(~onRegister=(
+        ~contractAddress,
+        ~contractName,
+      ) => registeredContracts->Js.Array2.push((contractName, contractAddress))->ignore),
+    })
+    registeredContracts
+  }
+
+  it("Registers the child address of the factory event together with the contractRegister", () => {
+    Assert.deepEqual(
+      registerContracts(~chainId=1),
+      [(Enums.ContractType.FactoryNft, nftAddress), (SimpleNft, nftAddress)],
+      ~message="Should register the factory child before the contract of the hand written contractRegister",
+    )
+  })
+
+  it("Doesn't register the child address on networks without the factory", () => {
+    Assert.deepEqual(registerContracts(~chainId=1337), [(Enums.ContractType.SimpleNft, nftAddress)])
+  })
+})
@@ -46,6 +46,7 @@ module MockEvent = (
   let transactionSchema = T.transactionSchema
 
   let convertHyperSyncEventArgs = (Utils.magic: HyperSyncClient.Decoder.decodedEvent => eventArgs)
+  let anonymousInputs = None
 
   let handlerRegister: Types.HandlerTypes.Register.t = Types.HandlerTypes.Register.make(
     ~topic0=sighash->EvmTypes.Hex.fromStringUnsafe,
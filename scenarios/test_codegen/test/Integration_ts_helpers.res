@@ -33,6 +33,9 @@ let getLocalChainConfig = (nftFactoryContractAddress): chainConfig => {
     endBlock: None,
     chain,
     contracts,
+    blockHandlers: [],
+    transactionHandlers: [],
+    calls: [],
     source: RpcSource.make({
       chain,
       contracts,
@@ -0,0 +1,50 @@
+open RescriptMocha
+
+describe("Migrations", () => {
+  it("Names partial indices by their predicate", () => {
+    let makePartialIndexQuery = where =>
+      Migrations.makeCreateIndexQuery(~tableName="Token", ~indexFields=["owner"], ~where)
+
+    Assert.equal(
+      makePartialIndexQuery(`"balance" > 0`),
+      `CREATE INDEX IF NOT EXISTS "Token_owner_partial_a035c292" ON "public"."Token"("owner") WHERE "balance" > 0; `,
+    )
+    Assert.equal(
+      makePartialIndexQuery(`"balance" = 0`),
+      `CREATE INDEX IF NOT EXISTS "Token_owner_partial_50cd3892" ON "public"."Token"("owner") WHERE "balance" = 0; `,
+      ~message="Partial indices on the same fields should have a different name per predicate",
+    )
+  })
+
+  it("Shortens index names longer than Postgres identifiers keeping them unique", () => {
+    let makeQuery = (~where) =>
+      Migrations.makeCreateIndexQuery(
+        ~tableName="TokenTransferHistoricalAggregate",
+        ~indexFields=["sourceAccountAddress", "destinationAccountAddress"],
+        ~order=Desc,
+        ~where,
+      )
+    let getIndexName = query =>
+      switch query->Js.String2.match_(%re(`/CREATE INDEX IF NOT EXISTS "([^"]+)"/`)) {
+      | Some([_, Some(indexName)]) => indexName
+      | _ => Js.Exn.raiseError("No index name in the query")
+      }
+
+    let indexName = makeQuery(~where=`"amount" > 0`)->getIndexName
+    Assert.equal(indexName->Js.String2.length, 63)
+    Assert.ok(
+      indexName->Js.String2.startsWith("TokenTransferHistoricalAggregate_sourceAccountAddress_"),
+      ~message="Should keep the start of the full name",
+    )
+    Assert.notEqual(
+      indexName,
+      makeQuery(~where=`"amount" < 0`)->getIndexName,
+      ~message="Names differing only after the cut should stay different",
+    )
+    Assert.equal(
+      Migrations.makeCreateIndexQuery(~tableName="Token", ~indexFields=["owner"])->getIndexName,
+      "Token_owner",
+      ~message="Should keep names which fit",
+    )
+  })
+})
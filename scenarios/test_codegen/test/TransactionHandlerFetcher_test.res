@@ -0,0 +1,70 @@
+open RescriptMocha
+
+describe("TransactionHandlerFetcher.isMatching", () => {
+  let mockTransaction = (~from, ~to): Source.blockTransaction => {
+    blockNumber: 1,
+    blockTimestamp: 1,
+    blockHash: "0x123",
+    transactionIndex: 0,
+    from: from->Address.unsafeFromString,
+    to: to->Belt.Option.map(Address.unsafeFromString),
+    input: "0xa9059cbb0000",
+    status: Some(1),
+    transaction: %raw(`{}`),
+  }
+
+  let selection = (
+    ~from=[],
+    ~to=[],
+    ~functionSelectors=[],
+    ~status=?,
+    (),
+  ): Source.transactionSelection => {
+    from: from->Belt.Array.map(Address.Evm.fromStringOrThrow),
+    to: to->Belt.Array.map(Address.Evm.fromStringOrThrow),
+    functionSelectors,
+    status,
+  }
+
+  it("Matches lowercased source addresses against checksummed configured addresses", () => {
+    let transaction = mockTransaction(
+      ~from="0x2c169dfe5fbba12957bdd0ba47d9cedbfe260ca7",
+      ~to=Some("0xd8da6bf26964af9d7eed9e03e53415d37aa96045"),
+    )
+
+    Assert.ok(
+      transaction->TransactionHandlerFetcher.isMatching(
+        ~selection=selection(~from=["0x2C169DFe5fBbA12957Bdd0Ba47d9CEDbFE260CA7"], ()),
+      ),
+    )
+    Assert.ok(
+      transaction->TransactionHandlerFetcher.isMatching(
+        ~selection=selection(
+          ~to=["0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"],
+          ~functionSelectors=["0xa9059cbb"],
+          ~status=1,
+          (),
+        ),
+      ),
+    )
+  })
+
+  it("Doesn't match transactions outside of the selection", () => {
+    let transaction = mockTransaction(~from="0x2c169dfe5fbba12957bdd0ba47d9cedbfe260ca7", ~to=None)
+
+    Assert.ok(
+      !(
+        transaction->TransactionHandlerFetcher.isMatching(
+          ~selection=selection(~from=["0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"], ()),
+        )
+      ),
+    )
+    Assert.ok(
+      !(
+        transaction->TransactionHandlerFetcher.isMatching(
+          ~selection=selection(~to=["0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"], ()),
+        )
+      ),
+    )
+  })
+})
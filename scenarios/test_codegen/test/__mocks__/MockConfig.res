@@ -34,6 +34,9 @@ let mockChainConfig: Config.chainConfig = {
   endBlock: None,
   chain: chain1337,
   contracts,
+  blockHandlers: [],
+  transactionHandlers: [],
+  calls: [],
   source: RpcSource.make({
     chain: chain1337,
     contracts,
@@ -1,7 +1,8 @@
 module InMemoryStore = {
   let setEntity = (inMemoryStore, ~entityMod, entity) => {
-    let inMemTable =
-      inMemoryStore->InMemoryStore.getInMemTable(~entityMod=entityMod->Entities.entityModToInternal)
+    let entityMod = entityMod->Entities.entityModToInternal
+    let module(Entity) = entityMod
+    let inMemTable = inMemoryStore->InMemoryStore.getInMemTable(~entityMod)
     let entity = entity->(Utils.magic: 'a => Entities.internalEntity)
     inMemTable->InMemoryTable.Entity.set(
       Set(entity)->Types.mkEntityUpdate(
@@ -11,7 +12,7 @@ module InMemoryStore = {
           blockNumber: 0,
           logIndex: 0,
         },
-        ~entityId=entity->Entities.getEntityId,
+        ~entityId=entity->Entity.getKey,
       ),
       ~shouldSaveHistory=RegisterHandlers.getConfig()->Config.shouldSaveHistory(
         ~isInReorgThreshold=false,
@@ -29,6 +29,8 @@ module TestEntity = {
   )
 
   let entityHistory = table->EntityHistory.fromTable(~schema)
+
+  let getKey = (entity: t) => entity.id
 }
 
 type testEntityHistory = EntityHistory.historyRow<TestEntity.t>
@@ -162,7 +164,7 @@ describe("Entity History Codegen", () => {
           -- Find the most recent record for the same id
           SELECT entity_history_block_timestamp, entity_history_chain_id, entity_history_block_number, entity_history_log_index INTO v_previous_record
           FROM "public"."TestEntity_history"
-          WHERE id = history_row.id
+          WHERE "id" = history_row."id"
           ORDER BY entity_history_block_timestamp DESC, entity_history_chain_id DESC, entity_history_block_number DESC, entity_history_log_index DESC
           LIMIT 1;
 
@@ -171,7 +173,7 @@ describe("Entity History Codegen", () => {
             history_row.previous_entity_history_block_timestamp := v_previous_record.entity_history_block_timestamp; history_row.previous_entity_history_chain_id := v_previous_record.entity_history_chain_id; history_row.previous_entity_history_block_number := v_previous_record.entity_history_block_number; history_row.previous_entity_history_log_index := v_previous_record.entity_history_log_index;
             ElSIF should_copy_current_entity THEN
             -- Check if a value for the id exists in the origin table and if so, insert a history row for it.
-            SELECT "id", "fieldA", "fieldB" FROM "public"."TestEntity" WHERE id = history_row.id INTO v_origin_record;
+            SELECT "id", "fieldA", "fieldB" FROM "public"."TestEntity" WHERE "id" = history_row."id" INTO v_origin_record;
             IF FOUND THEN
               INSERT INTO "public"."TestEntity_history" (entity_history_block_timestamp, entity_history_chain_id, entity_history_block_number, entity_history_log_index, "id", "fieldA", "fieldB", "action")
               -- SET the current change data fields to 0 since we don't know what they were
@@ -698,7 +700,7 @@ describe("Entity history rollbacks", () => {
 
   Async.it("Prunes history correctly with items in reorg threshold", async () => {
     await Db.sql->DbFunctions.EntityHistory.pruneStaleEntityHistory(
-      ~entityName=TestEntity.name,
+      ~entityMod=module(TestEntity),
       ~safeChainIdAndBlockNumberArray=[{chainId: 1, blockNumber: 3}, {chainId: 2, blockNumber: 2}],
       ~shouldDeepClean=true,
     )
@@ -730,7 +732,7 @@ describe("Entity history rollbacks", () => {
     "Deep clean prunes history correctly with items in reorg threshold without checking for stale history entities in threshold",
     async () => {
       await Db.sql->DbFunctions.EntityHistory.pruneStaleEntityHistory(
-        ~entityName=TestEntity.name,
+        ~entityMod=module(TestEntity),
         ~safeChainIdAndBlockNumberArray=[
           {chainId: 1, blockNumber: 3},
           {chainId: 2, blockNumber: 2},
@@ -756,7 +758,7 @@ describe("Entity history rollbacks", () => {
   )
   Async.it("Prunes history correctly with no items in reorg threshold", async () => {
     await Db.sql->DbFunctions.EntityHistory.pruneStaleEntityHistory(
-      ~entityName=TestEntity.name,
+      ~entityMod=module(TestEntity),
       ~safeChainIdAndBlockNumberArray=[{chainId: 1, blockNumber: 4}, {chainId: 2, blockNumber: 3}],
       ~shouldDeepClean=true,
     )
@@ -817,7 +819,7 @@ describe_skip("Prune performance test", () => {
     let startTime = Hrtime.makeTimer()
 
     try await Db.sql->DbFunctions.EntityHistory.pruneStaleEntityHistory(
-      ~entityName=TestEntity.name,
+      ~entityMod=module(TestEntity),
       ~safeChainIdAndBlockNumberArray=[{chainId: 1, blockNumber: 500}],
       ~shouldDeepClean=false,
     ) catch {
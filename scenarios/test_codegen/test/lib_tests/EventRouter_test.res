@@ -178,6 +178,69 @@ describe("EventRouter", () => {
     },
   )
 
+  it("getEvmEvent falls back to anonymous events of registered contracts by data shape", () => {
+    let contractName = "Contract1"
+    let router = EventRouter.empty()
+
+    router->EventRouter.addOrThrow(
+      EventRouter.getEvmAnonymousEventId(~topicCount=1, ~dataShape="32"),
+      "static",
+      ~contractName,
+      ~eventName="Event1",
+      ~chain=mockChain,
+      ~isWildcard=false,
+    )
+    router->EventRouter.addOrThrow(
+      EventRouter.getEvmAnonymousEventId(~topicCount=1, ~dataShape="dynamic"),
+      "dynamic",
+      ~contractName,
+      ~eventName="Event2",
+      ~chain=mockChain,
+      ~isWildcard=false,
+    )
+
+    let contractAddressMapping = ContractAddressingMap.make()
+    contractAddressMapping->ContractAddressingMap.addAddress(
+      ~name=contractName,
+      ~address=mockAddress1,
+    )
+    let topics = [
+      "0x000000000000000000000000000000000000000000000000000000000000000a"->EvmTypes.Hex.fromStringUnsafe,
+    ]
+    let staticData = "0x0000000000000000000000000000000000000000000000000000000000000001"
+
+    Assert.deepEqual(
+      router->EventRouter.getEvmEvent(
+        ~topics,
+        ~data=staticData,
+        ~contractAddress=mockAddress1,
+        ~contractAddressMapping,
+      ),
+      Some("static"),
+      ~message="Should match the event with the exact data size",
+    )
+    Assert.deepEqual(
+      router->EventRouter.getEvmEvent(
+        ~topics,
+        ~data="0x",
+        ~contractAddress=mockAddress1,
+        ~contractAddressMapping,
+      ),
+      Some("dynamic"),
+      ~message="Should fallback to the event with dynamic data",
+    )
+    Assert.deepEqual(
+      router->EventRouter.getEvmEvent(
+        ~topics,
+        ~data=staticData,
+        ~contractAddress=mockAddress2,
+        ~contractAddressMapping,
+      ),
+      None,
+      ~message="Should ignore logs of unregistered addresses",
+    )
+  })
+
   it("fromEvmEventModsOrThrow works", () => {
     let router = EventRouter.fromEvmEventModsOrThrow(
       [module(Types.Gravatar.NewGravatar)],
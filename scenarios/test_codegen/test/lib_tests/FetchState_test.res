@@ -377,6 +377,77 @@ describe("FetchState.make", () => {
       )
     },
   )
+
+  it("Creates separate partitions for contracts with a later start block", () => {
+    let fetchState = FetchState.make(
+      ~eventConfigs=[
+        {
+          contractName: "ContractA",
+          eventId: "0",
+          isWildcard: false,
+        },
+        {
+          contractName: "Gravatar",
+          eventId: "0",
+          isWildcard: false,
+          startBlock: 100,
+        },
+        {
+          contractName: "Gravatar",
+          eventId: "1",
+          isWildcard: false,
+          startBlock: 50,
+          endBlock: 200,
+        },
+      ],
+      ~staticContracts=Js.Dict.fromArray([
+        ("Gravatar", [mockAddress1]),
+        ("ContractA", [mockAddress2]),
+      ]),
+      ~dynamicContracts=[],
+      ~startBlock=10,
+      ~endBlock=None,
+      ~maxAddrInPartition=3,
+    )
+
+    Assert.deepEqual(
+      fetchState.partitions,
+      [
+        {
+          id: "0",
+          status: {fetchingStateId: None},
+          latestFetchedBlock: {
+            blockNumber: 9,
+            blockTimestamp: 0,
+          },
+          selection: fetchState.normalSelection,
+          contractAddressMapping: ContractAddressingMap.fromArray([(mockAddress2, "ContractA")]),
+          dynamicContracts: [],
+          fetchedEventQueue: [],
+        },
+        {
+          id: "1",
+          status: {fetchingStateId: None},
+          latestFetchedBlock: {
+            blockNumber: 49,
+            blockTimestamp: 0,
+          },
+          selection: fetchState.normalSelection,
+          contractAddressMapping: ContractAddressingMap.fromArray([(mockAddress1, "Gravatar")]),
+          dynamicContracts: [],
+          fetchedEventQueue: [],
+        },
+      ],
+      ~message=`The Gravatar partition should start from the earliest start block of its events`,
+    )
+    Assert.deepEqual(
+      fetchState.latestFullyFetchedBlock,
+      {
+        blockNumber: 9,
+        blockTimestamp: 0,
+      },
+    )
+  })
 })
 
 describe("FetchState.registerDynamicContracts", () => {
@@ -443,6 +514,63 @@ describe("FetchState.registerDynamicContracts", () => {
     },
   )
 
+  it("Traces the calls of dcs with call handlers in separate partitions by start block", () => {
+    let fetchState = makeInitial()
+
+    let dc1 = makeDynContractRegistration(~blockNumber=0, ~contractAddress=mockAddress1)
+    let dc2 = makeDynContractRegistration(
+      ~blockNumber=0,
+      ~contractAddress=mockAddress2,
+      ~contractType=NftFactory,
+    )
+    let dc3 = makeDynContractRegistration(~blockNumber=2, ~contractAddress=mockAddress3)
+
+    let makeCallHandlersPartition = (
+      ~id,
+      ~blockNumber,
+      ~dc: TablesStatic.DynamicContractRegistry.t,
+    ): FetchState.partition => {
+      id,
+      status: {fetchingStateId: None},
+      latestFetchedBlock: {
+        blockNumber,
+        blockTimestamp: 0,
+      },
+      selection: {
+        isWildcard: true,
+        eventConfigs: [],
+      },
+      contractAddressMapping: ContractAddressingMap.fromArray([(dc.contractAddress, "Gravatar")]),
+      dynamicContracts: [dc],
+      fetchedEventQueue: [],
+    }
+
+    let updatedFetchState =
+      fetchState->FetchState.registerDynamicContracts(
+        [dc1, dc2, dc3],
+        ~currentBlockHeight=10,
+        ~callHandlerContractNames=["Gravatar"],
+      )
+
+    Assert.deepEqual(
+      updatedFetchState.partitions->Array.sliceToEnd(3),
+      [
+        makeCallHandlersPartition(~id="callHandlers_3", ~blockNumber=0, ~dc=dc1),
+        makeCallHandlersPartition(~id="callHandlers_4", ~blockNumber=1, ~dc=dc3),
+      ],
+      ~message="Only the dcs of the contracts with call handlers should be traced",
+    )
+    Assert.equal(updatedFetchState.nextPartitionIndex, 5)
+
+    Assert.deepEqual(
+      updatedFetchState
+      ->FetchState.rollback(~firstChangeEvent={blockNumber: 2, logIndex: 0})
+      ->(fetchState => fetchState.partitions->Array.map(p => p.id)),
+      ["0", "1", "callHandlers_3"],
+      ~message="The partitions of the dcs registered after the rollback block should be removed",
+    )
+  })
+
   it(
     "Creates FetchState with wildcard and normal events. Addresses not belonging to event configs should be skipped (pre-registration case)",
     () => {
@@ -1421,6 +1549,179 @@ describe("FetchState.getNextQuery & integration", () => {
       ~message=`Should keep Wildcard partition even if it's empty`,
     )
   })
+
+  it("Stops fetching partitions after the end block of their events", () => {
+    let fetchState = FetchState.make(
+      ~eventConfigs=[
+        {
+          contractName: "ContractA",
+          eventId: "0",
+          isWildcard: false,
+        },
+        {
+          contractName: "Gravatar",
+          eventId: "0",
+          isWildcard: false,
+          endBlock: 100,
+        },
+        {
+          contractName: "Gravatar",
+          eventId: "1",
+          isWildcard: false,
+          endBlock: 200,
+        },
+      ],
+      ~staticContracts=Js.Dict.fromArray([
+        ("Gravatar", [mockAddress1]),
+        ("ContractA", [mockAddress2]),
+      ]),
+      ~dynamicContracts=[],
+      ~startBlock=10,
+      ~endBlock=None,
+      ~maxAddrInPartition=1,
+    )
+
+    let getNextQuery = fs =>
+      fs->FetchState.getNextQuery(
+        ~currentBlockHeight=300,
+        ~concurrencyLimit=10,
+        ~maxQueueSize=10,
+        ~stateId=0,
+      )
+
+    let gravatarQuery: FetchState.query = {
+      partitionId: "0",
+      target: EndBlock({toBlock: 200}),
+      selection: fetchState.normalSelection,
+      contractAddressMapping: ContractAddressingMap.fromArray([(mockAddress1, "Gravatar")]),
+      fromBlock: 10,
+    }
+    let contractAQuery: FetchState.query = {
+      partitionId: "1",
+      target: Head,
+      selection: fetchState.normalSelection,
+      contractAddressMapping: ContractAddressingMap.fromArray([(mockAddress2, "ContractA")]),
+      fromBlock: 10,
+    }
+
+    Assert.deepEqual(
+      fetchState->getNextQuery,
+      Ready([gravatarQuery, contractAQuery]),
+      ~message=`Should fetch the Gravatar partition until the latest end block of its events`,
+    )
+
+    let fetchState =
+      fetchState
+      ->FetchState.setQueryResponse(
+        ~query=gravatarQuery,
+        ~latestFetchedBlock={
+          blockNumber: 200,
+          blockTimestamp: 200,
+        },
+        ~newItems=[],
+        ~currentBlockHeight=300,
+      )
+      ->Result.getExn
+
+    Assert.deepEqual(
+      fetchState->getNextQuery,
+      Ready([contractAQuery]),
+      ~message=`Shouldn't query the ended partition`,
+    )
+    Assert.deepEqual(
+      fetchState.latestFullyFetchedBlock,
+      {
+        blockNumber: 9,
+        blockTimestamp: 0,
+      },
+    )
+
+    let fetchState =
+      fetchState
+      ->FetchState.setQueryResponse(
+        ~query=contractAQuery,
+        ~latestFetchedBlock={
+          blockNumber: 300,
+          blockTimestamp: 300,
+        },
+        ~newItems=[],
+        ~currentBlockHeight=300,
+      )
+      ->Result.getExn
+
+    Assert.deepEqual(
+      fetchState.latestFullyFetchedBlock,
+      {
+        blockNumber: 300,
+        blockTimestamp: 300,
+      },
+      ~message=`The ended partition shouldn't hold back the fully fetched block`,
+    )
+    Assert.deepEqual(
+      fetchState->FetchState.getEarliestEvent,
+      NoItem({
+        latestFetchedBlock: {
+          blockNumber: 300,
+          blockTimestamp: 300,
+        },
+      }),
+    )
+    Assert.deepEqual(fetchState->getNextQuery, WaitingForNewBlock)
+  })
+
+  it("Stops fetching the block handlers partition after the end block of the handlers", () => {
+    let fetchState = FetchState.make(
+      ~eventConfigs=[],
+      ~staticContracts=Js.Dict.empty(),
+      ~dynamicContracts=[],
+      ~startBlock=0,
+      ~endBlock=None,
+      ~maxAddrInPartition=3,
+      ~hasBlockHandlers=true,
+      ~blockHandlersEndBlock=50,
+    )
+
+    let getNextQuery = fs =>
+      fs->FetchState.getNextQuery(
+        ~currentBlockHeight=300,
+        ~concurrencyLimit=10,
+        ~maxQueueSize=10,
+        ~stateId=0,
+      )
+
+    let query: FetchState.query = {
+      partitionId: FetchState.blockHandlersPartitionId,
+      target: EndBlock({toBlock: 50}),
+      selection: {
+        isWildcard: true,
+        eventConfigs: [],
+      },
+      contractAddressMapping: ContractAddressingMap.make(),
+      fromBlock: 0,
+    }
+
+    Assert.deepEqual(fetchState->getNextQuery, Ready([query]))
+
+    let fetchState =
+      fetchState
+      ->FetchState.setQueryResponse(
+        ~query,
+        ~latestFetchedBlock={
+          blockNumber: 50,
+          blockTimestamp: 50,
+        },
+        ~newItems=[],
+        ~currentBlockHeight=300,
+      )
+      ->Result.getExn
+
+    Assert.deepEqual(
+      fetchState->getNextQuery,
+      NothingToQuery,
+      ~message=`Shouldn't wait for new blocks when every partition has ended`,
+    )
+    Assert.deepEqual(fetchState.isFetchingAtHead, true)
+  })
 })
 
 describe("FetchState unit tests for specific cases", () => {
@@ -0,0 +1,91 @@
+open RescriptMocha
+
+let topic0 =
+  "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"->EvmTypes.Hex.fromStringUnsafe
+let from1 =
+  "0x0000000000000000000000002e645469f354bb4f5c8a05b3b30a929361cf77ec"->EvmTypes.Hex.fromStringUnsafe
+let from2 =
+  "0x0000000000000000000000006b175474e89094c44da98b954eedeac495271d0f"->EvmTypes.Hex.fromStringUnsafe
+let to1 =
+  "0x000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"->EvmTypes.Hex.fromStringUnsafe
+
+describe("LogSelection.intersectTopicSelections", () => {
+  it("Keeps the values of a topic when the other selection doesn't filter it", () => {
+    Assert.deepEqual(
+      LogSelection.intersectTopicSelections(
+        [LogSelection.makeTopicSelection(~topic0=[topic0])->Utils.unwrapResultExn],
+        [LogSelection.makeTopicSelection(~topic0=[topic0], ~topic1=[from1])->Utils.unwrapResultExn],
+      ),
+      [LogSelection.makeTopicSelection(~topic0=[topic0], ~topic1=[from1])->Utils.unwrapResultExn],
+    )
+  })
+
+  it("Keeps only the common values of a topic", () => {
+    Assert.deepEqual(
+      LogSelection.intersectTopicSelections(
+        [
+          LogSelection.makeTopicSelection(
+            ~topic0=[topic0],
+            ~topic1=[from1, from2],
+          )->Utils.unwrapResultExn,
+        ],
+        [
+          LogSelection.makeTopicSelection(
+            ~topic0=[topic0],
+            ~topic1=[from2],
+            ~topic2=[to1],
+          )->Utils.unwrapResultExn,
+        ],
+      ),
+      [
+        LogSelection.makeTopicSelection(
+          ~topic0=[topic0],
+          ~topic1=[from2],
+          ~topic2=[to1],
+        )->Utils.unwrapResultExn,
+      ],
+    )
+  })
+
+  it("Intersects every selection of the first group with every selection of the second", () => {
+    Assert.deepEqual(
+      LogSelection.intersectTopicSelections(
+        [
+          LogSelection.makeTopicSelection(~topic0=[topic0], ~topic1=[from1])->Utils.unwrapResultExn,
+          LogSelection.makeTopicSelection(~topic0=[topic0], ~topic1=[from2])->Utils.unwrapResultExn,
+        ],
+        [
+          LogSelection.makeTopicSelection(~topic0=[topic0], ~topic2=[to1])->Utils.unwrapResultExn,
+          LogSelection.makeTopicSelection(
+            ~topic0=[topic0],
+            ~topic1=[from2],
+          )->Utils.unwrapResultExn,
+        ],
+      ),
+      [
+        LogSelection.makeTopicSelection(
+          ~topic0=[topic0],
+          ~topic1=[from1],
+          ~topic2=[to1],
+        )->Utils.unwrapResultExn,
+        LogSelection.makeTopicSelection(
+          ~topic0=[topic0],
+          ~topic1=[from2],
+          ~topic2=[to1],
+        )->Utils.unwrapResultExn,
+        LogSelection.makeTopicSelection(~topic0=[topic0], ~topic1=[from2])->Utils.unwrapResultExn,
+      ],
+      ~message="Should drop the selection with different from values",
+    )
+  })
+
+  it("Returns no selections when the groups don't have common values", () => {
+    Assert.deepEqual(
+      LogSelection.intersectTopicSelections(
+        [LogSelection.makeTopicSelection(~topic0=[topic0], ~topic1=[from1])->Utils.unwrapResultExn],
+        [LogSelection.makeTopicSelection(~topic0=[topic0], ~topic1=[from2])->Utils.unwrapResultExn],
+      ),
+      [],
+    )
+  })
+})
@@ -144,4 +144,84 @@ describe("Table functions postgres interop", () => {
 
     Assert.equal(batchSetFnString, expected)
   })
+
+  it("Makes insert only batch set function for immutable entity", () => {
+    let table = mkTable(
+      "test_table",
+      ~schemaName="public",
+      ~isImmutable=true,
+      ~fields=[mkField("id", Text, ~isPrimaryKey), mkField("field_a", Numeric)],
+    )
+
+    let batchSetFnString = table->PostgresInterop.makeBatchSetFnString
+
+    let expected = `(sql, rows) => {
+      return sql\`
+        INSERT INTO "public"."test_table"
+        \${sql(rows, "id", "field_a")};\`
+    }`
+
+    Assert.equal(batchSetFnString, expected)
+  })
+
+  it("Makes rollup queries for aggregation", () => {
+    let aggregation = {
+      source: "Swap",
+      intervals: [Hour],
+      dimensions: ["token_id"],
+      aggregates: [mkAggregate("volume", Sum, ~arg="amount"), mkAggregate("swapCount", Count)],
+    }
+    let table = mkTable(
+      "TokenStats",
+      ~schemaName="public",
+      ~aggregation,
+      ~fields=[
+        mkField("id", Text, ~isPrimaryKey),
+        mkField("interval", Text),
+        mkField("timestamp", Timestamp),
+        mkField("token", Text, ~linkedEntity="Token"),
+        mkField("volume", Numeric),
+        mkField("swapCount", Integer),
+      ],
+    )
+
+    let queries = table->makeAggregationRollupQueries(~aggregation)
+
+    Assert.deepEqual(
+      queries,
+      [
+        `DELETE FROM "public"."TokenStats" WHERE "interval" = 'hour' AND "timestamp" >= (date_trunc('hour', $1::timestamptz AT TIME ZONE 'UTC') AT TIME ZONE 'UTC') AND "timestamp" < ((date_trunc('hour', $2::timestamptz AT TIME ZONE 'UTC') + interval '1 hour') AT TIME ZONE 'UTC');`,
+        `INSERT INTO "public"."TokenStats" ("id", "interval", "timestamp", "token_id", "volume", "swapCount")
+SELECT concat_ws('-', 'hour', EXTRACT(EPOCH FROM (date_trunc('hour', "timestamp" AT TIME ZONE 'UTC') AT TIME ZONE 'UTC'))::bigint, "token_id"), 'hour', (date_trunc('hour', "timestamp" AT TIME ZONE 'UTC') AT TIME ZONE 'UTC'), "token_id", (SUM("amount"))::NUMERIC, (COUNT(*))::INTEGER
+FROM "public"."Swap"
+WHERE "timestamp" >= (date_trunc('hour', $1::timestamptz AT TIME ZONE 'UTC') AT TIME ZONE 'UTC') AND "timestamp" < ((date_trunc('hour', $2::timestamptz AT TIME ZONE 'UTC') + interval '1 hour') AT TIME ZONE 'UTC')
+GROUP BY (date_trunc('hour', "timestamp" AT TIME ZONE 'UTC') AT TIME ZONE 'UTC'), "token_id";`,
+      ],
+    )
+  })
+
+  it("Makes timestamp range query for the source of an aggregation", () => {
+    let aggregation = {
+      source: "Swap",
+      intervals: [Hour],
+      dimensions: [],
+      aggregates: [mkAggregate("swapCount", Count)],
+    }
+    let table = mkTable(
+      "SwapStats",
+      ~schemaName="public",
+      ~aggregation,
+      ~fields=[
+        mkField("id", Text, ~isPrimaryKey),
+        mkField("interval", Text),
+        mkField("timestamp", Timestamp),
+        mkField("swapCount", Integer),
+      ],
+    )
+
+    Assert.equal(
+      table->makeAggregationSourceTimestampRangeQuery(~aggregation),
+      `SELECT MIN("timestamp") AS "fromTimestamp", MAX("timestamp") AS "toTimestamp" FROM "public"."Swap" WHERE "id"::text = ANY($1::text[]);`,
+    )
+  })
 })
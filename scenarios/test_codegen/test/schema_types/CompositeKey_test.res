@@ -0,0 +1,104 @@
+open RescriptMocha
+
+describe("Load and save an entity with a composite key from DB", () => {
+  Async.before(() => {
+    DbHelpers.runUpDownMigration()
+  })
+
+  Async.after(() => {
+    // It is probably overkill that we are running these 'after' also
+    DbHelpers.runUpDownMigration()
+  })
+
+  Async.it("be able to set, read and delete entities with composite keys from DB", async () => {
+    This.timeout(5 * 1000)
+
+    let sql = Db.sql
+    /// Setup DB
+    let testEntity1: Entities.EntityWithCompositeKey.t = {
+      owner: "0x1",
+      tokenId: 1n,
+      amount: 10n,
+    }
+    // Same owner, so only the full key tells the entities apart
+    let testEntity2: Entities.EntityWithCompositeKey.t = {
+      owner: "0x1",
+      tokenId: 2n,
+      amount: 20n,
+    }
+    let testEntity3: Entities.EntityWithCompositeKey.t = {
+      owner: "0x2",
+      tokenId: 1n,
+      amount: 30n,
+    }
+
+    await DbFunctionsEntities.batchSet(~entityMod=module(Entities.EntityWithCompositeKey))(
+      sql,
+      [testEntity1, testEntity2],
+    )
+
+    let inMemoryStore = InMemoryStore.make()
+    let loadLayer = LoadLayer.makeWithDbConnection()
+
+    let contextEnv = ContextEnv.make(
+      ~eventItem=MockEvents.newGravatarLog1->MockEvents.newGravatarEventToBatchItem,
+      ~logger=Logging.logger,
+    )
+
+    let loaderContext =
+      contextEnv
+      ->ContextEnv.getLoaderContext(~loadLayer, ~inMemoryStore)
+      ->(Utils.magic: Internal.loaderContext => Types.loaderContext)
+
+    Assert.deepEqual(
+      await loaderContext.entityWithCompositeKey.get((testEntity2.owner, testEntity2.tokenId)),
+      Some(testEntity2),
+      ~message="Should load the entity stored in the DB by its composite key",
+    )
+
+    let handlerContext =
+      contextEnv
+      ->ContextEnv.getHandlerContext(~inMemoryStore, ~loadLayer, ~shouldSaveHistory=false)
+      ->(Utils.magic: Internal.handlerContext => Types.handlerContext)
+
+    handlerContext.entityWithCompositeKey.set(testEntity3)
+    handlerContext.entityWithCompositeKey.deleteUnsafe((testEntity1.owner, testEntity1.tokenId))
+
+    Assert.deepEqual(
+      await handlerContext.entityWithCompositeKey.get((testEntity3.owner, testEntity3.tokenId)),
+      Some(testEntity3),
+      ~message="Should get the entity set in the handler by its composite key",
+    )
+    Assert.deepEqual(
+      await handlerContext.entityWithCompositeKey.get((testEntity1.owner, testEntity1.tokenId)),
+      None,
+      ~message="Should not get the entity deleted in the handler",
+    )
+
+    await sql->IO.executeBatch(
+      ~inMemoryStore,
+      ~isInReorgThreshold=false,
+      ~config=RegisterHandlers.getConfig(),
+    )
+
+    let dbEntities = await DbFunctionsEntities.batchRead(
+      ~entityMod=module(Entities.EntityWithCompositeKey),
+    )(
+      sql,
+      [testEntity1, testEntity2, testEntity3]->Belt.Array.map(
+        Entities.EntityWithCompositeKey.getKey,
+      ),
+    )
+
+    Assert.deepEqual(
+      dbEntities->Js.Array2.sortInPlaceWith((a, b) =>
+        compare(
+          a->Entities.EntityWithCompositeKey.getKey,
+          b->Entities.EntityWithCompositeKey.getKey,
+        )
+      ),
+      [testEntity2, testEntity3],
+      ~message="Should write the set and deleted entities to the DB",
+    )
+  })
+})
@@ -0,0 +1,89 @@
+open RescriptMocha
+
+describe("Load and save an entity with an Int8 id from DB", () => {
+  Async.before(() => {
+    DbHelpers.runUpDownMigration()
+  })
+
+  Async.after(() => {
+    // It is probably overkill that we are running these 'after' also
+    DbHelpers.runUpDownMigration()
+  })
+
+  Async.it("be able to set and read entities with Int8 ids from DB", async () => {
+    This.timeout(5 * 1000)
+
+    let sql = Db.sql
+    /// Setup DB
+    // Bigger than Number.MAX_SAFE_INTEGER to make sure the id isn't rounded on the way
+    let testEntity1: Entities.EntityWithInt8Id.t = {
+      id: 9007199254740993n,
+      name: "testEntity",
+    }
+    let testEntity2: Entities.EntityWithInt8Id.t = {
+      id: 2n,
+      name: "testEntity2",
+    }
+
+    await DbFunctionsEntities.batchSet(~entityMod=module(Entities.EntityWithInt8Id))(
+      sql,
+      [testEntity1],
+    )
+
+    let inMemoryStore = InMemoryStore.make()
+    let loadLayer = LoadLayer.makeWithDbConnection()
+
+    let contextEnv = ContextEnv.make(
+      ~eventItem=MockEvents.newGravatarLog1->MockEvents.newGravatarEventToBatchItem,
+      ~logger=Logging.logger,
+    )
+
+    let loaderContext =
+      contextEnv
+      ->ContextEnv.getLoaderContext(~loadLayer, ~inMemoryStore)
+      ->(Utils.magic: Internal.loaderContext => Types.loaderContext)
+
+    Assert.deepEqual(
+      await loaderContext.entityWithInt8Id.get(testEntity1.id),
+      Some(testEntity1),
+      ~message="Should load the entity stored in the DB by its Int8 id",
+    )
+    Assert.deepEqual(
+      await loaderContext.entityWithInt8Id.getWhere.name.eq(testEntity1.name),
+      [testEntity1],
+      ~message="Should load the entity stored in the DB by its index",
+    )
+
+    let handlerContext =
+      contextEnv
+      ->ContextEnv.getHandlerContext(~inMemoryStore, ~loadLayer, ~shouldSaveHistory=false)
+      ->(Utils.magic: Internal.handlerContext => Types.handlerContext)
+
+    handlerContext.entityWithInt8Id.set(testEntity2)
+
+    Assert.deepEqual(
+      await handlerContext.entityWithInt8Id.get(testEntity1.id),
+      Some(testEntity1),
+    )
+    Assert.deepEqual(
+      await handlerContext.entityWithInt8Id.get(testEntity2.id),
+      Some(testEntity2),
+      ~message="Should get the entity set in the handler by its Int8 id",
+    )
+
+    await sql->IO.executeBatch(
+      ~inMemoryStore,
+      ~isInReorgThreshold=false,
+      ~config=RegisterHandlers.getConfig(),
+    )
+
+    Assert.deepEqual(
+      await DbFunctionsEntities.batchRead(~entityMod=module(Entities.EntityWithInt8Id))(
+        sql,
+        [testEntity2.id->BigInt.toString],
+      ),
+      [testEntity2],
+      ~message="Should write the entity set in the handler to the DB",
+    )
+  })
+})